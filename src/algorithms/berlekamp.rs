@@ -0,0 +1,144 @@
+use crate::algorithms;
+use crate::algorithms::cantor_zassenhaus::pow_mod_f;
+use crate::algorithms::smith::{DenseMatrix, kernel};
+use crate::divisibility::DivisibilityRingStore;
+use crate::field::Field;
+use crate::integer::*;
+use crate::pid::{EuclideanRing, EuclideanRingStore};
+use crate::ring::*;
+use crate::homomorphism::*;
+use crate::rings::finite::{FiniteRing, FiniteRingStore};
+use crate::rings::poly::{PolyRing, PolyRingStore};
+
+///
+/// Computes a basis of the Berlekamp subalgebra of `Fq[X]/(f)`, i.e. of the `Fq`-subspace of
+/// all `g` with `g^q = g mod f`. If `f` is square-free, its dimension is the number of irreducible
+/// factors of `f`. The basis elements are returned as polynomials of degree `< deg(f)`.
+///
+pub fn berlekamp_subalgebra<P>(poly_ring: P, f: &El<P>) -> Vec<El<P>>
+    where P: PolyRingStore,
+        P::Type: PolyRing + EuclideanRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: FiniteRing + Field
+{
+    let ZZ = BigIntRing::RING;
+    let F = poly_ring.base_ring();
+    let n = poly_ring.degree(f).unwrap();
+    let x_pow_q = pow_mod_f(&poly_ring, poly_ring.indeterminate(), f, &F.size(&ZZ), ZZ);
+
+    // since `g^q = g(X^q)` for `g` in `Fq[X]`, the condition `g^q = g` is linear in the coefficients
+    // of `g`; the `i`-th column of the matrix is `X^(qi) - X^i mod f`
+    let mut matrix = DenseMatrix::zero(n, n, F);
+    let mut current = poly_ring.one();
+    for i in 0..n {
+        for j in 0..n {
+            *matrix.at_mut(j, i) = F.clone_el(poly_ring.coefficient_at(&current, j));
+        }
+        F.sub_assign(matrix.at_mut(i, i), F.one());
+        current = poly_ring.euclidean_rem(poly_ring.mul_ref_snd(current, &x_pow_q), f);
+    }
+    return kernel(&mut matrix, F).into_iter()
+        .map(|(_, g)| poly_ring.from_terms(g.into_iter().enumerate().filter(|(_, c)| !F.is_zero(c)).map(|(i, c)| (c, i))))
+        .collect();
+}
+
+///
+/// Factors a square-free polynomial over a finite field into its irreducible factors, using
+/// Berlekamp's algorithm. The factors are only determined up to multiplication by units.
+///
+/// # Algorithm
+///
+/// By the Chinese remainder theorem, `Fq[X]/(f)` is isomorphic to `Fq[X]/(f1) x ... x Fq[X]/(fr)`,
+/// where `f1, ..., fr` are the irreducible factors of `f`. Under this isomorphism, the Berlekamp
+/// subalgebra corresponds to `Fq x ... x Fq`, and can be computed by linear algebra. In particular,
+/// each element `g` of the Berlekamp subalgebra satisfies `f = prod_c gcd(f, g - c)`, where `c` runs
+/// through `Fq`. Furthermore, for each two irreducible factors of `f`, there is a basis element of
+/// the Berlekamp subalgebra that separates them.
+///
+/// Since this iterates through all elements of `Fq`, it is only suitable for small fields. On the
+/// other hand, it is completely deterministic.
+///
+pub fn berlekamp<P>(poly_ring: P, f: El<P>) -> Vec<El<P>>
+    where P: PolyRingStore,
+        P::Type: PolyRing + EuclideanRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: FiniteRing + Field
+{
+    assert!(!poly_ring.is_zero(&f));
+    let F = poly_ring.base_ring();
+    if poly_ring.degree(&f).unwrap() == 0 {
+        return Vec::new();
+    }
+    let subalgebra_basis = berlekamp_subalgebra(&poly_ring, &f);
+    let factor_count = subalgebra_basis.len();
+    let mut result = vec![f];
+    for g in subalgebra_basis.iter().filter(|g| poly_ring.degree(g).unwrap_or(0) > 0) {
+        if result.len() == factor_count {
+            break;
+        }
+        let mut new_result = Vec::new();
+        for mut h in result.into_iter() {
+            for c in F.elements() {
+                if poly_ring.degree(&h).unwrap() <= 1 {
+                    break;
+                }
+                let d = algorithms::eea::gcd(poly_ring.clone_el(&h), poly_ring.sub_ref_fst(g, poly_ring.inclusion().map(c)), &poly_ring);
+                if poly_ring.degree(&d).unwrap() > 0 && poly_ring.degree(&d) != poly_ring.degree(&h) {
+                    h = poly_ring.checked_div(&h, &d).unwrap();
+                    new_result.push(d);
+                }
+            }
+            new_result.push(h);
+        }
+        result = new_result;
+    }
+    debug_assert_eq!(factor_count, result.len());
+    return result;
+}
+
+#[cfg(test)]
+use crate::rings::poly::dense_poly::DensePolyRing;
+#[cfg(test)]
+use crate::rings::zn::zn_static::Fp;
+#[cfg(test)]
+use crate::rings::extension::galois_field::GaloisField;
+
+#[test]
+fn test_berlekamp_subalgebra() {
+    let ring = DensePolyRing::new(Fp::<3>::RING, "X");
+    // `X^2 + 1` is irreducible over `F3`, and `X + 1`, `X + 2` are coprime to it
+    let f = ring.prod([
+        ring.from_terms([(1, 0), (1, 2)].into_iter()),
+        ring.from_terms([(1, 0), (1, 1)].into_iter()),
+        ring.from_terms([(2, 0), (1, 1)].into_iter())
+    ].into_iter());
+    let basis = berlekamp_subalgebra(&ring, &f);
+    assert_eq!(3, basis.len());
+    for g in &basis {
+        assert_el_eq!(&ring, g, &ring.euclidean_rem(ring.pow(ring.clone_el(g), 3), &f));
+    }
+}
+
+#[test]
+fn test_berlekamp() {
+    let ring = DensePolyRing::new(Fp::<2>::RING, "X");
+    let factors = [
+        ring.from_terms([(1, 1)].into_iter()),
+        ring.from_terms([(1, 0), (1, 1)].into_iter()),
+        ring.from_terms([(1, 0), (1, 1), (1, 2)].into_iter()),
+        ring.from_terms([(1, 0), (1, 1), (1, 3)].into_iter()),
+        ring.from_terms([(1, 0), (1, 2), (1, 3)].into_iter()),
+        ring.from_terms([(1, 0), (1, 1), (1, 4)].into_iter())
+    ];
+    let f = ring.prod(factors.iter().map(|g| ring.clone_el(g)));
+    let result = berlekamp(&ring, f);
+    assert_eq!(factors.len(), result.len());
+    for g in &factors {
+        assert!(result.iter().any(|h| ring.eq_el(g, h)));
+    }
+
+    let field = GaloisField::new(3, 2);
+    let ring = DensePolyRing::new(&field, "X");
+    let f = ring.sub(ring.pow(ring.indeterminate(), 9), ring.indeterminate());
+    let result = berlekamp(&ring, f);
+    assert_eq!(9, result.len());
+    assert!(result.iter().all(|g| ring.degree(g) == Some(1)));
+}
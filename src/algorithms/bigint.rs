@@ -391,8 +391,11 @@ pub fn bigint_div<A: Allocator>(lhs: &mut [BlockInt], rhs: &[BlockInt], mut out:
 				}
 				d -= 1;
 			}
-			let quo = division_step_last(lhs, rhs, d, &mut tmp);
-			bigint_add(&mut out, &[quo], 0);
+			// if the top block is already cleared, we have `lhs < rhs`
+			if lhs[d] != 0 {
+				let quo = division_step_last(lhs, rhs, d, &mut tmp);
+				bigint_add(&mut out, &[quo], 0);
+			}
 			return out;
 		}
 	}
@@ -548,6 +551,18 @@ fn test_div_last_block_overflow() {
     assert_eq!(Vec::<BlockInt>::new(), truncate_zeros(x));
 }
 
+#[test]
+fn test_div_last_block_cleared() {
+    // the top block of the remainder is already cleared before the last division step
+    let mut x = parse("265568996408383549344794103276234313664796558863515961599722069100175334455250343351137064210494732346559884531328387003778489794858616666754339590727004788684219842455983976293072896");
+    let y = parse("32592575621351777380295131014550050576823494298654980010178247189663609630744782043094154432");
+    let q = parse("8148143905337944345073782753637512644205873574663745002544561797418336594736922481713614952");
+    let r = parse("5266903642601661330354499466671896301237820805632");
+    let quotient = bigint_div(&mut x, &y, Vec::new());
+    assert_eq!(truncate_zeros(r), truncate_zeros(x));
+    assert_eq!(truncate_zeros(q), truncate_zeros(quotient));
+}
+
 #[test]
 fn test_div_small() {
     let mut x = parse("891023591340178345678931246518793456983745682137459364598623489512389745698237456890239238476873429872346579");
@@ -4,16 +4,20 @@ use crate::pid::{EuclideanRingStore, EuclideanRing};
 use crate::field::{Field, FieldStore};
 use crate::integer::*;
 use crate::ordered::OrderedRingStore;
-use crate::primitive_int::StaticRing;
 use crate::ring::*;
 use crate::homomorphism::*;
 use crate::rings::poly::{PolyRingStore, PolyRing};
-use crate::rings::zn::{ZnRingStore, ZnRing};
-use crate::rings::finite::FiniteRingStore;
+use crate::rings::finite::{FiniteRing, FiniteRingStore};
+#[cfg(test)]
+use crate::rings::zn::ZnRingStore;
 
 use oorandom;
 
-fn pow_mod_f<P, I>(poly_ring: P, g: El<P>, f: &El<P>, pow: &El<I>, ZZ: I) -> El<P>
+///
+/// Computes `g^pow mod f` using square-and-multiply, where all intermediate results are
+/// reduced modulo `f`.
+///
+pub fn pow_mod_f<P, I>(poly_ring: P, g: El<P>, f: &El<P>, pow: &El<I>, ZZ: I) -> El<P>
     where P: PolyRingStore,
         P::Type: PolyRing + EuclideanRing,
         I: IntegerRingStore,
@@ -40,34 +44,23 @@ fn normalize_poly<P>(poly_ring: P, poly: &mut El<P>)
     poly_ring.inclusion().mul_assign_map_ref(poly, &inv_lc);
 }
 
-fn derive_poly<P>(poly_ring: P, poly: &El<P>) -> El<P>
-    where P: PolyRingStore,
-        P::Type: PolyRing
-{
-    poly_ring.from_terms(poly_ring.terms(poly)
-        .filter(|(_, i)| *i > 0)
-        .map(|(c, i)| (poly_ring.base_ring().int_hom().mul_ref_fst_map(c, i as i32), i - 1))
-    )
-}
-
 pub fn distinct_degree_factorization<P>(poly_ring: P, mut f: El<P>) -> Vec<El<P>>
     where P: PolyRingStore,
         P::Type: PolyRing + EuclideanRing,
-        <<P as RingStore>::Type as RingExtension>::BaseRing: ZnRingStore,
-        <<<P as RingStore>::Type as RingExtension>::BaseRing as RingStore>::Type: ZnRing + Field
+        <<<P as RingStore>::Type as RingExtension>::BaseRing as RingStore>::Type: FiniteRing + Field
 {
-    let p = poly_ring.base_ring().modulus();
-    let ZZ = poly_ring.base_ring().integer_ring();
+    let ZZ = BigIntRing::RING;
+    let q = poly_ring.base_ring().size(&ZZ);
     assert!(!poly_ring.is_zero(&f));
 
     let mut result = Vec::new();
     result.push(poly_ring.one());
     let mut x_power_q_mod_f = poly_ring.indeterminate();
     while poly_ring.degree(&f) != Some(0) {
-        // technically, we could just compute gcd(f, X^(p^i) - X), however p^i might be
+        // technically, we could just compute gcd(f, X^(q^i) - X), however q^i might be
         // really large and eea will be very slow. Hence, we do the first modulo operation
-        // X^(p^i) mod f using square-and-multiply in the ring F[X]/(f)
-        x_power_q_mod_f = pow_mod_f(&poly_ring, x_power_q_mod_f, &f, p, ZZ);
+        // X^(q^i) mod f using square-and-multiply in the ring F[X]/(f)
+        x_power_q_mod_f = pow_mod_f(&poly_ring, x_power_q_mod_f, &f, &q, ZZ);
         let fq_defining_poly_mod_f = poly_ring.sub_ref_fst(&x_power_q_mod_f, poly_ring.indeterminate());
         let deg_i_factor = algorithms::eea::gcd(poly_ring.clone_el(&f), poly_ring.clone_el(&fq_defining_poly_mod_f), &poly_ring);
         f = poly_ring.euclidean_div(f, &deg_i_factor);
@@ -77,11 +70,96 @@ pub fn distinct_degree_factorization<P>(poly_ring: P, mut f: El<P>) -> Vec<El<P>
     return result;
 }
 
+///
+/// Checks whether the given polynomial over a finite field is irreducible.
+///
+/// This uses Ben-Or's variant of the distinct-degree factorization, i.e. it checks
+/// that `gcd(X^(q^i) - X, f) = 1` for all `i <= deg(f)/2`, but aborts as soon as a
+/// nontrivial factor is found. In particular, it is fast for random (and thus most
+/// likely reducible) polynomials.
+///
+pub fn is_irreducible<P>(poly_ring: P, f: &El<P>) -> bool
+    where P: PolyRingStore,
+        P::Type: PolyRing + EuclideanRing,
+        <<<P as RingStore>::Type as RingExtension>::BaseRing as RingStore>::Type: FiniteRing + Field
+{
+    let ZZ = BigIntRing::RING;
+    let q = poly_ring.base_ring().size(&ZZ);
+    let n = match poly_ring.degree(f) {
+        None | Some(0) => return false,
+        Some(n) => n
+    };
+    let mut x_power_q_mod_f = poly_ring.indeterminate();
+    for _ in 0..(n / 2) {
+        x_power_q_mod_f = pow_mod_f(&poly_ring, x_power_q_mod_f, f, &q, ZZ);
+        let fq_defining_poly_mod_f = poly_ring.sub_ref_fst(&x_power_q_mod_f, poly_ring.indeterminate());
+        let gcd = algorithms::eea::gcd(poly_ring.clone_el(f), fq_defining_poly_mod_f, &poly_ring);
+        if !poly_ring.is_unit(&gcd) {
+            return false;
+        }
+    }
+    return true;
+}
+
+///
+/// Returns a monic irreducible polynomial of the given degree over a finite field, chosen
+/// uniformly at random using the given source of randomness.
+///
+pub fn random_irreducible<P, G>(poly_ring: P, degree: usize, mut rng: G) -> El<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing + EuclideanRing,
+        <<<P as RingStore>::Type as RingExtension>::BaseRing as RingStore>::Type: FiniteRing + Field,
+        G: FnMut() -> u64
+{
+    assert!(degree >= 1);
+    loop {
+        let f = poly_ring.from_terms(
+            (0..degree).map(|i| (poly_ring.base_ring().random_element(&mut rng), i))
+                .chain(Some((poly_ring.base_ring().one(), degree)))
+        );
+        if is_irreducible(&poly_ring, &f) {
+            return f;
+        }
+    }
+}
+
+///
+/// Returns a monic primitive polynomial of the given degree over a finite field `Fq`, chosen
+/// uniformly at random using the given source of randomness.
+///
+/// A polynomial `f` is primitive, if it is irreducible and the image of `X` generates the
+/// multiplicative group of the field `Fq[X]/(f)`. This requires factoring `q^degree - 1`,
+/// which might be slow if this number is large.
+///
+pub fn random_primitive_polynomial<P, G>(poly_ring: P, degree: usize, mut rng: G) -> El<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing + EuclideanRing,
+        <<<P as RingStore>::Type as RingExtension>::BaseRing as RingStore>::Type: FiniteRing + Field,
+        G: FnMut() -> u64
+{
+    let ZZ = BigIntRing::RING;
+    let q = poly_ring.base_ring().size(&ZZ);
+    let group_order = ZZ.sub(ZZ.pow(q, degree), ZZ.one());
+    let cofactors = algorithms::int_factor::factor(&ZZ, ZZ.clone_el(&group_order)).into_iter()
+        .map(|(r, _)| ZZ.checked_div(&group_order, &r).unwrap())
+        .collect::<Vec<_>>();
+    loop {
+        let f = random_irreducible(&poly_ring, degree, &mut rng);
+        if cofactors.iter().all(|e| !poly_ring.is_one(&pow_mod_f(&poly_ring, poly_ring.indeterminate(), &f, e, ZZ))) {
+            return f;
+        }
+    }
+}
+
 ///
 /// Uses the Cantor-Zassenhaus algorithm to find a nontrivial, factor of a polynomial f
 /// over a finite field, that is squarefree and consists only of irreducible factors of 
 /// degree d.
 /// 
+/// The base field may be any finite field, not necessarily a prime field. In the following
+/// description, we denote it by `Fp`; if it is not a prime field, `p` should be read as the
+/// size of the base field.
+/// 
 /// # Algorithm
 /// 
 /// The algorithm relies on the fact that for some monic polynomial T over Fp have
@@ -113,29 +191,52 @@ pub fn distinct_degree_factorization<P>(poly_ring: P, mut f: El<P>) -> Vec<El<P>
 /// c runs through the columns of A resp. B. There are 2d such columns in total, thus s = 2d
 /// will do (note that all columns are different, as `1, a, ..., a^(d - 1)` is a basis of Fq
 /// and similarly for b). 
+/// 
+/// ## Characteristic 2
+/// 
+/// If `q` is even, then `(q - 1)/2` is not an integer, and we use the trace map instead. More concretely,
+/// we have
+/// ```text
+/// T^q - T = S (S + 1)   where   S = T + T^2 + T^4 + ... + T^(q/2)
+/// ```
+/// and `S(a)` is the trace of `T(a)` over F2. Hence, `S(a)` is either zero or one, and again
+/// `gcd(S, f)` is a nontrivial factor of f with probability approximately 1/2.
 ///
 #[allow(non_snake_case)]
 pub fn cantor_zassenhaus<P>(poly_ring: P, f: El<P>, d: usize) -> El<P>
     where P: PolyRingStore,
         P::Type: PolyRing + EuclideanRing,
-        <<P as RingStore>::Type as RingExtension>::BaseRing: ZnRingStore,
-        <<<P as RingStore>::Type as RingExtension>::BaseRing as RingStore>::Type: ZnRing + Field,
-        <<<<P as RingStore>::Type as RingExtension>::BaseRing as RingStore>::Type as ZnRing>::IntegerRingBase: CanonicalIso<BigIntRingBase>
+        <<<P as RingStore>::Type as RingExtension>::BaseRing as RingStore>::Type: FiniteRing + Field
 {
     let ZZ = BigIntRing::RING;
-    let p = int_cast(poly_ring.base_ring().integer_ring().clone_el(poly_ring.base_ring().modulus()), &ZZ, poly_ring.base_ring().integer_ring());
-    assert!(ZZ.is_odd(&p));
+    let q = poly_ring.base_ring().size(&ZZ);
     assert!(poly_ring.degree(&f).unwrap() % d == 0);
     assert!(poly_ring.degree(&f).unwrap() > d);
-    let mut rng = oorandom::Rand64::new(ZZ.default_hash(&p) as u128);
-    let exp = ZZ.half_exact(ZZ.sub(ZZ.pow(p, d), ZZ.one()));
+    let mut rng = oorandom::Rand64::new(ZZ.default_hash(&q) as u128);
+    let exp = if ZZ.is_odd(&q) {
+        Some(ZZ.half_exact(ZZ.sub(ZZ.pow(ZZ.clone_el(&q), d), ZZ.one())))
+    } else {
+        None
+    };
+    // if `q = 2^k`, the trace `F_(q^d) -> F2` is the sum of `k * d` conjugates
+    let trace_len = ZZ.abs_log2_ceil(&q).unwrap() * d;
 
     loop {
         let T = poly_ring.from_terms(
             (0..(2 * d)).map(|i| (poly_ring.base_ring().random_element(|| rng.rand_u64()), i))
                 .chain(Some((poly_ring.base_ring().one(), 2 * d)))
         );
-        let G = poly_ring.sub(pow_mod_f(&poly_ring, T, &f, &exp, ZZ), poly_ring.one());
+        let G = if let Some(exp) = &exp {
+            poly_ring.sub(pow_mod_f(&poly_ring, T, &f, exp, ZZ), poly_ring.one())
+        } else {
+            let mut current = poly_ring.euclidean_rem(T, &f);
+            let mut trace = poly_ring.clone_el(&current);
+            for _ in 1..trace_len {
+                current = poly_ring.euclidean_rem(poly_ring.pow(current, 2), &f);
+                poly_ring.add_assign_ref(&mut trace, &current);
+            }
+            trace
+        };
         let g = algorithms::eea::gcd(poly_ring.clone_el(&f), G, &poly_ring);
         if !poly_ring.is_unit(&g) && poly_ring.checked_div(&g, &f).is_none() {
             return g;
@@ -143,69 +244,48 @@ pub fn cantor_zassenhaus<P>(poly_ring: P, f: El<P>, d: usize) -> El<P>
     }
 }
 
+///
+/// Computes the square-free part of a nonzero polynomial over a finite field, i.e. the product of all its
+/// irreducible factors, each with multiplicity one. For details, see
+/// [`algorithms::poly_squarefree::squarefree_decomposition_finite_field()`].
+///
 pub fn poly_squarefree_part<P>(poly_ring: P, poly: El<P>) -> El<P>
     where P: PolyRingStore,
         P::Type: PolyRing + EuclideanRing,
-        <P::Type as RingExtension>::BaseRing: ZnRingStore,
-        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: ZnRing
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: FiniteRing + Field
 {
-    assert!(!poly_ring.is_zero(&poly));
-    let derivate = derive_poly(&poly_ring, &poly);
-    if poly_ring.is_zero(&derivate) {
-        let p = int_cast(poly_ring.base_ring().integer_ring().clone_el(poly_ring.base_ring().modulus()), &StaticRing::<i128>::RING, poly_ring.base_ring().integer_ring());
-        let base_poly = poly_ring.from_terms(poly_ring.terms(&poly).map(|(c, i)| (poly_ring.base_ring().clone_el(c), i / p as usize)));
-        return poly_squarefree_part(poly_ring, base_poly);
-    } else {
-        let square_part = algorithms::eea::gcd(poly_ring.clone_el(&poly), derivate, &poly_ring);
-        return poly_ring.checked_div(&poly, &square_part).unwrap();
-    }
+    let decomposition = algorithms::poly_squarefree::squarefree_decomposition_finite_field(&poly_ring, &poly);
+    poly_ring.prod(decomposition.into_iter().map(|(factor, _)| factor))
 }
 
-pub fn factor_complete<'a, P>(poly_ring: P, mut el: El<P>) -> Vec<(El<P>, usize)> 
+pub fn factor_complete<'a, P>(poly_ring: P, el: El<P>) -> Vec<(El<P>, usize)> 
     where P: PolyRingStore,
         P::Type: PolyRing + EuclideanRing,
-        <<P as RingStore>::Type as RingExtension>::BaseRing: ZnRingStore,
-        <<<P as RingStore>::Type as RingExtension>::BaseRing as RingStore>::Type: ZnRing + Field,
-        <<<<P as RingStore>::Type as RingExtension>::BaseRing as RingStore>::Type as ZnRing>::IntegerRingBase: CanonicalIso<BigIntRingBase>
+        <<<P as RingStore>::Type as RingExtension>::BaseRing as RingStore>::Type: FiniteRing + Field
 {
     assert!(!poly_ring.is_zero(&el));
 
     let mut result = Vec::new();
-    let mut unit = poly_ring.base_ring().one();
+    let unit = poly_ring.base_ring().clone_el(poly_ring.lc(&el).unwrap());
 
-    // we repeatedly remove the square-free part
-    while !poly_ring.is_unit(&el) {
-        let sqrfree_part = poly_squarefree_part(&poly_ring, poly_ring.clone_el(&el));
-        assert!(!poly_ring.is_unit(&sqrfree_part));
+    // the factors of the square-free decomposition are monic and pairwise coprime
+    for (sqrfree_part, power) in algorithms::poly_squarefree::squarefree_decomposition_finite_field(&poly_ring, &el) {
 
         // factor the square-free part into distinct-degree factors
-        for (d, factor_d) in distinct_degree_factorization(&poly_ring, poly_ring.clone_el(&sqrfree_part)).into_iter().enumerate() {
+        for (d, factor_d) in distinct_degree_factorization(&poly_ring, sqrfree_part).into_iter().enumerate() {
             let mut stack = Vec::new();
             stack.push(factor_d);
             
             // and finally extract each individual factor
             while let Some(mut current) = stack.pop() {
                 // normalize current
-                let lc = poly_ring.lc(&current).unwrap();
-                poly_ring.base_ring().mul_assign_ref(&mut unit, lc);
-                let lc_inv = poly_ring.base_ring().div(&poly_ring.base_ring().one(), lc);
+                let lc_inv = poly_ring.base_ring().div(&poly_ring.base_ring().one(), poly_ring.lc(&current).unwrap());
                 poly_ring.inclusion().mul_assign_map_ref(&mut current, &lc_inv);
 
                 if poly_ring.is_one(&current) {
                     continue;
                 } else if poly_ring.degree(&current) == Some(d) {
-                    // add to result
-                    let mut found = false;
-                    for (factor, power) in &mut result {
-                        if poly_ring.eq_el(factor, &current) {
-                            *power += 1;
-                            found = true;
-                            break;
-                        }
-                    }
-                    if !found {
-                        result.push((current, 1));
-                    }
+                    result.push((current, power));
                 } else {
                     let factor = cantor_zassenhaus(&poly_ring, poly_ring.clone_el(&current), d);
                     stack.push(poly_ring.checked_div(&current, &factor).unwrap());
@@ -213,10 +293,7 @@ pub fn factor_complete<'a, P>(poly_ring: P, mut el: El<P>) -> Vec<(El<P>, usize)
                 }
             }
         }
-        el = poly_ring.checked_div(&el, &sqrfree_part).unwrap();
     }
-    poly_ring.base_ring().mul_assign_ref(&mut unit, poly_ring.coefficient_at(&el, 0));
-    debug_assert!(!poly_ring.base_ring().is_zero(&unit));
     if !poly_ring.base_ring().is_one(&unit) {
         result.push((poly_ring.inclusion().map(unit), 1));
     }
@@ -228,7 +305,13 @@ use crate::rings::poly::dense_poly::DensePolyRing;
 #[cfg(test)]
 use crate::rings::zn::zn_static::Fp;
 #[cfg(test)]
-use crate::rings::zn::zn_42;
+use crate::rings::zn::{zn_42, zn_64};
+#[cfg(test)]
+use crate::rings::poly::gf2_poly::GF2PolyRing;
+#[cfg(test)]
+use crate::rings::extension::galois_field::GaloisField;
+#[cfg(test)]
+use crate::rings::extension::FreeAlgebraStore;
 
 #[test]
 fn test_poly_squarefree_part() {
@@ -292,3 +375,135 @@ fn test_cantor_zassenhaus() {
     normalize_poly(&ring, &mut factor);
     assert!(ring.eq_el(&factor, &f) || ring.eq_el(&factor, &g));
 }
+
+#[test]
+fn test_is_irreducible() {
+    let ring = DensePolyRing::new(Fp::<2>::RING, "X");
+    assert!(is_irreducible(&ring, &ring.from_terms([(1, 0), (1, 1), (1, 2)].into_iter())));
+    assert!(is_irreducible(&ring, &ring.from_terms([(1, 0), (1, 1), (1, 3)].into_iter())));
+    assert!(is_irreducible(&ring, &ring.from_terms([(1, 0), (1, 1), (1, 4)].into_iter())));
+    assert!(!is_irreducible(&ring, &ring.from_terms([(1, 0), (1, 2)].into_iter())));
+    assert!(!is_irreducible(&ring, &ring.one()));
+    assert!(!is_irreducible(&ring, &ring.zero()));
+    // there are exactly 3 monic irreducible polynomials of degree 4 over `F2`
+    let irreducible_count = (0..16).filter(|i| is_irreducible(&ring, &ring.from_terms(
+        (0..4).map(|j| ((i >> j) & 1, j)).chain(Some((1, 4)))
+    ))).count();
+    assert_eq!(3, irreducible_count);
+}
+
+#[test]
+fn test_random_irreducible() {
+    let ring = DensePolyRing::new(Fp::<7>::RING, "X");
+    let mut rng = oorandom::Rand64::new(1);
+    for d in 1..6 {
+        let f = random_irreducible(&ring, d, || rng.rand_u64());
+        assert_eq!(Some(d), ring.degree(&f));
+        assert!(ring.base_ring().is_one(ring.lc(&f).unwrap()));
+        let factorization = factor_complete(&ring, ring.clone_el(&f));
+        assert_eq!(1, factorization.len());
+        assert_el_eq!(&ring, &f, &factorization[0].0);
+    }
+}
+
+#[test]
+fn test_random_primitive_polynomial() {
+    let ring = DensePolyRing::new(Fp::<2>::RING, "X");
+    let mut rng = oorandom::Rand64::new(1);
+    let primitive = [
+        ring.from_terms([(1, 0), (1, 1), (1, 4)].into_iter()),
+        ring.from_terms([(1, 0), (1, 3), (1, 4)].into_iter())
+    ];
+    for _ in 0..10 {
+        let f = random_primitive_polynomial(&ring, 4, || rng.rand_u64());
+        assert!(primitive.iter().any(|g| ring.eq_el(&f, g)));
+    }
+}
+
+#[test]
+fn test_cantor_zassenhaus_characteristic_two() {
+    let ring = GF2PolyRing::new("X");
+    // `X^4 + X + 1` and `X^4 + X^3 + 1` are both irreducible
+    let f = ring.get_ring().from_words(vec![0b10011]);
+    let g = ring.get_ring().from_words(vec![0b11001]);
+    let p = ring.mul_ref(&f, &g);
+    let factor = cantor_zassenhaus(&ring, p, 4);
+    assert!(ring.eq_el(&factor, &f) || ring.eq_el(&factor, &g));
+
+    let h = ring.prod([&f, &g, &f, &ring.indeterminate(), &ring.add(ring.indeterminate(), ring.one())].into_iter().map(|x| ring.clone_el(x)));
+    let mut factorization = factor_complete(&ring, h);
+    factorization.sort_by_key(|(factor, _)| ring.get_ring().words(factor).to_vec());
+    assert_eq!(4, factorization.len());
+    assert_el_eq!(&ring, &ring.indeterminate(), &factorization[0].0);
+    assert_el_eq!(&ring, &ring.add(ring.indeterminate(), ring.one()), &factorization[1].0);
+    assert_el_eq!(&ring, &f, &factorization[2].0);
+    assert_eq!(2, factorization[2].1);
+    assert_el_eq!(&ring, &g, &factorization[3].0);
+    assert_eq!(1, factorization[3].1);
+}
+
+#[test]
+fn test_factor_complete_extension_field() {
+    // over `F9`, the polynomial `X^9 - X` splits into all linear factors
+    let field = GaloisField::new(3, 2);
+    let ring = DensePolyRing::new(&field, "X");
+    let f = ring.sub(ring.pow(ring.indeterminate(), 9), ring.indeterminate());
+    let factorization = factor_complete(&ring, ring.clone_el(&f));
+    assert_eq!(9, factorization.len());
+    assert!(factorization.iter().all(|(g, e)| ring.degree(g) == Some(1) && *e == 1));
+    assert_el_eq!(&ring, &f, &ring.prod(factorization.iter().map(|(g, e)| ring.pow(ring.clone_el(g), *e))));
+
+    // `X^2 + 1` is irreducible over `F3`, but splits over `F9`
+    let g = ring.from_terms([(field.one(), 0), (field.one(), 2)].into_iter());
+    let h = ring.from_terms([(field.int_hom().map(2), 0), (field.one(), 1), (field.one(), 4)].into_iter());
+    let f = ring.prod([ring.clone_el(&g), ring.clone_el(&g), ring.clone_el(&h)].into_iter());
+    let factorization = factor_complete(&ring, ring.clone_el(&f));
+    assert_el_eq!(&ring, &f, &ring.prod(factorization.iter().map(|(g, e)| ring.pow(ring.clone_el(g), *e))));
+    assert!(factorization.iter().all(|(g, _)| is_irreducible(&ring, g)));
+    assert_eq!(2, factorization.iter().filter(|(g, e)| ring.degree(g) == Some(1) && *e == 2).count());
+}
+
+#[test]
+fn test_factor_complete_large_multiplicities() {
+    // the degrees are large enough that the gcds in the square-free decomposition are computed using the half-gcd algorithm
+    let field = zn_64::Zn::new(65537).as_field().ok().unwrap();
+    let ring = DensePolyRing::new(field, "X");
+    let g = ring.from_terms([(field.one(), 0), (field.one(), 2)].into_iter());
+    let h = ring.from_terms([(field.int_hom().map(-3), 0), (field.one(), 1)].into_iter());
+    let f = ring.mul(ring.pow(ring.clone_el(&g), 600), ring.pow(ring.clone_el(&h), 700));
+
+    let half_gcd_calls = algorithms::half_gcd::half_gcd_eea_calls();
+    let squarefree_part = poly_squarefree_part(&ring, ring.clone_el(&f));
+    let lc_inv = field.div(&field.one(), ring.lc(&squarefree_part).unwrap());
+    assert_el_eq!(&ring, &ring.mul_ref(&g, &h), &ring.inclusion().mul_map(squarefree_part, lc_inv));
+    assert!(algorithms::half_gcd::half_gcd_eea_calls() > half_gcd_calls);
+
+    let half_gcd_calls = algorithms::half_gcd::half_gcd_eea_calls();
+    let factorization = factor_complete(&ring, ring.clone_el(&f));
+    assert!(algorithms::half_gcd::half_gcd_eea_calls() > half_gcd_calls);
+    // `-1` is a square modulo `65537`, so `g` splits into two linear factors
+    assert_eq!(3, factorization.len());
+    assert!(factorization.iter().all(|(g, _)| ring.degree(g) == Some(1)));
+    assert_eq!(2, factorization.iter().filter(|(_, e)| *e == 600).count());
+    assert_el_eq!(&ring, &f, &ring.prod(factorization.iter().map(|(g, e)| ring.pow(ring.clone_el(g), *e))));
+}
+
+#[test]
+fn test_factor_complete_characteristic_two_extension_field() {
+    for field in [GaloisField::new(2, 3), GaloisField::new(2, 4)] {
+        let ring = DensePolyRing::new(&field, "X");
+        let x = field.canonical_gen();
+        // `Y^p` has a zero derivative, so this requires taking `p`-th roots of the coefficients
+        let f = ring.prod([
+            ring.from_terms([(field.clone_el(&x), 0), (field.one(), 2)].into_iter()),
+            ring.from_terms([(field.clone_el(&x), 0), (field.one(), 2)].into_iter()),
+            ring.from_terms([(field.one(), 0), (field.clone_el(&x), 1), (field.one(), 3)].into_iter()),
+            ring.from_terms([(field.add(field.clone_el(&x), field.one()), 0), (field.one(), 1), (field.one(), 5)].into_iter())
+        ].into_iter());
+        let factorization = factor_complete(&ring, ring.clone_el(&f));
+        assert_el_eq!(&ring, &f, &ring.prod(factorization.iter().map(|(g, e)| ring.pow(ring.clone_el(g), *e))));
+        assert!(factorization.iter().all(|(g, _)| is_irreducible(&ring, g)));
+        // `X^2 + θ` is the square of `X + sqrt(θ)`
+        assert!(factorization.iter().any(|(g, e)| ring.degree(g) == Some(1) && *e >= 4));
+    }
+}
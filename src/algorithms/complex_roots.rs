@@ -0,0 +1,299 @@
+use std::f64::consts::PI;
+
+use crate::divisibility::DivisibilityRingStore;
+use crate::ordered::OrderedRingStore;
+use crate::ring::*;
+use crate::rings::float_complex::{Complex64, Complex64El};
+use crate::rings::float_mp::{ComplexMP, ComplexMPEl};
+
+const MAX_ITERATIONS: usize = 1000;
+const MAX_NEWTON_ITERATIONS: usize = 100;
+
+///
+/// Evaluates the polynomial with the given coefficients (in ascending order of degree) and its
+/// derivative at `x`, using Horner's scheme.
+///
+fn evaluate_with_derivative(poly: &[Complex64El], x: Complex64El) -> (Complex64El, Complex64El) {
+    let CC = Complex64::RING;
+    let mut value = CC.zero();
+    let mut derivative = CC.zero();
+    for c in poly.iter().rev() {
+        derivative = CC.add(CC.mul(derivative, x), value);
+        value = CC.add(CC.mul(value, x), *c);
+    }
+    return (value, derivative);
+}
+
+fn is_nonzero(x: Complex64El) -> bool {
+    Complex64::RING.abs(x) > 0.
+}
+
+///
+/// Approximates all complex roots of the polynomial with the given coefficients (in ascending order of
+/// degree), using the Aberth-Ehrlich method. The last coefficient must be nonzero, and roots are returned
+/// with multiplicity.
+///
+/// The Aberth-Ehrlich method improves approximations to all roots simultaneously, and converges cubically
+/// to simple roots. Multiple roots (and clusters of close roots) are found with reduced accuracy and speed;
+/// if necessary, the approximations can afterwards be improved using [`newton_polish()`].
+///
+/// Since this works with `f64` values, the accuracy is limited by the condition of the roots.
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::rings::float_complex::*;
+/// # use feanor_math::algorithms::complex_roots::*;
+/// let CC = Complex64::RING;
+/// // the roots of `X^2 + 1`
+/// let roots = aberth_roots(&[CC.one(), CC.zero(), CC.one()]);
+/// assert_eq!(2, roots.len());
+/// assert!(roots.iter().any(|z| CC.is_absolute_approx_eq(*z, Complex64::I, 1e-12)));
+/// assert!(roots.iter().any(|z| CC.is_absolute_approx_eq(*z, CC.negate(Complex64::I), 1e-12)));
+/// ```
+///
+pub fn aberth_roots(poly: &[Complex64El]) -> Vec<Complex64El> {
+    let CC = Complex64::RING;
+    assert!(poly.len() > 0 && is_nonzero(*poly.last().unwrap()), "the leading coefficient must be nonzero");
+    let n = poly.len() - 1;
+    let lc = poly[n];
+
+    // all roots lie within the Fujiwara bound `2 max |a_i / a_n|^(1 / (n - i))`
+    let radius = 2. * (0..n).map(|i| (CC.abs(poly[i]) / CC.abs(lc)).powf(1. / (n - i) as f64)).fold(0., f64::max);
+    if radius == 0. {
+        return (0..n).map(|_| CC.zero()).collect();
+    }
+    // start on a circle of half the bound around the mean of the roots; the rotation avoids initial values that are
+    // symmetric w.r.t. the real axis, which would make it impossible to converge to non-real roots of real polynomials
+    let center = CC.checked_div(&CC.negate(poly[n - 1]), &CC.mul(lc, CC.from_f64(n as f64))).unwrap();
+    let mut roots = (0..n).map(|k| CC.add(center, CC.mul(CC.from_f64(radius / 2.), CC.exp(CC.from_parts(0., 2. * PI * k as f64 / n as f64 + 0.4))))).collect::<Vec<_>>();
+
+    for _ in 0..MAX_ITERATIONS {
+        let mut max_change: f64 = 0.;
+        for k in 0..n {
+            let (value, derivative) = evaluate_with_derivative(poly, roots[k]);
+            if !is_nonzero(value) {
+                continue;
+            }
+            let repulsion = (0..n).filter(|j| *j != k)
+                .map(|j| CC.sub(roots[k], roots[j]))
+                .filter(|d| is_nonzero(*d))
+                .fold(CC.zero(), |current, d| CC.add(current, CC.checked_div(&CC.one(), &d).unwrap()));
+            // this is the Aberth correction `w / (1 - w * repulsion)` with `w = f(z) / f'(z)`, which avoids a division by `f'(z)`
+            let denominator = CC.sub(derivative, CC.mul(value, repulsion));
+            if !is_nonzero(denominator) {
+                continue;
+            }
+            let change = CC.checked_div(&value, &denominator).unwrap();
+            roots[k] = CC.sub(roots[k], change);
+            max_change = max_change.max(CC.abs(change) / f64::max(1., CC.abs(roots[k])));
+        }
+        if max_change < 4. * f64::EPSILON {
+            break;
+        }
+    }
+    return roots;
+}
+
+///
+/// Improves the given approximation to a root of the polynomial with the given coefficients (in ascending
+/// order of degree) by Newton iteration. The iteration stops as soon as the corrections stop decreasing,
+/// so the result is never worse than the given approximation, up to rounding errors.
+///
+/// This is intended to polish the results of [`aberth_roots()`]; note however that if `root` is not already
+/// close to a root, Newton iteration might converge to another root.
+///
+pub fn newton_polish(poly: &[Complex64El], root: Complex64El) -> Complex64El {
+    let CC = Complex64::RING;
+    let mut current = root;
+    let mut last_change = f64::INFINITY;
+    for _ in 0..MAX_NEWTON_ITERATIONS {
+        let (value, derivative) = evaluate_with_derivative(poly, current);
+        if !is_nonzero(value) || !is_nonzero(derivative) {
+            break;
+        }
+        let change = CC.checked_div(&value, &derivative).unwrap();
+        if !(CC.abs(change) < last_change) {
+            break;
+        }
+        last_change = CC.abs(change);
+        current = CC.sub(current, change);
+        if last_change <= f64::EPSILON * CC.abs(current) {
+            break;
+        }
+    }
+    return current;
+}
+
+fn evaluate_with_derivative_mp(CC: &ComplexMP, poly: &[ComplexMPEl], x: &ComplexMPEl) -> (ComplexMPEl, ComplexMPEl) {
+    let mut value = CC.zero();
+    let mut derivative = CC.zero();
+    for c in poly.iter().rev() {
+        derivative = CC.add(CC.mul_ref_snd(derivative, x), CC.clone_el(&value));
+        value = CC.add_ref_snd(CC.mul_ref_snd(value, x), c);
+    }
+    return (value, derivative);
+}
+
+///
+/// Approximates all complex roots of the polynomial with the given coefficients (in ascending order of
+/// degree) up to the precision of the given ring, using the Aberth-Ehrlich method. The last coefficient
+/// must be nonzero, and roots are returned with multiplicity.
+///
+/// The iteration is started with the `f64` approximations computed by [`aberth_roots()`], and then continued
+/// using arithmetic in [`ComplexMP`], until the corrections are below the precision of the ring. In particular,
+/// this can distinguish roots that are too close to be separated by [`aberth_roots()`]. However, as in the `f64`
+/// case, multiple roots (and clusters of close roots) are only found with reduced accuracy.
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::homomorphism::*;
+/// # use feanor_math::rings::float_mp::*;
+/// # use feanor_math::algorithms::complex_roots::*;
+/// let CC = ComplexMP::new(200);
+/// let RR = CC.real_part_ring();
+/// // the roots of `X^2 - 2`
+/// let roots = aberth_roots_mp(&CC, &[CC.int_hom().map(-2), CC.zero(), CC.one()]);
+/// let sqrt2 = CC.from_parts(RR.sqrt(RR.int_hom().map(2)), RR.zero());
+/// assert!(roots.iter().any(|z| CC.is_approx_eq(CC.clone_el(z), CC.clone_el(&sqrt2), 16)));
+/// assert!(roots.iter().any(|z| CC.is_approx_eq(CC.clone_el(z), CC.negate(CC.clone_el(&sqrt2)), 16)));
+/// ```
+///
+pub fn aberth_roots_mp(CC: &ComplexMP, poly: &[ComplexMPEl]) -> Vec<ComplexMPEl> {
+    let RR = CC.real_part_ring();
+    let to_f64 = |x: &ComplexMPEl| Complex64::RING.from_parts(RR.to_f64(&CC.re(x)), RR.to_f64(&CC.im(x)));
+    let from_f64 = |x: Complex64El| CC.from_parts(RR.from_f64(Complex64::RING.re(x)), RR.from_f64(Complex64::RING.im(x)));
+    let mut roots = aberth_roots(&poly.iter().map(to_f64).collect::<Vec<_>>()).into_iter().map(from_f64).collect::<Vec<_>>();
+    let n = roots.len();
+
+    // stop when all relative corrections are below `2^(4 - precision)`
+    let threshold = RR.pow(RR.from_f64(0.5), CC.precision().saturating_sub(4));
+    for _ in 0..MAX_ITERATIONS {
+        let mut converged = true;
+        for k in 0..n {
+            let (value, derivative) = evaluate_with_derivative_mp(CC, poly, &roots[k]);
+            let repulsion = (0..n).filter(|j| *j != k)
+                .filter_map(|j| CC.checked_div(&CC.one(), &CC.sub_ref(&roots[k], &roots[j])))
+                .fold(CC.zero(), |current, summand| CC.add(current, summand));
+            let denominator = CC.sub(derivative, CC.mul_ref(&value, &repulsion));
+            let change = match CC.checked_div(&value, &denominator) {
+                Some(change) => change,
+                None => continue
+            };
+            CC.sub_assign_ref(&mut roots[k], &change);
+            let abs_root = CC.abs(&roots[k]);
+            let scale = if RR.is_lt(&abs_root, &RR.one()) { RR.one() } else { abs_root };
+            if !RR.is_lt(&CC.abs(&change), &RR.mul(scale, RR.clone_el(&threshold))) {
+                converged = false;
+            }
+        }
+        if converged {
+            break;
+        }
+    }
+    return roots;
+}
+
+#[cfg(test)]
+use crate::homomorphism::*;
+
+#[cfg(test)]
+fn assert_roots_approx_eq(expected: &[Complex64El], actual: &[Complex64El], threshold: f64) {
+    let CC = Complex64::RING;
+    assert_eq!(expected.len(), actual.len());
+    let mut used = actual.iter().map(|_| false).collect::<Vec<_>>();
+    for x in expected {
+        let (i, _) = actual.iter().enumerate()
+            .filter(|(i, _)| !used[*i])
+            .min_by(|(_, a), (_, b)| CC.abs(CC.sub(**a, *x)).partial_cmp(&CC.abs(CC.sub(**b, *x))).unwrap())
+            .unwrap();
+        assert!(CC.is_absolute_approx_eq(*x, actual[i], threshold), "no approximation found for root {}", CC.format(x));
+        used[i] = true;
+    }
+}
+
+#[cfg(test)]
+fn poly_from_roots(roots: &[Complex64El]) -> Vec<Complex64El> {
+    let CC = Complex64::RING;
+    let mut result = vec![CC.one()];
+    for root in roots {
+        result.insert(0, CC.zero());
+        for i in 0..(result.len() - 1) {
+            let summand = CC.mul(result[i + 1], CC.negate(*root));
+            CC.add_assign(&mut result[i], summand);
+        }
+    }
+    return result;
+}
+
+#[test]
+fn test_aberth_roots() {
+    let CC = Complex64::RING;
+
+    let roots = [CC.from_f64(1.), CC.from_f64(-2.), CC.from_parts(0.5, 3.)];
+    assert_roots_approx_eq(&roots, &aberth_roots(&poly_from_roots(&roots)), 1e-12);
+
+    let roots = [CC.from_parts(0., 1.), CC.from_parts(0., -1.), CC.from_parts(3., 1.), CC.from_parts(-1., -2.)];
+    let poly = poly_from_roots(&roots).into_iter().map(|c| CC.mul(c, CC.from_parts(2., -1.))).collect::<Vec<_>>();
+    assert_roots_approx_eq(&roots, &aberth_roots(&poly), 1e-12);
+
+    // roots of unity
+    let n = 32;
+    let poly = (0..=n).map(|i| if i == 0 { CC.from_f64(-1.) } else if i == n { CC.one() } else { CC.zero() }).collect::<Vec<_>>();
+    let roots = (0..n).map(|k| CC.root_of_unity(k, n)).collect::<Vec<_>>();
+    assert_roots_approx_eq(&roots, &aberth_roots(&poly), 1e-12);
+
+    // Wilkinson's polynomial is badly conditioned, so we can only expect limited accuracy
+    let roots = (1..=15).map(|i| CC.from_f64(i as f64)).collect::<Vec<_>>();
+    assert_roots_approx_eq(&roots, &aberth_roots(&poly_from_roots(&roots)), 1e-3);
+
+    // `X^3`
+    assert_roots_approx_eq(&[CC.zero(), CC.zero(), CC.zero()], &aberth_roots(&[CC.zero(), CC.zero(), CC.zero(), CC.one()]), 1e-12);
+}
+
+#[test]
+fn test_newton_polish() {
+    let CC = Complex64::RING;
+    // `(X - 1)^2 (X - 2 - i)`
+    let roots = [CC.one(), CC.one(), CC.from_parts(2., 1.)];
+    let poly = poly_from_roots(&roots);
+    let approximations = aberth_roots(&poly);
+    assert_roots_approx_eq(&roots, &approximations, 1e-6);
+    let polished = approximations.iter().map(|z| newton_polish(&poly, *z)).collect::<Vec<_>>();
+    assert_roots_approx_eq(&roots, &polished, 1e-6);
+
+    // `X^2 - 2`
+    let poly = [CC.from_f64(-2.), CC.zero(), CC.one()];
+    let root = newton_polish(&poly, CC.from_f64(1.4));
+    assert!(CC.is_absolute_approx_eq(CC.from_f64(2f64.sqrt()), root, 1e-15));
+}
+
+#[test]
+fn test_aberth_roots_mp() {
+    let CC = ComplexMP::new(300);
+    let RR = CC.real_part_ring();
+    let poly_from_roots_mp = |roots: &[ComplexMPEl]| roots.iter().fold(vec![CC.one()], |current, root| {
+        let mut result = (0..=current.len()).map(|_| CC.zero()).collect::<Vec<_>>();
+        for (i, c) in current.iter().enumerate() {
+            CC.add_assign(&mut result[i + 1], CC.clone_el(c));
+            CC.sub_assign(&mut result[i], CC.mul_ref(c, root));
+        }
+        result
+    });
+    let assert_roots_approx_eq_mp = |expected: &[ComplexMPEl], actual: &[ComplexMPEl], bits: usize| {
+        assert_eq!(expected.len(), actual.len());
+        for x in expected {
+            let threshold = RR.pow(RR.from_f64(0.5), bits);
+            assert!(actual.iter().any(|y| CC.is_absolute_approx_eq(CC.clone_el(x), CC.clone_el(y), &threshold)), "no approximation found for root {}", CC.format(x));
+        }
+    };
+
+    let roots = [CC.from_f64(1.), CC.from_f64(-2.), CC.from_parts(RR.from_f64(0.5), RR.from_f64(3.)), CC.from_parts(RR.sqrt(RR.int_hom().map(3)), RR.one())];
+    assert_roots_approx_eq_mp(&roots, &aberth_roots_mp(&CC, &poly_from_roots_mp(&roots)), 280);
+
+    // these roots cannot be distinguished using `f64`
+    let eps = RR.pow(RR.from_f64(0.5), 80);
+    let roots = [CC.one(), CC.from_parts(RR.add(RR.one(), eps), RR.zero()), CC.from_f64(-1.)];
+    assert_roots_approx_eq_mp(&roots, &aberth_roots_mp(&CC, &poly_from_roots_mp(&roots)), 200);
+}
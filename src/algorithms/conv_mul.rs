@@ -1,3 +1,5 @@
+use std::cmp::min;
+
 use crate::ring::*;
 use crate::mempool::*;
 use super::karatsuba::*;
@@ -22,7 +24,54 @@ impl<R: RingBase + ?Sized> KaratsubaHint for R {
     }
 }
 
+///
+/// Helper trait that gives rings the ability to compute convolutions of long sequences using a fast
+/// Fourier transform, instead of karatsuba convolution.
+/// 
+/// This is default implemented for all rings to never use FFT-based convolution, and specialized for rings
+/// that support it, usually by calling one of the functions in [`crate::algorithms::fft_mul`]. Currently,
+/// these are
+///  - the primitive integer rings [`crate::primitive_int::StaticRing`] for `i32`, `i64` and `i128`, and the
+///    big integer ring [`crate::rings::rust_bigint::RustBigintRing`], which use the complex FFT for small inputs
+///    and the multi-prime number-theoretic transform otherwise,
+///  - the rings [`crate::rings::zn::zn_64::Zn`], [`crate::rings::zn::zn_42::Zn`], [`crate::rings::zn::zn_static::Zn`]
+///    and [`crate::rings::zn::zn_barett::Zn`] over [`crate::integer::BigIntRing`], which use the number-theoretic
+///    transform if the modulus is a suitable prime, and the multi-prime number-theoretic transform otherwise.
+/// 
+/// All other rings, in particular [`crate::rings::zn::zn_barett::Zn`] over primitive integer rings and
+/// [`crate::rings::zn::zn_rns::Zn`], always use karatsuba convolution.
+/// 
+pub trait FFTConvolutionHint: RingBase {
+
+    ///
+    /// If both inputs have at least this length, [`FFTConvolutionHint::add_assign_fft_convoluted_mul()`]
+    /// is used to compute their convolution.
+    /// 
+    fn fft_convolution_threshold(&self) -> usize;
+
+    ///
+    /// Computes `dst += lhs * rhs`, where `lhs * rhs` is the convolution of `lhs` and `rhs`. If the
+    /// FFT-based convolution is not supported for the given inputs, this returns `false` without modifying
+    /// `dst`, in which case karatsuba convolution is used instead.
+    /// 
+    fn add_assign_fft_convoluted_mul(&self, dst: &mut [Self::Element], lhs: &[Self::Element], rhs: &[Self::Element]) -> bool;
+}
+
+impl<R: RingBase + ?Sized> FFTConvolutionHint for R {
+
+    default fn fft_convolution_threshold(&self) -> usize {
+        usize::MAX
+    }
+
+    default fn add_assign_fft_convoluted_mul(&self, _dst: &mut [Self::Element], _lhs: &[Self::Element], _rhs: &[Self::Element]) -> bool {
+        false
+    }
+}
+
 pub fn add_assign_convoluted_mul<R: RingStore + Copy, M: MemoryProvider<El<R>>>(dst: &mut [El<R>], lhs: &[El<R>], rhs: &[El<R>], ring: R, memory_provider: &M) {
+    if min(lhs.len(), rhs.len()) >= ring.get_ring().fft_convolution_threshold() && ring.get_ring().add_assign_fft_convoluted_mul(dst, lhs, rhs) {
+        return;
+    }
     // checks are done by karatsuba()
     karatsuba(ring.get_ring().karatsuba_threshold(), dst, lhs, rhs, ring, memory_provider);
 }
@@ -6,6 +6,34 @@ use crate::ring::*;
 use std::mem::swap;
 use std::cmp::Ordering;
 
+///
+/// Helper trait that gives euclidean rings the ability to compute Bezout identities using an
+/// algorithm that is asymptotically faster than the standard euclidean algorithm, which is then
+/// used by [`eea()`] and [`gcd()`].
+/// 
+/// This is default implemented for all rings to always use the standard euclidean algorithm, and
+/// specialized for [`crate::rings::poly::dense_poly::DensePolyRingBase`] to use the half-gcd algorithm
+/// for large degrees.
+/// 
+pub trait EEAHint: RingBase {
+
+    ///
+    /// Returns `(s, t, d)` such that `s * lhs + t * rhs = d` is a greatest common divisor of `lhs` and `rhs`,
+    /// or `None` if the standard euclidean algorithm should be used for the given inputs.
+    /// 
+    fn fast_eea(&self, lhs: &Self::Element, rhs: &Self::Element) -> Option<(Self::Element, Self::Element, Self::Element)>
+        where Self: EuclideanRing;
+}
+
+impl<R: RingBase + ?Sized> EEAHint for R {
+
+    default fn fast_eea(&self, _lhs: &Self::Element, _rhs: &Self::Element) -> Option<(Self::Element, Self::Element, Self::Element)>
+        where Self: EuclideanRing
+    {
+        None
+    }
+}
+
 ///
 /// For a, b computes s, t, d such that `s*a + t*b == d` is a greatest 
 /// common divisor of a and b. d is only unique up to units, and s, t 
@@ -13,12 +41,16 @@ use std::cmp::Ordering;
 /// of these solutions is returned. For integers, see signed_eea 
 /// which gives more guarantees.
 /// 
-/// The given ring must be euclidean
+/// The given ring must be euclidean. If the ring provides a faster algorithm
+/// via [`EEAHint`], this is used instead of the standard euclidean algorithm.
 /// 
 pub fn eea<R>(fst: El<R>, snd: El<R>, ring: R) -> (El<R>, El<R>, El<R>) 
     where R: EuclideanRingStore,
         R::Type: EuclideanRing
 {
+    if let Some(result) = ring.get_ring().fast_eea(&fst, &snd) {
+        return result;
+    }
     let (mut a, mut b) = (fst, snd);
     let (mut sa, mut ta) = (ring.one(), ring.zero());
     let (mut sb, mut tb) = (ring.zero(), ring.one());
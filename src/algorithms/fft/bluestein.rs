@@ -262,18 +262,22 @@ impl<R> FFTTable for FFTTableBluestein<R>
     }
 }
 
-impl<R: RingStore<Type = Complex64>> ErrorEstimate for FFTTableBluestein<R> {
+impl<R> ErrorEstimate for FFTTableBluestein<R>
+    where R: RingStore,
+        R::Type: ApproxComplexRing
+{
     
     fn expected_absolute_error(&self, input_bound: f64, input_error: f64) -> f64 {
-        let error_after_twiddling = input_error + input_bound * (root_of_unity_error() + f64::EPSILON);
+        let ring = self.m_fft_table.ring().get_ring();
+        let error_after_twiddling = input_error + input_bound * (ring.root_of_unity_error() + ring.relative_error());
         let error_after_fft = self.m_fft_table.expected_absolute_error(input_bound, error_after_twiddling);
-        let b_bitreverse_fft_error = self.m_fft_table.expected_absolute_error(1., root_of_unity_error());
+        let b_bitreverse_fft_error = self.m_fft_table.expected_absolute_error(1., ring.root_of_unity_error());
         // now the values are increased by up to a factor of m, so use `input_bound * m` instead
         let new_input_bound = input_bound * self.m_fft_table.len() as f64;
         let b_bitreverse_fft_bound = self.m_fft_table.len() as f64;
-        let error_after_mul = new_input_bound * b_bitreverse_fft_error + b_bitreverse_fft_bound * error_after_fft + f64::EPSILON * new_input_bound * b_bitreverse_fft_bound;
+        let error_after_mul = new_input_bound * b_bitreverse_fft_error + b_bitreverse_fft_bound * error_after_fft + ring.relative_error() * new_input_bound * b_bitreverse_fft_bound;
         let error_after_inv_fft = self.m_fft_table.expected_absolute_error(new_input_bound * b_bitreverse_fft_bound, error_after_mul) / self.m_fft_table.len() as f64;
-        let error_end = error_after_inv_fft + new_input_bound * (root_of_unity_error() + f64::EPSILON);
+        let error_end = error_after_inv_fft + new_input_bound * (ring.root_of_unity_error() + ring.relative_error());
         return error_end;
     }
 }
@@ -1,3 +1,4 @@
+use crate::divisibility::DivisibilityRing;
 
 ///
 /// The absolute error in the expression `exp(2 * pi * i * (x / y))`.
@@ -6,10 +7,33 @@ pub fn root_of_unity_error() -> f64 {
     6. * f64::EPSILON
 }
 
+///
+/// Trait for approximate rings that represent the field of complex numbers `C`,
+/// and for which we can bound the error of the basic arithmetic operations.
+/// This is used to provide [`ErrorEstimate`]s for complex-valued FFTs.
+///
+pub trait ApproxComplexRing: DivisibilityRing {
+
+    ///
+    /// An upper bound on the relative error of a single addition or multiplication.
+    ///
+    fn relative_error(&self) -> f64;
+
+    ///
+    /// The absolute error in the expression `exp(2 * pi * i * (x / y))`, as computed
+    /// by this ring.
+    ///
+    fn root_of_unity_error(&self) -> f64 {
+        6. * self.relative_error()
+    }
+}
+
 pub trait ErrorEstimate {
 
     ///
-    /// This is only true if the table is created with the [`crate::rings::float_complex::Complex64`]-specific creator functions.
+    /// This is only true if the table is created with the creator functions specific to approximate
+    /// complex rings, like [`crate::rings::float_complex::Complex64`], [`crate::rings::float_mp::ComplexMP`]
+    /// or [`crate::rings::ball::ComplexBall`].
     /// Note that this is a worst-case estimate and likely to significantly overestimate the error.
     /// 
     /// This estimates the error from [`super::FFTTable::unordered_fft()`]. The error during the inverse
@@ -10,6 +10,8 @@ use crate::homomorphism::*;
 use crate::algorithms::fft::*;
 use crate::algorithms;
 use crate::rings::float_complex::*;
+use crate::rings::float_mp::*;
+use crate::rings::ball::*;
 use super::complex_fft::*;
 
 ///
@@ -107,6 +109,20 @@ impl<R> FFTTableCooleyTuckey<R>
         Self::new_with_pows(ring, |i| CC.root_of_unity(i, 1 << log2_n), log2_n)
     }
 
+    pub fn for_complex_mp(ring: R, log2_n: usize) -> Self
+        where R: RingStore<Type = ComplexMPBase>
+    {
+        let CC = RingValue::from(*ring.get_ring());
+        Self::new_with_pows(ring, |i| CC.root_of_unity(i, 1 << log2_n), log2_n)
+    }
+
+    pub fn for_complex_ball(ring: R, log2_n: usize) -> Self
+        where R: RingStore<Type = ComplexBall>
+    {
+        let CC = ComplexBall::RING;
+        Self::new_with_pows(ring, |i| CC.root_of_unity(i, 1 << log2_n), log2_n)
+    }
+
     pub fn bitreverse_permute_inplace<V, T>(&self, mut values: V) 
         where V: SwappableVectorViewMut<T>
     {
@@ -341,11 +357,15 @@ impl<R> FFTTable for FFTTableCooleyTuckey<R>
     }
 }
 
-impl<R: RingStore<Type = Complex64>> ErrorEstimate for FFTTableCooleyTuckey<R> {
+impl<R> ErrorEstimate for FFTTableCooleyTuckey<R>
+    where R: RingStore,
+        R::Type: ApproxComplexRing
+{
     
     fn expected_absolute_error(&self, input_bound: f64, input_error: f64) -> f64 {
+        let ring = self.ring.get_ring();
         // each butterfly doubles the error, and then adds up to 
-        let butterfly_absolute_error = input_bound * (root_of_unity_error() + f64::EPSILON);
+        let butterfly_absolute_error = input_bound * (ring.root_of_unity_error() + ring.relative_error());
         // the operator inf-norm of the FFT is its length
         return 2. * self.len() as f64 * butterfly_absolute_error + self.len() as f64 * input_error;
     }
@@ -494,6 +514,24 @@ fn test_approximate_fft() {
     }
 }
 
+#[test]
+fn test_approximate_fft_mp() {
+    let CC = ComplexMP::new(200);
+    for log2_n in [4, 7] {
+        let fft = FFTTableCooleyTuckey::for_complex_mp(&CC, log2_n);
+        let mut array = default_memory_provider!().get_new_init(1 << log2_n, |i| CC.root_of_unity(i as i64, 1 << log2_n));
+        fft.fft(&mut array, &default_memory_provider!(), &CC.identity());
+        let err = fft.expected_absolute_error(1., 0.);
+        assert!(err < 1e-50);
+        let err = CC.real_part_ring().from_f64(err);
+        assert!(CC.is_absolute_approx_eq(CC.clone_el(&array[0]), CC.zero(), &err));
+        assert!(CC.is_absolute_approx_eq(CC.clone_el(&array[1]), CC.int_hom().map(fft.len() as i32), &err));
+        for i in 2..fft.len() {
+            assert!(CC.is_absolute_approx_eq(CC.clone_el(&array[i]), CC.zero(), &err));
+        }
+    }
+}
+
 #[test]
 fn test_size_1_fft() {
     let ring = Fp::<17>::RING;
@@ -3,7 +3,6 @@ use crate::homomorphism::*;
 use crate::mempool::*;
 use crate::algorithms::fft::*;
 use crate::algorithms::fft::complex_fft::*;
-use crate::rings::float_complex::*;
 use crate::vector::subvector::*;
 
 pub struct FFTTableGenCooleyTuckey<R, T1, T2> 
@@ -174,14 +173,16 @@ impl<R, T1, T2> FFTTable for FFTTableGenCooleyTuckey<R, T1, T2>
 }
 
 impl<R, T1, T2> ErrorEstimate for FFTTableGenCooleyTuckey<R, T1, T2> 
-    where R: RingStore<Type = Complex64>, 
+    where R: RingStore, 
+        R::Type: ApproxComplexRing,
         T1: FFTTable<Ring = R> + ErrorEstimate, 
         T2: FFTTable<Ring = R> + ErrorEstimate
 {
     fn expected_absolute_error(&self, input_bound: f64, input_error: f64) -> f64 {
+        let ring = self.left_table.ring().get_ring();
         let error_after_first_fft = self.left_table.expected_absolute_error(input_bound, input_error);
         let new_input_bound = self.left_table.len() as f64 * input_bound;
-        let error_after_twiddling = error_after_first_fft + new_input_bound * (root_of_unity_error() + f64::EPSILON);
+        let error_after_twiddling = error_after_first_fft + new_input_bound * (ring.root_of_unity_error() + ring.relative_error());
         return self.right_table.expected_absolute_error(new_input_bound, error_after_twiddling);
     }
 }
@@ -194,6 +195,8 @@ use crate::algorithms;
 use crate::rings::zn::zn_42;
 #[cfg(test)]
 use crate::default_memory_provider;
+#[cfg(test)]
+use crate::rings::float_complex::*;
 
 #[test]
 fn test_fft_basic() {
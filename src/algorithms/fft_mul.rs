@@ -0,0 +1,538 @@
+use std::cell::OnceCell;
+use std::cmp::{max, min};
+
+use crate::algorithms;
+use crate::algorithms::fft::FFTTable;
+use crate::algorithms::fft::complex_fft::{ApproxComplexRing, ErrorEstimate};
+use crate::algorithms::fft::cooley_tuckey::FFTTableCooleyTuckey;
+use crate::default_memory_provider;
+use crate::divisibility::DivisibilityRingStore;
+use crate::integer::*;
+use crate::ordered::OrderedRingStore;
+use crate::pid::EuclideanRingStore;
+use crate::primitive_int::StaticRingBase;
+use crate::ring::*;
+use crate::homomorphism::*;
+use crate::rings::float_complex::Complex64;
+use crate::rings::zn::{ZnRing, ZnRingStore, zn_64};
+
+///
+/// Primes of the form `c * 2^32 + 1` with `2^57 < p < 2^58`, which are used by
+/// [`add_assign_multi_prime_convoluted_mul()`].
+///
+const NTT_PRIMES: [u64; 32] = [
+    288230358971842561, 288230273072496641, 288230255892627457, 288230191468118017,
+    288230165698314241, 288230152813412353, 288230092683870209, 288229985309687809,
+    288229950949949441, 288229938065047553, 288229912295243777, 288229783446224897,
+    288229637417336833, 288229590172696577, 288229585877729281, 288229577287794689,
+    288229469913612289, 288229263755182081, 288229203625639937, 288229122021261313,
+    288229083366555649, 288229036121915393, 288228984582307841, 288228933042700289,
+    288228645279891457, 288228301682507777, 288228258732834817, 288228232963031041,
+    288228052574404609, 288227876480745473, 288227859300876289, 288227781991464961
+];
+
+const NTT_PRIME_BITS: usize = 57;
+
+fn log2_ceil(n: usize) -> usize {
+    assert!(n > 0);
+    (usize::BITS - (n - 1).leading_zeros()) as usize
+}
+
+///
+/// Returns whether [`add_assign_ntt_convoluted_mul()`] can be used to compute the convolution
+/// of sequences of the given lengths over the given ring `Z/nZ`, i.e. whether `n` is a prime and
+/// `Z/nZ` contains a primitive `2^k`-th root of unity for some `2^k >= lhs_len + rhs_len - 1`.
+///
+pub fn ntt_convolution_supported<R>(ring: R, lhs_len: usize, rhs_len: usize) -> bool
+    where R: ZnRingStore,
+        R::Type: ZnRing
+{
+    if lhs_len == 0 || rhs_len == 0 {
+        return true;
+    }
+    return log2_ceil(lhs_len + rhs_len - 1) <= two_adic_valuation(&ring) && ring.is_field();
+}
+
+///
+/// Returns the largest `k` such that `2^k` divides `n - 1`, where `n` is the modulus of `Z/nZ`.
+///
+fn two_adic_valuation<R>(ring: R) -> usize
+    where R: ZnRingStore,
+        R::Type: ZnRing
+{
+    let ZZ = ring.integer_ring();
+    let modulus_minus_one = ZZ.sub_ref_fst(ring.modulus(), ZZ.one());
+    return ZZ.abs_lowest_set_bit(&modulus_minus_one).unwrap_or(0);
+}
+
+///
+/// Computes convolutions over a prime field `Z/pZ` using the number-theoretic transform, i.e. the
+/// Cooley-Tuckey FFT over `Z/pZ`.
+///
+/// The tables for the transforms are created when they are first needed and then stored in this object.
+/// Hence, if many convolutions over the same ring are computed, it is more efficient to create an
+/// `NTTConvolution` once and reuse it, instead of calling [`add_assign_ntt_convoluted_mul()`] repeatedly.
+/// Since the transform length is bounded by the largest power of two dividing `p - 1`, an `NTTConvolution`
+/// stores at most 64 tables.
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::homomorphism::*;
+/// # use feanor_math::rings::zn::*;
+/// # use feanor_math::rings::zn::zn_64::*;
+/// # use feanor_math::algorithms::fft_mul::*;
+/// let ring = Zn::new(65537);
+/// let convolution = NTTConvolution::new(&ring).unwrap();
+/// let lhs = [ring.int_hom().map(1), ring.int_hom().map(2)];
+/// let rhs = [ring.int_hom().map(3), ring.int_hom().map(4)];
+/// let mut result = [ring.zero(), ring.zero(), ring.zero()];
+/// convolution.add_assign_convoluted_mul(&mut result, &lhs, &rhs);
+/// assert_eq!(vec![3, 10, 8], result.iter().map(|x| ring.smallest_positive_lift(*x)).collect::<Vec<_>>());
+/// ```
+///
+pub struct NTTConvolution<R>
+    where R: ZnRingStore + Clone,
+        R::Type: ZnRing
+{
+    ring: R,
+    tables: Vec<OnceCell<FFTTableCooleyTuckey<R>>>
+}
+
+impl<R> NTTConvolution<R>
+    where R: ZnRingStore + Clone,
+        R::Type: ZnRing,
+        <R::Type as ZnRing>::IntegerRingBase: CanHomFrom<StaticRingBase<i64>>
+{
+    ///
+    /// Creates a new `NTTConvolution` over the given ring `Z/nZ`, or returns `None` if `n` is not
+    /// a prime, or `n - 1` is odd.
+    ///
+    pub fn new(ring: R) -> Option<Self> {
+        let max_log2_n = two_adic_valuation(&ring);
+        if max_log2_n == 0 || !ring.is_field() {
+            return None;
+        }
+        return Some(NTTConvolution {
+            tables: (0..=max_log2_n).map(|_| OnceCell::new()).collect(),
+            ring: ring
+        });
+    }
+
+    pub fn ring(&self) -> &R {
+        &self.ring
+    }
+
+    ///
+    /// Returns whether this object can compute the convolution of sequences of the given lengths, i.e.
+    /// whether `Z/pZ` contains a primitive `2^k`-th root of unity for some `2^k >= lhs_len + rhs_len - 1`.
+    ///
+    pub fn is_supported(&self, lhs_len: usize, rhs_len: usize) -> bool {
+        lhs_len == 0 || rhs_len == 0 || log2_ceil(lhs_len + rhs_len - 1) < self.tables.len()
+    }
+
+    ///
+    /// Returns the table for the transform of length `2^log2_n`, and creates it if this has not
+    /// been done before.
+    ///
+    fn table(&self, log2_n: usize) -> &FFTTableCooleyTuckey<R> {
+        self.tables[log2_n].get_or_init(|| create_ntt_table(self.ring.clone(), log2_n))
+    }
+
+    ///
+    /// Computes `dst += lhs * rhs`, where `lhs * rhs` is the convolution of `lhs` and `rhs`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the transform length required for the given sequences is not supported,
+    /// see [`NTTConvolution::is_supported()`], or if `dst` has length less than `lhs.len() + rhs.len() - 1`.
+    ///
+    pub fn add_assign_convoluted_mul(&self, dst: &mut [El<R>], lhs: &[El<R>], rhs: &[El<R>]) {
+        if lhs.len() == 0 || rhs.len() == 0 {
+            return;
+        }
+        assert!(self.is_supported(lhs.len(), rhs.len()));
+        let ring = &self.ring;
+        let out_len = lhs.len() + rhs.len() - 1;
+        assert!(dst.len() >= out_len);
+        // `FFTTableCooleyTuckey` does not support transforms of length 1
+        let log2_n = max(log2_ceil(out_len), 1);
+        let table = self.table(log2_n);
+        let pad = |values: &[El<R>]| (0..(1 << log2_n)).map(|i| if i < values.len() { ring.clone_el(&values[i]) } else { ring.zero() }).collect::<Vec<_>>();
+        let mut lhs_fft = pad(lhs);
+        let mut rhs_fft = pad(rhs);
+        table.unordered_fft(&mut lhs_fft[..], &default_memory_provider!(), &ring.identity());
+        table.unordered_fft(&mut rhs_fft[..], &default_memory_provider!(), &ring.identity());
+        for (x, y) in lhs_fft.iter_mut().zip(rhs_fft.iter()) {
+            ring.mul_assign_ref(x, y);
+        }
+        table.unordered_inv_fft(&mut lhs_fft[..], &default_memory_provider!(), &ring.identity());
+        for (d, x) in dst.iter_mut().zip(lhs_fft.into_iter()).take(out_len) {
+            ring.add_assign(d, x);
+        }
+    }
+}
+
+///
+/// Computes `dst += lhs * rhs`, where `lhs * rhs` is the convolution of `lhs` and `rhs`, using the
+/// number-theoretic transform, i.e. the Cooley-Tuckey FFT over the ring `Z/nZ`.
+///
+/// This creates the table for the transform on each call. To compute many convolutions over the same
+/// ring, use [`NTTConvolution`] instead.
+///
+/// # Panics
+///
+/// This function panics if `Z/nZ` does not contain a suitable root of unity, see [`ntt_convolution_supported()`],
+/// or if `dst` has length less than `lhs.len() + rhs.len() - 1`.
+///
+pub fn add_assign_ntt_convoluted_mul<R>(ring: R, dst: &mut [El<R>], lhs: &[El<R>], rhs: &[El<R>])
+    where R: ZnRingStore + Clone,
+        R::Type: ZnRing,
+        <R::Type as ZnRing>::IntegerRingBase: CanHomFrom<StaticRingBase<i64>>
+{
+    if lhs.len() == 0 || rhs.len() == 0 {
+        return;
+    }
+    let convolution = NTTConvolution::new(ring).expect("number-theoretic transform requires a prime modulus p with 2 | p - 1");
+    convolution.add_assign_convoluted_mul(dst, lhs, rhs);
+}
+
+///
+/// Creates the table for the number-theoretic transform of length `2^log2_n`. In contrast to
+/// [`FFTTableCooleyTuckey::for_zn()`], this computes the powers of the root of unity iteratively,
+/// which avoids a large number of exponentiations and inversions.
+///
+fn create_ntt_table<R>(ring: R, log2_n: usize) -> FFTTableCooleyTuckey<R>
+    where R: ZnRingStore + Clone,
+        R::Type: ZnRing,
+        <R::Type as ZnRing>::IntegerRingBase: CanHomFrom<StaticRingBase<i64>>
+{
+    let n = 1 << log2_n;
+    let root_of_unity = algorithms::unity_root::get_prim_root_of_unity_pow2(&ring, log2_n).unwrap();
+    let mut root_of_unity_pows = Vec::with_capacity(n);
+    root_of_unity_pows.push(ring.one());
+    for i in 1..n {
+        root_of_unity_pows.push(ring.mul_ref(&root_of_unity_pows[i - 1], &root_of_unity));
+    }
+    let root_of_unity_pow = |i: i64| ring.clone_el(&root_of_unity_pows[i.rem_euclid(n as i64) as usize]);
+    return FFTTableCooleyTuckey::new_with_pows(ring.clone(), root_of_unity_pow, log2_n);
+}
+
+fn bitlength<I>(ZZ: I, values: &[El<I>]) -> usize
+    where I: IntegerRingStore,
+        I::Type: IntegerRing
+{
+    values.iter().map(|x| ZZ.abs_highest_set_bit(x).map(|b| b + 1).unwrap_or(0)).max().unwrap_or(0)
+}
+
+///
+/// Returns whether [`add_assign_multi_prime_convoluted_mul()`] supports computing the convolution
+/// of the given sequences of integers, i.e. whether the result is small enough to be reconstructed
+/// from its values modulo the fixed set of primes used by the algorithm.
+///
+pub fn multi_prime_convolution_supported<I>(ZZ: I, lhs: &[El<I>], rhs: &[El<I>]) -> bool
+    where I: IntegerRingStore,
+        I::Type: IntegerRing
+{
+    required_prime_count(&ZZ, lhs, rhs) <= NTT_PRIMES.len()
+}
+
+fn required_prime_count<I>(ZZ: I, lhs: &[El<I>], rhs: &[El<I>]) -> usize
+    where I: IntegerRingStore,
+        I::Type: IntegerRing
+{
+    if lhs.len() == 0 || rhs.len() == 0 {
+        return 0;
+    }
+    // each entry of the result has absolute value `< min(len) 2^(lhs_bits + rhs_bits)`, and we need to distinguish
+    // positive from negative values
+    let result_bits = bitlength(&ZZ, lhs) + bitlength(&ZZ, rhs) + log2_ceil(min(lhs.len(), rhs.len())) + 1;
+    return result_bits / NTT_PRIME_BITS + 1;
+}
+
+///
+/// Computes `dst += lhs * rhs` for sequences of integers, where `lhs * rhs` is the convolution of `lhs` and `rhs`.
+/// This works by computing the convolution modulo sufficiently many primes `p` for which `Z/pZ` supports the
+/// number-theoretic transform, using [`add_assign_ntt_convoluted_mul()`], and reconstructing the result using the
+/// Chinese remainder theorem.
+///
+/// # Panics
+///
+/// This function panics if the result is too large to be reconstructed using the fixed set of primes used by this
+/// algorithm, see [`multi_prime_convolution_supported()`], or if `dst` has length less than `lhs.len() + rhs.len() - 1`.
+///
+pub fn add_assign_multi_prime_convoluted_mul<I>(ZZ: I, dst: &mut [El<I>], lhs: &[El<I>], rhs: &[El<I>])
+    where I: IntegerRingStore,
+        I::Type: IntegerRing
+{
+    if lhs.len() == 0 || rhs.len() == 0 {
+        return;
+    }
+    let out_len = lhs.len() + rhs.len() - 1;
+    assert!(dst.len() >= out_len);
+    let prime_count = required_prime_count(&ZZ, lhs, rhs);
+    assert!(prime_count <= NTT_PRIMES.len(), "result of convolution is too large for multi-prime convolution");
+
+    let ZZbig = BigIntRing::RING;
+    let lhs = lhs.iter().map(|x| int_cast(ZZ.clone_el(x), &ZZbig, &ZZ)).collect::<Vec<_>>();
+    let rhs = rhs.iter().map(|x| int_cast(ZZ.clone_el(x), &ZZbig, &ZZ)).collect::<Vec<_>>();
+    let mut result = (0..out_len).map(|_| ZZbig.zero()).collect::<Vec<_>>();
+    let mut modulus = ZZbig.one();
+    for p in &NTT_PRIMES[..prime_count] {
+        let Fp = zn_64::Zn::new(*p);
+        let lhs_mod_p = lhs.iter().map(|x| Fp.coerce(&ZZbig, ZZbig.clone_el(x))).collect::<Vec<_>>();
+        let rhs_mod_p = rhs.iter().map(|x| Fp.coerce(&ZZbig, ZZbig.clone_el(x))).collect::<Vec<_>>();
+        let mut result_mod_p = (0..out_len).map(|_| Fp.zero()).collect::<Vec<_>>();
+        add_assign_ntt_convoluted_mul(&Fp, &mut result_mod_p[..], &lhs_mod_p[..], &rhs_mod_p[..]);
+        // we know `result mod modulus`, and add the multiple of `modulus` that gives the right value modulo `p`
+        let modulus_inv = Fp.invert(&Fp.coerce(&ZZbig, ZZbig.clone_el(&modulus))).unwrap();
+        for (x, x_mod_p) in result.iter_mut().zip(result_mod_p.into_iter()) {
+            let correction = Fp.mul_ref_snd(Fp.sub(x_mod_p, Fp.coerce(&ZZbig, ZZbig.clone_el(x))), &modulus_inv);
+            ZZbig.add_assign(x, ZZbig.mul_ref_snd(int_cast(Fp.smallest_positive_lift(correction), &ZZbig, Fp.integer_ring()), &modulus));
+        }
+        ZZbig.mul_assign(&mut modulus, int_cast(*p as i64, &ZZbig, Fp.integer_ring()));
+    }
+    let half_modulus = ZZbig.half_exact(ZZbig.sub_ref_fst(&modulus, ZZbig.one()));
+    for (d, x) in dst.iter_mut().zip(result.into_iter()) {
+        let x = if ZZbig.is_gt(&x, &half_modulus) { ZZbig.sub_ref_snd(x, &modulus) } else { x };
+        ZZ.add_assign(d, int_cast(x, &ZZ, &ZZbig));
+    }
+}
+
+///
+/// Computes `dst += lhs * rhs` for sequences of integers, where `lhs * rhs` is the convolution of `lhs` and `rhs`,
+/// using a complex-valued FFT over [`Complex64`].
+///
+/// Since floating point arithmetic is imprecise, the result is only guaranteed to be correct if the input is
+/// small enough. Hence, this function first uses [`ErrorEstimate`] to bound the error of the computation, and
+/// if this bound is not small enough to guarantee the correctness of the result, it returns `false` without
+/// modifying `dst`. Since the error estimate is a worst-case estimate, this is only the case for inputs with
+/// entries of at most a few bits.
+///
+pub fn add_assign_complex_fft_convoluted_mul<I>(ZZ: I, dst: &mut [El<I>], lhs: &[El<I>], rhs: &[El<I>]) -> bool
+    where I: IntegerRingStore,
+        I::Type: IntegerRing
+{
+    if lhs.len() == 0 || rhs.len() == 0 {
+        return true;
+    }
+    let out_len = lhs.len() + rhs.len() - 1;
+    assert!(dst.len() >= out_len);
+    let CC = Complex64::RING;
+    let log2_n = max(1, log2_ceil(out_len));
+    let table = FFTTableCooleyTuckey::for_complex(CC, log2_n);
+    let n = table.len() as f64;
+
+    let bound = |values: &[El<I>]| values.iter().map(|x| ZZ.to_float_approx(x).abs()).fold(0., f64::max);
+    let (lhs_bound, rhs_bound) = (bound(lhs), bound(rhs));
+    let relative_error = CC.get_ring().relative_error();
+    let lhs_fft_error = table.expected_absolute_error(lhs_bound, lhs_bound * relative_error);
+    let rhs_fft_error = table.expected_absolute_error(rhs_bound, rhs_bound * relative_error);
+    let (lhs_fft_bound, rhs_fft_bound) = (n * lhs_bound, n * rhs_bound);
+    let product_error = lhs_fft_bound * rhs_fft_error + rhs_fft_bound * lhs_fft_error + lhs_fft_error * rhs_fft_error + relative_error * lhs_fft_bound * rhs_fft_bound;
+    let result_error = table.expected_absolute_error(lhs_fft_bound * rhs_fft_bound, product_error) / n;
+    if result_error >= 0.5 {
+        return false;
+    }
+
+    let pad = |values: &[El<I>]| (0..(1 << log2_n)).map(|i| if i < values.len() { CC.from_f64(ZZ.to_float_approx(&values[i])) } else { CC.zero() }).collect::<Vec<_>>();
+    let mut lhs_fft = pad(lhs);
+    let mut rhs_fft = pad(rhs);
+    table.unordered_fft(&mut lhs_fft[..], &default_memory_provider!(), &CC.identity());
+    table.unordered_fft(&mut rhs_fft[..], &default_memory_provider!(), &CC.identity());
+    for (x, y) in lhs_fft.iter_mut().zip(rhs_fft.iter()) {
+        CC.mul_assign_ref(x, y);
+    }
+    table.unordered_inv_fft(&mut lhs_fft[..], &default_memory_provider!(), &CC.identity());
+    for (d, x) in dst.iter_mut().zip(lhs_fft.into_iter()).take(out_len) {
+        ZZ.add_assign(d, ZZ.from_float_approx(CC.re(x).round()).unwrap());
+    }
+    return true;
+}
+
+///
+/// Computes `dst += lhs * rhs` for sequences of integers, using the complex-valued FFT if it is guaranteed to give
+/// the correct result, and the multi-prime number-theoretic transform otherwise. If the result is too large for both
+/// methods, `false` is returned and `dst` is not modified.
+///
+/// This is used to implement [`crate::algorithms::conv_mul::FFTConvolutionHint`] for integer rings.
+///
+pub fn add_assign_integer_fft_convoluted_mul<I>(ZZ: I, dst: &mut [El<I>], lhs: &[El<I>], rhs: &[El<I>]) -> bool
+    where I: IntegerRingStore,
+        I::Type: IntegerRing
+{
+    if add_assign_complex_fft_convoluted_mul(&ZZ, dst, lhs, rhs) {
+        return true;
+    } else if multi_prime_convolution_supported(&ZZ, lhs, rhs) {
+        add_assign_multi_prime_convoluted_mul(&ZZ, dst, lhs, rhs);
+        return true;
+    } else {
+        return false;
+    }
+}
+
+///
+/// Computes `dst += lhs * rhs` for sequences of elements of `Z/nZ`, using the number-theoretic transform
+/// if `Z/nZ` supports it, and otherwise by computing the convolution of the smallest positive lifts over
+/// the integers, using [`add_assign_multi_prime_convoluted_mul()`]. If the result is too large for both
+/// methods, `false` is returned and `dst` is not modified.
+///
+/// This is used to implement [`crate::algorithms::conv_mul::FFTConvolutionHint`] for implementations of `Z/nZ`.
+///
+pub fn add_assign_zn_fft_convoluted_mul<R>(ring: R, dst: &mut [El<R>], lhs: &[El<R>], rhs: &[El<R>]) -> bool
+    where R: ZnRingStore,
+        R::Type: ZnRing,
+        <R::Type as ZnRing>::IntegerRingBase: CanHomFrom<StaticRingBase<i64>>
+{
+    if let Some(convolution) = NTTConvolution::new(&ring).filter(|convolution| convolution.is_supported(lhs.len(), rhs.len())) {
+        convolution.add_assign_convoluted_mul(dst, lhs, rhs);
+        return true;
+    }
+    let ZZ = ring.integer_ring();
+    let lhs_lift = lhs.iter().map(|x| ring.smallest_positive_lift(ring.clone_el(x))).collect::<Vec<_>>();
+    let rhs_lift = rhs.iter().map(|x| ring.smallest_positive_lift(ring.clone_el(x))).collect::<Vec<_>>();
+    let ZZbig = BigIntRing::RING;
+    if !multi_prime_convolution_supported(ZZ, &lhs_lift[..], &rhs_lift[..]) {
+        return false;
+    }
+    let lhs_lift = lhs_lift.into_iter().map(|x| int_cast(x, &ZZbig, ZZ)).collect::<Vec<_>>();
+    let rhs_lift = rhs_lift.into_iter().map(|x| int_cast(x, &ZZbig, ZZ)).collect::<Vec<_>>();
+    let mut result = (0..(lhs.len() + rhs.len() - 1)).map(|_| ZZbig.zero()).collect::<Vec<_>>();
+    add_assign_multi_prime_convoluted_mul(&ZZbig, &mut result[..], &lhs_lift[..], &rhs_lift[..]);
+    let modulus = int_cast(ZZ.clone_el(ring.modulus()), &ZZbig, ZZ);
+    for (d, x) in dst.iter_mut().zip(result.into_iter()) {
+        ring.add_assign(d, ring.coerce(ZZ, int_cast(ZZbig.euclidean_rem(x, &modulus), ZZ, &ZZbig)));
+    }
+    return true;
+}
+
+#[cfg(test)]
+use crate::rings::zn::{zn_barett, zn_42, zn_static};
+#[cfg(test)]
+use crate::primitive_int::StaticRing;
+#[cfg(test)]
+use crate::algorithms::conv_mul::FFTConvolutionHint;
+#[cfg(test)]
+use crate::algorithms::karatsuba::karatsuba;
+
+#[cfg(test)]
+fn naive_convolution<R: RingStore>(ring: R, lhs: &[El<R>], rhs: &[El<R>]) -> Vec<El<R>> {
+    let mut result = (0..(lhs.len() + rhs.len())).map(|_| ring.zero()).collect::<Vec<_>>();
+    karatsuba(usize::MAX, &mut result[..], lhs, rhs, &ring, &default_memory_provider!());
+    result.pop();
+    return result;
+}
+
+#[test]
+fn test_ntt_convoluted_mul() {
+    let ring = zn_64::Zn::new(65537);
+    let lhs = (0..100).map(|i| ring.int_hom().map(i * i + 1)).collect::<Vec<_>>();
+    let rhs = (0..37).map(|i| ring.int_hom().map(3 * i - 7)).collect::<Vec<_>>();
+    assert!(ntt_convolution_supported(&ring, lhs.len(), rhs.len()));
+    let mut actual = (0..136).map(|_| ring.one()).collect::<Vec<_>>();
+    add_assign_ntt_convoluted_mul(&ring, &mut actual[..], &lhs[..], &rhs[..]);
+    let expected = naive_convolution(&ring, &lhs[..], &rhs[..]);
+    for i in 0..136 {
+        assert_el_eq!(&ring, &ring.add_ref_fst(&expected[i], ring.one()), &actual[i]);
+    }
+
+    // the second call reuses the table created by the first one
+    let convolution = NTTConvolution::new(&ring).unwrap();
+    assert!(convolution.is_supported(lhs.len(), rhs.len()));
+    for _ in 0..2 {
+        let mut actual = (0..136).map(|_| ring.zero()).collect::<Vec<_>>();
+        convolution.add_assign_convoluted_mul(&mut actual[..], &lhs[..], &rhs[..]);
+        for i in 0..136 {
+            assert_el_eq!(&ring, &expected[i], &actual[i]);
+        }
+    }
+
+    let mut actual = [ring.one()];
+    add_assign_ntt_convoluted_mul(&ring, &mut actual[..], &[ring.int_hom().map(3)], &[ring.int_hom().map(5)]);
+    assert_el_eq!(&ring, &ring.int_hom().map(16), &actual[0]);
+
+    assert!(!ntt_convolution_supported(zn_64::Zn::new(65537), 1 << 16, 2));
+    assert!(!ntt_convolution_supported(zn_64::Zn::new(7), 4, 4));
+    assert!(!convolution.is_supported(1 << 16, 2));
+    assert!(NTTConvolution::new(zn_64::Zn::new(65535)).is_none());
+}
+
+#[test]
+fn test_multi_prime_convoluted_mul() {
+    let ZZ = BigIntRing::RING;
+    let lhs = (0..50).map(|i| ZZ.sub(ZZ.power_of_two(100 + i), ZZ.int_hom().map(i as i32))).collect::<Vec<_>>();
+    let rhs = (0..70).map(|i| ZZ.negate(ZZ.power_of_two(3 * i))).collect::<Vec<_>>();
+    assert!(multi_prime_convolution_supported(&ZZ, &lhs[..], &rhs[..]));
+    let mut actual = (0..119).map(|_| ZZ.zero()).collect::<Vec<_>>();
+    add_assign_multi_prime_convoluted_mul(&ZZ, &mut actual[..], &lhs[..], &rhs[..]);
+    let expected = naive_convolution(&ZZ, &lhs[..], &rhs[..]);
+    for i in 0..119 {
+        assert_el_eq!(&ZZ, &expected[i], &actual[i]);
+    }
+
+    let huge = vec![ZZ.power_of_two(2000)];
+    assert!(!multi_prime_convolution_supported(&ZZ, &huge[..], &huge[..]));
+}
+
+#[test]
+fn test_complex_fft_convoluted_mul() {
+    let ZZ = StaticRing::<i64>::RING;
+    let lhs = (0..200).map(|i| (i * 7) % 31 - 15).collect::<Vec<_>>();
+    let rhs = (0..100).map(|i| (i * 13) % 29 - 14).collect::<Vec<_>>();
+    let mut actual = vec![0; 299];
+    assert!(add_assign_complex_fft_convoluted_mul(&ZZ, &mut actual[..], &lhs[..], &rhs[..]));
+    assert_eq!(naive_convolution(&ZZ, &lhs[..], &rhs[..]), actual);
+
+    let large = (0..200).map(|i| (1 << 40) + i).collect::<Vec<_>>();
+    let mut actual = vec![0; 399];
+    assert!(!add_assign_complex_fft_convoluted_mul(&ZZ, &mut actual[..], &large[..], &large[..]));
+    assert!(actual.iter().all(|x| *x == 0));
+}
+
+#[test]
+fn test_integer_fft_convoluted_mul() {
+    // too large for the complex FFT, so this uses the multi-prime number-theoretic transform
+    let ZZ = StaticRing::<i64>::RING;
+    let lhs = (0..300).map(|i| (1 << 20) + i * i).collect::<Vec<_>>();
+    let rhs = (0..200).map(|i| (1 << 21) - 3 * i).collect::<Vec<_>>();
+    let mut actual = vec![0; 499];
+    assert!(!add_assign_complex_fft_convoluted_mul(&ZZ, &mut actual[..], &lhs[..], &rhs[..]));
+    assert!(ZZ.get_ring().add_assign_fft_convoluted_mul(&mut actual[..], &lhs[..], &rhs[..]));
+    assert_eq!(naive_convolution(&ZZ, &lhs[..], &rhs[..]), actual);
+}
+
+#[test]
+fn test_zn_fft_convoluted_mul_hint() {
+    let ring = zn_42::Zn::new(65537);
+    let lhs = (0..300).map(|i| ring.int_hom().map(i * i + 1)).collect::<Vec<_>>();
+    let rhs = (0..300).map(|i| ring.int_hom().map(5 * i - 2)).collect::<Vec<_>>();
+    let mut actual = (0..599).map(|_| ring.zero()).collect::<Vec<_>>();
+    assert!(ring.get_ring().add_assign_fft_convoluted_mul(&mut actual[..], &lhs[..], &rhs[..]));
+    let expected = naive_convolution(&ring, &lhs[..], &rhs[..]);
+    for i in 0..599 {
+        assert_el_eq!(&ring, &expected[i], &actual[i]);
+    }
+
+    // `1009 - 1` is only divisible by `2^4`, so this uses the multi-prime number-theoretic transform
+    let ring = zn_static::Zn::<1009>::RING;
+    let lhs = (0..300).map(|i| ring.int_hom().map(i * i + 1)).collect::<Vec<_>>();
+    let rhs = (0..300).map(|i| ring.int_hom().map(5 * i - 2)).collect::<Vec<_>>();
+    let mut actual = (0..599).map(|_| ring.zero()).collect::<Vec<_>>();
+    assert!(ring.get_ring().add_assign_fft_convoluted_mul(&mut actual[..], &lhs[..], &rhs[..]));
+    assert_eq!(naive_convolution(&ring, &lhs[..], &rhs[..]), actual);
+}
+
+#[test]
+fn test_zn_fft_convoluted_mul() {
+    // `2^61 - 1` is not NTT-friendly
+    let ZZ = BigIntRing::RING;
+    let ring = zn_barett::Zn::new(ZZ, ZZ.sub(ZZ.power_of_two(61), ZZ.one()));
+    let lhs = (0..80).map(|i| ring.coerce(&ZZ, ZZ.sub(ZZ.power_of_two(60), ZZ.int_hom().map(i)))).collect::<Vec<_>>();
+    let rhs = (0..90).map(|i| ring.int_hom().map(i * i)).collect::<Vec<_>>();
+    let mut actual = (0..169).map(|_| ring.zero()).collect::<Vec<_>>();
+    assert!(add_assign_zn_fft_convoluted_mul(&ring, &mut actual[..], &lhs[..], &rhs[..]));
+    let expected = naive_convolution(&ring, &lhs[..], &rhs[..]);
+    for i in 0..169 {
+        assert_el_eq!(&ring, &expected[i], &actual[i]);
+    }
+}
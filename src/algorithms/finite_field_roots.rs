@@ -0,0 +1,200 @@
+use crate::algorithms;
+use crate::algorithms::cantor_zassenhaus::pow_mod_f;
+use crate::divisibility::DivisibilityRingStore;
+use crate::field::{Field, FieldStore};
+use crate::integer::*;
+use crate::pid::EuclideanRing;
+use crate::ring::*;
+use crate::rings::finite::{FiniteRing, FiniteRingStore};
+use crate::rings::poly::{PolyRing, PolyRingStore};
+
+///
+/// Computes `gcd(f, X^q - X)`, i.e. the product of all `X - a` where `a` runs through the distinct
+/// roots of `f` in `Fq`.
+///
+fn distinct_linear_factors<P>(poly_ring: P, f: &El<P>) -> El<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing + EuclideanRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: FiniteRing + Field
+{
+    let ZZ = BigIntRing::RING;
+    let x_pow_q = pow_mod_f(&poly_ring, poly_ring.indeterminate(), f, &poly_ring.base_ring().size(&ZZ), ZZ);
+    return algorithms::eea::gcd(poly_ring.clone_el(f), poly_ring.sub(x_pow_q, poly_ring.indeterminate()), &poly_ring);
+}
+
+fn root_of_linear_poly<P>(poly_ring: P, f: &El<P>) -> El<<P::Type as RingExtension>::BaseRing>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: Field
+{
+    assert_eq!(Some(1), poly_ring.degree(f));
+    let F = poly_ring.base_ring();
+    return F.negate(F.div(poly_ring.coefficient_at(f, 0), poly_ring.coefficient_at(f, 1)));
+}
+
+///
+/// Finds a root of the given nonzero polynomial over a finite field `Fq`, or returns `None` if it
+/// has no roots in `Fq`.
+///
+/// This is faster than computing all roots using [`roots()`], since after computing `gcd(f, X^q - X)`,
+/// we only have to follow one branch of the equal-degree splitting of Cantor-Zassenhaus.
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::rings::poly::*;
+/// # use feanor_math::rings::poly::dense_poly::*;
+/// # use feanor_math::rings::zn::zn_static::*;
+/// # use feanor_math::algorithms::finite_field_roots::*;
+/// let ring = DensePolyRing::new(Fp::<7>::RING, "X");
+/// // `X^2 - 2` has the roots `3` and `4` in `F7`, but `X^2 - 3` has no roots
+/// let root = find_root(&ring, &ring.from_terms([(5, 0), (1, 2)].into_iter())).unwrap();
+/// assert!(root == 3 || root == 4);
+/// assert!(find_root(&ring, &ring.from_terms([(4, 0), (1, 2)].into_iter())).is_none());
+/// ```
+///
+pub fn find_root<P>(poly_ring: P, f: &El<P>) -> Option<El<<P::Type as RingExtension>::BaseRing>>
+    where P: PolyRingStore,
+        P::Type: PolyRing + EuclideanRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: FiniteRing + Field
+{
+    assert!(!poly_ring.is_zero(f));
+    if poly_ring.degree(f).unwrap() == 0 {
+        return None;
+    }
+    let mut g = distinct_linear_factors(&poly_ring, f);
+    if poly_ring.degree(&g).unwrap() == 0 {
+        return None;
+    }
+    while poly_ring.degree(&g).unwrap() > 1 {
+        let factor = algorithms::cantor_zassenhaus::cantor_zassenhaus(&poly_ring, poly_ring.clone_el(&g), 1);
+        if 2 * poly_ring.degree(&factor).unwrap() <= poly_ring.degree(&g).unwrap() {
+            g = factor;
+        } else {
+            g = poly_ring.checked_div(&g, &factor).unwrap();
+        }
+    }
+    return Some(root_of_linear_poly(&poly_ring, &g));
+}
+
+///
+/// Computes all roots of the given nonzero polynomial over a finite field `Fq`, together with
+/// their multiplicities. The roots are returned in no particular order.
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::rings::poly::*;
+/// # use feanor_math::rings::poly::dense_poly::*;
+/// # use feanor_math::rings::zn::zn_static::*;
+/// # use feanor_math::algorithms::finite_field_roots::*;
+/// let ring = DensePolyRing::new(Fp::<7>::RING, "X");
+/// // `(X - 1)^2 (X - 2) (X^2 + 1)`
+/// let f = ring.prod([
+///     ring.from_terms([(6, 0), (1, 1)].into_iter()),
+///     ring.from_terms([(6, 0), (1, 1)].into_iter()),
+///     ring.from_terms([(5, 0), (1, 1)].into_iter()),
+///     ring.from_terms([(1, 0), (1, 2)].into_iter())
+/// ].into_iter());
+/// let mut roots = roots(&ring, &f);
+/// roots.sort();
+/// assert_eq!(vec![(1, 2), (2, 1)], roots);
+/// ```
+///
+pub fn roots<P>(poly_ring: P, f: &El<P>) -> Vec<(El<<P::Type as RingExtension>::BaseRing>, usize)>
+    where P: PolyRingStore,
+        P::Type: PolyRing + EuclideanRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: FiniteRing + Field
+{
+    assert!(!poly_ring.is_zero(f));
+    if poly_ring.degree(f).unwrap() == 0 {
+        return Vec::new();
+    }
+    let mut linear_factors = Vec::new();
+    let mut stack = vec![distinct_linear_factors(&poly_ring, f)];
+    while let Some(current) = stack.pop() {
+        match poly_ring.degree(&current).unwrap() {
+            0 => {},
+            1 => linear_factors.push(current),
+            _ => {
+                let factor = algorithms::cantor_zassenhaus::cantor_zassenhaus(&poly_ring, poly_ring.clone_el(&current), 1);
+                stack.push(poly_ring.checked_div(&current, &factor).unwrap());
+                stack.push(factor);
+            }
+        }
+    }
+    return linear_factors.into_iter().map(|linear_factor| {
+        let mut multiplicity = 1;
+        let mut current = poly_ring.checked_div(f, &linear_factor).unwrap();
+        while let Some(quo) = poly_ring.checked_div(&current, &linear_factor) {
+            current = quo;
+            multiplicity += 1;
+        }
+        (root_of_linear_poly(&poly_ring, &linear_factor), multiplicity)
+    }).collect();
+}
+
+#[cfg(test)]
+use crate::homomorphism::*;
+#[cfg(test)]
+use crate::rings::poly::dense_poly::DensePolyRing;
+#[cfg(test)]
+use crate::rings::poly::gf2_poly::GF2PolyRing;
+#[cfg(test)]
+use crate::rings::zn::zn_64::Zn;
+#[cfg(test)]
+use crate::rings::zn::ZnRingStore;
+#[cfg(test)]
+use crate::rings::extension::galois_field::GaloisField;
+
+#[test]
+fn test_roots() {
+    let field = Zn::new(65537).as_field().ok().unwrap();
+    let ring = DensePolyRing::new(field, "X");
+    let linear = |a: i32| ring.sub(ring.indeterminate(), ring.inclusion().map(field.int_hom().map(a)));
+    let f = ring.prod([linear(1), linear(1), linear(1), linear(-5), linear(1000), linear(1000), ring.from_terms([(field.int_hom().map(3), 0), (field.one(), 2)].into_iter())].into_iter());
+    let mut actual = roots(&ring, &f).into_iter().map(|(r, e)| (field.smallest_positive_lift(r), e)).collect::<Vec<_>>();
+    actual.sort();
+    assert_eq!(vec![(1, 3), (1000, 2), (65532, 1)], actual);
+
+    assert!(roots(&ring, &ring.one()).is_empty());
+    assert!(roots(&ring, &ring.from_terms([(field.int_hom().map(3), 0), (field.one(), 2)].into_iter())).is_empty());
+    assert_eq!(vec![(0, 5)], roots(&ring, &ring.pow(ring.indeterminate(), 5)).into_iter().map(|(r, e)| (field.smallest_positive_lift(r), e)).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_roots_extension_field() {
+    let field = GaloisField::new(2, 4);
+    let ring = DensePolyRing::new(&field, "X");
+    // `X^16 - X` has every element of `F16` as a simple root
+    let f = ring.sub(ring.pow(ring.indeterminate(), 16), ring.indeterminate());
+    let actual = roots(&ring, &f);
+    assert_eq!(16, actual.len());
+    assert!(actual.iter().all(|(r, e)| *e == 1 && field.is_zero(&ring.evaluate(&f, r, &field.identity()))));
+
+    // `X^2 + X + 1` has no roots in `F2`, but in `F4` and `F16`
+    let ring = GF2PolyRing::new("X");
+    let f = ring.from_terms([(1, 0), (1, 1), (1, 2)].into_iter());
+    assert!(find_root(&ring, &f).is_none());
+    let ring = DensePolyRing::new(&field, "X");
+    let f = ring.from_terms([(field.one(), 0), (field.one(), 1), (field.one(), 2)].into_iter());
+    let root = find_root(&ring, &f).unwrap();
+    assert!(field.is_zero(&ring.evaluate(&f, &root, &field.identity())));
+}
+
+#[test]
+fn test_find_root() {
+    let field = Zn::new(65537).as_field().ok().unwrap();
+    let ring = DensePolyRing::new(field, "X");
+    let mut rng = oorandom::Rand64::new(1);
+    for _ in 0..10 {
+        let f = ring.prod([
+            algorithms::cantor_zassenhaus::random_irreducible(&ring, 1, || rng.rand_u64()),
+            algorithms::cantor_zassenhaus::random_irreducible(&ring, 1, || rng.rand_u64()),
+            algorithms::cantor_zassenhaus::random_irreducible(&ring, 1, || rng.rand_u64()),
+            algorithms::cantor_zassenhaus::random_irreducible(&ring, 3, || rng.rand_u64())
+        ].into_iter());
+        let root = find_root(&ring, &f).unwrap();
+        assert!(field.is_zero(&ring.evaluate(&f, &root, &field.identity())));
+    }
+}
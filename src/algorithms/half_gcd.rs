@@ -0,0 +1,255 @@
+use crate::pid::*;
+use crate::ring::*;
+use crate::rings::poly::*;
+
+///
+/// Inputs of degree below this are handled by the standard euclidean algorithm during
+/// the half-gcd recursion.
+///
+const HALF_GCD_BASE_CASE_DEGREE: usize = 32;
+
+#[cfg(test)]
+thread_local! {
+
+    ///
+    /// Counts the calls to [`half_gcd_eea()`] in the current thread, so that tests can check that
+    /// the half-gcd algorithm is used.
+    ///
+    static HALF_GCD_EEA_CALLS: std::cell::Cell<usize> = std::cell::Cell::new(0);
+}
+
+#[cfg(test)]
+pub(crate) fn half_gcd_eea_calls() -> usize {
+    HALF_GCD_EEA_CALLS.with(|calls| calls.get())
+}
+
+///
+/// A 2x2-matrix `[[m00, m01], [m10, m11]]` of polynomials, stored in row-major order.
+///
+type PolyMatrix<P> = [El<P>; 4];
+
+fn identity_matrix<P>(poly_ring: P) -> PolyMatrix<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing
+{
+    [poly_ring.one(), poly_ring.zero(), poly_ring.zero(), poly_ring.one()]
+}
+
+fn matrix_mul<P>(poly_ring: P, lhs: &PolyMatrix<P>, rhs: &PolyMatrix<P>) -> PolyMatrix<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing
+{
+    [
+        poly_ring.add(poly_ring.mul_ref(&lhs[0], &rhs[0]), poly_ring.mul_ref(&lhs[1], &rhs[2])),
+        poly_ring.add(poly_ring.mul_ref(&lhs[0], &rhs[1]), poly_ring.mul_ref(&lhs[1], &rhs[3])),
+        poly_ring.add(poly_ring.mul_ref(&lhs[2], &rhs[0]), poly_ring.mul_ref(&lhs[3], &rhs[2])),
+        poly_ring.add(poly_ring.mul_ref(&lhs[2], &rhs[1]), poly_ring.mul_ref(&lhs[3], &rhs[3]))
+    ]
+}
+
+fn matrix_apply<P>(poly_ring: P, matrix: &PolyMatrix<P>, a: &El<P>, b: &El<P>) -> (El<P>, El<P>)
+    where P: PolyRingStore,
+        P::Type: PolyRing
+{
+    (
+        poly_ring.add(poly_ring.mul_ref(&matrix[0], a), poly_ring.mul_ref(&matrix[1], b)),
+        poly_ring.add(poly_ring.mul_ref(&matrix[2], a), poly_ring.mul_ref(&matrix[3], b))
+    )
+}
+
+///
+/// Replaces `(a, b)` by `(b, a - qb)`, and `matrix` by `[[0, 1], [1, -q]] * matrix`, where `q` is the
+/// quotient of the division of `a` by `b`.
+///
+fn euclidean_step<P>(poly_ring: P, a: &mut El<P>, b: &mut El<P>, matrix: &mut PolyMatrix<P>)
+    where P: PolyRingStore,
+        P::Type: PolyRing + EuclideanRing
+{
+    let (q, r) = poly_ring.euclidean_div_rem(std::mem::replace(a, poly_ring.zero()), b);
+    *a = std::mem::replace(b, r);
+    let new_row1 = [
+        poly_ring.sub_ref_fst(&matrix[0], poly_ring.mul_ref(&q, &matrix[2])),
+        poly_ring.sub_ref_fst(&matrix[1], poly_ring.mul_ref(&q, &matrix[3]))
+    ];
+    let [_, _, m10, m11] = std::mem::replace(matrix, identity_matrix(&poly_ring));
+    let [n10, n11] = new_row1;
+    *matrix = [m10, m11, n10, n11];
+}
+
+fn shift_right<P>(poly_ring: P, f: &El<P>, k: usize) -> El<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing
+{
+    poly_ring.from_terms(poly_ring.terms(f).filter(|(_, i)| *i >= k).map(|(c, i)| (poly_ring.base_ring().clone_el(c), i - k)))
+}
+
+fn is_deg_lt<P>(poly_ring: P, f: &El<P>, d: usize) -> bool
+    where P: PolyRingStore,
+        P::Type: PolyRing
+{
+    poly_ring.degree(f).map(|f_deg| f_deg < d).unwrap_or(true)
+}
+
+///
+/// Computes the half-gcd of `a` and `b`, i.e. a matrix `M` that is the product of the matrices `[[0, 1], [1, -q]]`
+/// corresponding to the first steps of the euclidean algorithm on `(a, b)`, such that the entries of `M * (a, b)`
+/// have degree `>= ceil(deg(a) / 2)` resp. `< ceil(deg(a) / 2)`.
+///
+/// This requires `deg(a) > deg(b)`. The algorithm follows the classical divide-and-conquer approach, see e.g.
+/// Thull and Yap, "A Unified Approach to HGCD Algorithms for polynomials and integers".
+///
+fn half_gcd_matrix<P>(poly_ring: P, a: &El<P>, b: &El<P>) -> PolyMatrix<P>
+    where P: PolyRingStore + Copy,
+        P::Type: PolyRing + EuclideanRing
+{
+    let n = poly_ring.degree(a).unwrap();
+    debug_assert!(is_deg_lt(poly_ring, b, n + 1) && (n == 0 || is_deg_lt(poly_ring, b, n)));
+    let m = (n + 1) / 2;
+    if is_deg_lt(poly_ring, b, m) {
+        return identity_matrix(poly_ring);
+    }
+    if n < HALF_GCD_BASE_CASE_DEGREE {
+        let mut result = identity_matrix(poly_ring);
+        let (mut a, mut b) = (poly_ring.clone_el(a), poly_ring.clone_el(b));
+        while !is_deg_lt(poly_ring, &b, m) {
+            euclidean_step(poly_ring, &mut a, &mut b, &mut result);
+        }
+        return result;
+    }
+
+    // the quotients in the euclidean algorithm only depend on the highest coefficients, so we can find the first half of them recursively
+    let fst_matrix = half_gcd_matrix(poly_ring, &shift_right(poly_ring, a, m), &shift_right(poly_ring, b, m));
+    let (mut c, mut d) = matrix_apply(poly_ring, &fst_matrix, a, b);
+    if is_deg_lt(poly_ring, &d, m) {
+        return fst_matrix;
+    }
+    let mut result = fst_matrix;
+    euclidean_step(poly_ring, &mut c, &mut d, &mut result);
+    if is_deg_lt(poly_ring, &d, m) {
+        return result;
+    }
+    let l = poly_ring.degree(&c).unwrap();
+    let k = 2 * m - l;
+    let snd_matrix = half_gcd_matrix(poly_ring, &shift_right(poly_ring, &c, k), &shift_right(poly_ring, &d, k));
+    return matrix_mul(poly_ring, &snd_matrix, &result);
+}
+
+///
+/// Computes the Bezout identity of the polynomials `lhs` and `rhs` over a field, i.e. `(s, t, d)` such that
+/// `d` is a greatest common divisor of `lhs` and `rhs` and `s * lhs + t * rhs = d`.
+///
+/// In contrast to [`crate::algorithms::eea::eea()`], this uses the half-gcd algorithm, and thus requires only
+/// `O(M(n) log(n))` operations, where `M(n)` is the cost of multiplying polynomials of degree `n`. Note that
+/// [`crate::algorithms::eea::eea()`] automatically uses this algorithm for large dense polynomials over
+/// rings that support fast multiplication.
+///
+/// # Example
+/// ```
+/// # use feanor_math::assert_el_eq;
+/// # use feanor_math::ring::*;
+/// # use feanor_math::homomorphism::*;
+/// # use feanor_math::rings::poly::*;
+/// # use feanor_math::rings::poly::dense_poly::*;
+/// # use feanor_math::rings::zn::zn_static::*;
+/// # use feanor_math::algorithms::half_gcd::*;
+/// let P = DensePolyRing::new(Fp::<17>::RING, "X");
+/// let common_factor = P.from_terms([(3, 0), (1, 1), (1, 4)].into_iter());
+/// let f = P.mul_ref(&common_factor, &P.from_terms([(1, 0), (1, 7)].into_iter()));
+/// let g = P.mul_ref(&common_factor, &P.from_terms([(2, 0), (1, 5)].into_iter()));
+/// let (s, t, d) = half_gcd_eea(&P, P.clone_el(&f), P.clone_el(&g));
+/// assert_eq!(Some(4), P.degree(&d));
+/// assert_el_eq!(&P, &d, &P.add(P.mul(s, f), P.mul(t, g)));
+/// ```
+///
+pub fn half_gcd_eea<P>(poly_ring: P, lhs: El<P>, rhs: El<P>) -> (El<P>, El<P>, El<P>)
+    where P: PolyRingStore + Copy,
+        P::Type: PolyRing + EuclideanRing
+{
+    #[cfg(test)]
+    HALF_GCD_EEA_CALLS.with(|calls| calls.set(calls.get() + 1));
+    let (mut a, mut b) = (lhs, rhs);
+    let mut result = identity_matrix(poly_ring);
+    if poly_ring.degree(&a) < poly_ring.degree(&b) {
+        std::mem::swap(&mut a, &mut b);
+        result = [poly_ring.zero(), poly_ring.one(), poly_ring.one(), poly_ring.zero()];
+    }
+    // invariant: `result * (lhs, rhs) = (a, b)` and `deg(a) >= deg(b)`
+    while !poly_ring.is_zero(&b) {
+        if poly_ring.degree(&a) == poly_ring.degree(&b) {
+            euclidean_step(poly_ring, &mut a, &mut b, &mut result);
+            continue;
+        }
+        let step_matrix = half_gcd_matrix(poly_ring, &a, &b);
+        (a, b) = matrix_apply(poly_ring, &step_matrix, &a, &b);
+        result = matrix_mul(poly_ring, &step_matrix, &result);
+        if !poly_ring.is_zero(&b) {
+            euclidean_step(poly_ring, &mut a, &mut b, &mut result);
+        }
+    }
+    let [s, t, _, _] = result;
+    return (s, t, a);
+}
+
+#[cfg(test)]
+use crate::rings::poly::dense_poly::DensePolyRing;
+#[cfg(test)]
+use crate::rings::zn::zn_static::Fp;
+#[cfg(test)]
+use crate::rings::zn::{zn_64, ZnRingStore};
+#[cfg(test)]
+use crate::homomorphism::*;
+
+#[cfg(test)]
+fn assert_is_bezout_identity<P>(poly_ring: P, lhs: &El<P>, rhs: &El<P>, (s, t, d): (El<P>, El<P>, El<P>))
+    where P: PolyRingStore + Copy,
+        P::Type: PolyRing + EuclideanRing
+{
+    assert_el_eq!(&poly_ring, &d, &poly_ring.add(poly_ring.mul_ref(&s, lhs), poly_ring.mul_ref(&t, rhs)));
+    if poly_ring.is_zero(&d) {
+        assert!(poly_ring.is_zero(lhs) && poly_ring.is_zero(rhs));
+    } else {
+        assert!(poly_ring.is_zero(&poly_ring.euclidean_rem(poly_ring.clone_el(lhs), &d)));
+        assert!(poly_ring.is_zero(&poly_ring.euclidean_rem(poly_ring.clone_el(rhs), &d)));
+    }
+}
+
+#[test]
+fn test_half_gcd_matrix() {
+    let P = DensePolyRing::new(Fp::<257>::RING, "X");
+    let a = P.from_terms((0..=150).map(|i| ((i * i + 1) % 257, i as usize)));
+    let b = P.from_terms((0..=131).map(|i| ((3 * i + 5) % 257, i as usize)));
+    let matrix = half_gcd_matrix(&P, &a, &b);
+    let (c, d) = matrix_apply(&P, &matrix, &a, &b);
+    assert!(P.degree(&c).unwrap() >= 75);
+    assert!(P.degree(&d).unwrap() < 75);
+
+    // compare with the standard euclidean algorithm, stopped at the same degree
+    let mut expected = identity_matrix(&P);
+    let (mut e, mut f) = (P.clone_el(&a), P.clone_el(&b));
+    while !is_deg_lt(&P, &f, 75) {
+        euclidean_step(&P, &mut e, &mut f, &mut expected);
+    }
+    for i in 0..4 {
+        assert_el_eq!(&P, &expected[i], &matrix[i]);
+    }
+}
+
+#[test]
+fn test_half_gcd_eea() {
+    let P = DensePolyRing::new(Fp::<7>::RING, "X");
+    let f = P.from_terms([(1, 0), (1, 1), (3, 4)].into_iter());
+    let g = P.from_terms([(2, 0), (1, 3)].into_iter());
+    assert_is_bezout_identity(&P, &f, &g, half_gcd_eea(&P, P.clone_el(&f), P.clone_el(&g)));
+    assert_is_bezout_identity(&P, &g, &f, half_gcd_eea(&P, P.clone_el(&g), P.clone_el(&f)));
+    assert_is_bezout_identity(&P, &f, &P.zero(), half_gcd_eea(&P, P.clone_el(&f), P.zero()));
+    assert_is_bezout_identity(&P, &P.zero(), &P.zero(), half_gcd_eea(&P, P.zero(), P.zero()));
+
+    let P = DensePolyRing::new(zn_64::Zn::new(65537).as_field().ok().unwrap(), "X");
+    let hom = P.base_ring().int_hom();
+    let common_factor = P.from_terms((0..=100).map(|i| (hom.map(i * i + 1), i as usize)));
+    let f = P.mul_ref(&common_factor, &P.from_terms((0..=300).map(|i| (hom.map(7 * i + 2), i as usize))));
+    let g = P.mul_ref(&common_factor, &P.from_terms((0..=250).map(|i| (hom.map(i * i * i + 5), i as usize))));
+    let (s, t, d) = half_gcd_eea(&P, P.clone_el(&f), P.clone_el(&g));
+    assert_eq!(Some(100), P.degree(&d));
+    assert_is_bezout_identity(&P, &f, &g, (s, t, d));
+}
@@ -0,0 +1,393 @@
+use crate::divisibility::{DivisibilityRing, DivisibilityRingStore};
+use crate::ring::*;
+use crate::homomorphism::*;
+use crate::rings::poly::{PolyRing, PolyRingStore};
+
+///
+/// If the number of points or the degree of the polynomial is below this bound, [`multipoint_evaluate()`]
+/// evaluates at each point separately, as building the subproduct tree does not pay off. The value is
+/// chosen based on `bench_multipoint_evaluate_{tree,naive}_*` over [`crate::rings::zn::zn_64::Zn`], for
+/// `n` points and a polynomial of degree `n - 1`. There, the subproduct tree is slower for `n = 1536`
+/// (14.3 ms vs 11.2 ms), slightly faster for `n = 2048` (17.7 ms vs 19.8 ms), equally fast for `n = 2560`
+/// (30.7 ms vs 31.2 ms) and clearly faster for `n = 3072` (30.2 ms vs 45.2 ms).
+///
+const MULTIPOINT_EVALUATE_THRESHOLD: usize = 2048;
+
+///
+/// Below this number of points, [`interpolate()`] falls back to [`newton_interpolate()`].
+///
+const INTERPOLATE_THRESHOLD: usize = 128;
+
+fn invert_difference<R>(ring: R, x: &El<R>) -> El<R>
+    where R: RingStore,
+        R::Type: DivisibilityRing
+{
+    if let Some(result) = ring.invert(x) {
+        result
+    } else {
+        panic!("the differences of interpolation points must be units")
+    }
+}
+
+///
+/// Builds the subproduct tree of the given points, i.e. the `i`-th entry of the result contains the
+/// products of `2^i` consecutive linear factors `X - a_j` (except possibly for the last product, which
+/// may contain less factors). The last entry contains only the single polynomial `prod_j (X - a_j)`.
+///
+fn subproduct_tree<P>(poly_ring: P, points: &[El<<P::Type as RingExtension>::BaseRing>]) -> Vec<Vec<El<P>>>
+    where P: PolyRingStore,
+        P::Type: PolyRing
+{
+    assert!(points.len() > 0);
+    let mut result = vec![points.iter().map(|a| poly_ring.sub(poly_ring.indeterminate(), poly_ring.inclusion().map_ref(a))).collect::<Vec<_>>()];
+    while result.last().unwrap().len() > 1 {
+        let current = result.last().unwrap();
+        let next = current.chunks(2).map(|factors| match factors {
+            [lhs, rhs] => poly_ring.mul_ref(lhs, rhs),
+            [single] => poly_ring.clone_el(single),
+            _ => unreachable!()
+        }).collect::<Vec<_>>();
+        result.push(next);
+    }
+    return result;
+}
+
+fn multipoint_evaluate_tree<P>(poly_ring: P, f: &El<P>, tree: &[Vec<El<P>>]) -> Vec<El<<P::Type as RingExtension>::BaseRing>>
+    where P: PolyRingStore,
+        P::Type: PolyRing
+{
+    // all polynomials in the tree are monic, so we can go down the remainder tree without any divisions in the base ring
+    let mut remainders = vec![poly_ring.div_rem_monic(poly_ring.clone_el(f), &tree.last().unwrap()[0]).1];
+    for level in tree.iter().rev().skip(1) {
+        remainders = level.iter().enumerate().map(|(i, modulus)| poly_ring.div_rem_monic(poly_ring.clone_el(&remainders[i / 2]), modulus).1).collect();
+    }
+    return remainders.iter().map(|r| poly_ring.base_ring().clone_el(poly_ring.coefficient_at(r, 0))).collect();
+}
+
+///
+/// Evaluates the polynomial `f` at all the given points.
+///
+/// For a small number of points or a polynomial of small degree, this evaluates `f` at each point separately,
+/// using [`PolyRingStore::evaluate()`], which costs `O(n deg(f))` operations in the base ring. Otherwise, it computes the remainders of `f` modulo the polynomials in the subproduct tree of the points,
+/// which requires only `O(log(n))` multiplications and divisions with remainder of polynomials of degree
+/// `<= max(deg(f), n)`. This is much faster for many points, if the polynomial ring implements subquadratic
+/// multiplication and division.
+///
+/// This works over any ring, as all divisions are by monic polynomials.
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::rings::poly::*;
+/// # use feanor_math::rings::poly::dense_poly::*;
+/// # use feanor_math::primitive_int::*;
+/// # use feanor_math::algorithms::interpolate::*;
+/// let ZZ = StaticRing::<i64>::RING;
+/// let P = DensePolyRing::new(ZZ, "X");
+/// let f = P.from_terms([(1, 0), (2, 1), (1, 3)].into_iter());
+/// assert_eq!(vec![1, 4, 13, -2], multipoint_evaluate(&P, &f, &[0, 1, 2, -1]));
+/// ```
+///
+pub fn multipoint_evaluate<P>(poly_ring: P, f: &El<P>, points: &[El<<P::Type as RingExtension>::BaseRing>]) -> Vec<El<<P::Type as RingExtension>::BaseRing>>
+    where P: PolyRingStore,
+        P::Type: PolyRing
+{
+    if points.len() < MULTIPOINT_EVALUATE_THRESHOLD || poly_ring.degree(f).map(|d| d + 1 < MULTIPOINT_EVALUATE_THRESHOLD).unwrap_or(true) {
+        return points.iter().map(|a| poly_ring.evaluate(f, a, &poly_ring.base_ring().identity())).collect();
+    }
+    let tree = subproduct_tree(&poly_ring, points);
+    return multipoint_evaluate_tree(&poly_ring, f, &tree);
+}
+
+///
+/// Computes the unique polynomial `f` of degree `< n` with `f(a_i) = y_i` for all `i < n`, where `a_i`
+/// are the given points and `y_i` the given values, using the formula of Lagrange.
+///
+/// This requires the differences `a_i - a_j` for `i != j` to be units in the base ring, and will panic
+/// otherwise. The cost is `O(n^2)` operations in the base ring.
+///
+pub fn lagrange_interpolate<P>(poly_ring: P, points: &[El<<P::Type as RingExtension>::BaseRing>], values: &[El<<P::Type as RingExtension>::BaseRing>]) -> El<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: DivisibilityRing
+{
+    assert_eq!(points.len(), values.len());
+    let ring = poly_ring.base_ring();
+    let linear_factors = points.iter().map(|a| poly_ring.sub(poly_ring.indeterminate(), poly_ring.inclusion().map_ref(a))).collect::<Vec<_>>();
+    let modulus = poly_ring.prod(linear_factors.iter().map(|f| poly_ring.clone_el(f)));
+    let mut result = poly_ring.zero();
+    for (i, (a, y)) in points.iter().zip(values.iter()).enumerate() {
+        if ring.is_zero(y) {
+            continue;
+        }
+        let (basis_poly, _) = poly_ring.div_rem_monic(poly_ring.clone_el(&modulus), &linear_factors[i]);
+        let denominator = poly_ring.evaluate(&basis_poly, a, &ring.identity());
+        let factor = ring.mul_ref_fst(y, invert_difference(ring, &denominator));
+        poly_ring.add_assign(&mut result, poly_ring.inclusion().mul_map(basis_poly, factor));
+    }
+    return result;
+}
+
+///
+/// Computes the unique polynomial `f` of degree `< n` with `f(a_i) = y_i` for all `i < n`, where `a_i`
+/// are the given points and `y_i` the given values, using Newton's divided differences.
+///
+/// This requires the differences `a_i - a_j` for `i != j` to be units in the base ring, and will panic
+/// otherwise. The cost is `O(n^2)` operations in the base ring.
+///
+/// # Example
+/// ```
+/// # use feanor_math::assert_el_eq;
+/// # use feanor_math::ring::*;
+/// # use feanor_math::homomorphism::*;
+/// # use feanor_math::rings::poly::*;
+/// # use feanor_math::rings::poly::dense_poly::*;
+/// # use feanor_math::rings::zn::zn_static::*;
+/// # use feanor_math::algorithms::interpolate::*;
+/// let F7 = Fp::<7>::RING;
+/// let P = DensePolyRing::new(F7, "X");
+/// let f = newton_interpolate(&P, &[0, 1, 2], &[1, 2, 5]);
+/// assert_el_eq!(&P, &P.from_terms([(1, 0), (1, 2)].into_iter()), &f);
+/// ```
+///
+pub fn newton_interpolate<P>(poly_ring: P, points: &[El<<P::Type as RingExtension>::BaseRing>], values: &[El<<P::Type as RingExtension>::BaseRing>]) -> El<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: DivisibilityRing
+{
+    assert_eq!(points.len(), values.len());
+    let ring = poly_ring.base_ring();
+    let n = points.len();
+    let mut coefficients = values.iter().map(|y| ring.clone_el(y)).collect::<Vec<_>>();
+    for j in 1..n {
+        for i in (j..n).rev() {
+            let difference = ring.sub_ref(&coefficients[i], &coefficients[i - 1]);
+            coefficients[i] = ring.mul(difference, invert_difference(ring, &ring.sub_ref(&points[i], &points[i - j])));
+        }
+    }
+    let mut result = poly_ring.zero();
+    for (c, a) in coefficients.into_iter().zip(points.iter()).rev() {
+        result = poly_ring.add(poly_ring.mul(result, poly_ring.sub(poly_ring.indeterminate(), poly_ring.inclusion().map_ref(a))), poly_ring.inclusion().map(c));
+    }
+    return result;
+}
+
+///
+/// Computes the unique polynomial `f` of degree `< n` with `f(a_i) = y_i` for all `i < n`, where `a_i`
+/// are the given points and `y_i` the given values.
+///
+/// This requires the differences `a_i - a_j` for `i != j` to be units in the base ring, and will panic
+/// otherwise. In particular, this works for polynomials over any field, and over rings `Z/nZ` if the
+/// points are distinct modulo every prime factor of `n`.
+///
+/// For a small number of points, this uses [`newton_interpolate()`]. Otherwise, it uses the subproduct tree
+/// of the points, i.e. computes the weights `y_i / M'(a_i)` with `M = prod_i (X - a_i)` via [`multipoint_evaluate()`]
+/// and combines them bottom-up along the tree. Its cost is thus `O(log(n))` multiplications and divisions with
+/// remainder of polynomials of degree `<= n`.
+///
+/// # Example
+/// ```
+/// # use feanor_math::assert_el_eq;
+/// # use feanor_math::ring::*;
+/// # use feanor_math::homomorphism::*;
+/// # use feanor_math::rings::poly::*;
+/// # use feanor_math::rings::poly::dense_poly::*;
+/// # use feanor_math::rings::zn::*;
+/// # use feanor_math::rings::zn::zn_64::*;
+/// # use feanor_math::algorithms::interpolate::*;
+/// let Fp = Zn::new(65537);
+/// let P = DensePolyRing::new(Fp, "X");
+/// let f = P.from_terms((0..100).map(|i| (Fp.int_hom().map(i), i as usize)));
+/// let points = (0..100).map(|i| Fp.int_hom().map(i * i + 1)).collect::<Vec<_>>();
+/// let values = multipoint_evaluate(&P, &f, &points);
+/// assert_el_eq!(&P, &f, &interpolate(&P, &points, &values));
+/// ```
+///
+pub fn interpolate<P>(poly_ring: P, points: &[El<<P::Type as RingExtension>::BaseRing>], values: &[El<<P::Type as RingExtension>::BaseRing>]) -> El<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: DivisibilityRing
+{
+    assert_eq!(points.len(), values.len());
+    if points.len() < INTERPOLATE_THRESHOLD {
+        return newton_interpolate(poly_ring, points, values);
+    }
+    let ring = poly_ring.base_ring();
+    let tree = subproduct_tree(&poly_ring, points);
+    let modulus_derivative = poly_ring.derivative(&tree.last().unwrap()[0]);
+    let denominators = multipoint_evaluate_tree(&poly_ring, &modulus_derivative, &tree);
+    let mut current = values.iter().zip(denominators.iter()).map(|(y, d)| poly_ring.inclusion().map(ring.mul_ref_fst(y, invert_difference(ring, d)))).collect::<Vec<_>>();
+    // invariant: `current[i] * (M / tree[level][i])` interpolates the values at all points belonging to `tree[level][i]`
+    for level in tree.iter().take(tree.len() - 1) {
+        current = current.chunks(2).zip(level.chunks(2)).map(|(summands, factors)| match (summands, factors) {
+            ([lhs, rhs], [lhs_factor, rhs_factor]) => poly_ring.add(poly_ring.mul_ref(lhs, rhs_factor), poly_ring.mul_ref(rhs, lhs_factor)),
+            ([single], [_]) => poly_ring.clone_el(single),
+            _ => unreachable!()
+        }).collect();
+    }
+    debug_assert!(current.len() == 1);
+    return current.pop().unwrap();
+}
+
+#[cfg(test)]
+use crate::integer::BigIntRing;
+#[cfg(test)]
+use crate::rings::poly::dense_poly::DensePolyRing;
+#[cfg(test)]
+use crate::rings::zn::zn_static::Fp;
+#[cfg(test)]
+use crate::rings::zn::{ZnRingStore, zn_64};
+
+#[test]
+fn test_multipoint_evaluate() {
+    let ZZ = BigIntRing::RING;
+    let ZZX = DensePolyRing::new(ZZ, "X");
+    let f = ZZX.from_terms([(3, 0), (-1, 1), (2, 4), (1, 7)].into_iter().map(|(c, i)| (ZZ.int_hom().map(c), i)));
+    for n in [0, 1, 2, 5, 16, 17, 40] {
+        let points = (0..n).map(|i| ZZ.int_hom().map(i - n / 2)).collect::<Vec<_>>();
+        let expected = points.iter().map(|a| ZZX.evaluate(&f, a, &ZZ.identity())).collect::<Vec<_>>();
+        let actual = multipoint_evaluate(&ZZX, &f, &points);
+        assert_eq!(expected.len(), actual.len());
+        for (e, a) in expected.iter().zip(actual.iter()) {
+            assert_el_eq!(&ZZ, e, a);
+        }
+        if n > 0 {
+            let actual = multipoint_evaluate_tree(&ZZX, &f, &subproduct_tree(&ZZX, &points));
+            assert_eq!(expected.len(), actual.len());
+            for (e, a) in expected.iter().zip(actual.iter()) {
+                assert_el_eq!(&ZZ, e, a);
+            }
+        }
+    }
+
+    // the polynomial has larger degree than the number of points
+    let Fp = zn_64::Zn::new(65537);
+    let FpX = DensePolyRing::new(Fp, "X");
+    let f = FpX.from_terms((0..100).map(|i| (Fp.int_hom().map(i * i - 7), i as usize)));
+    let points = (0..50).map(|i| Fp.int_hom().map(i)).collect::<Vec<_>>();
+    let expected = points.iter().map(|a| FpX.evaluate(&f, a, &Fp.identity())).collect::<Vec<_>>();
+    let actual = multipoint_evaluate_tree(&FpX, &f, &subproduct_tree(&FpX, &points));
+    for (e, a) in expected.iter().zip(actual.iter()) {
+        assert_el_eq!(&Fp, e, a);
+    }
+    assert!(multipoint_evaluate_tree(&FpX, &FpX.zero(), &subproduct_tree(&FpX, &points)).iter().all(|a| Fp.is_zero(a)));
+}
+
+#[test]
+fn test_multipoint_evaluate_large() {
+    let Fp = zn_64::Zn::new(65537);
+    let FpX = DensePolyRing::new(Fp, "X");
+    let n = MULTIPOINT_EVALUATE_THRESHOLD + 100;
+    let f = FpX.from_terms((0..n).map(|i| (Fp.int_hom().map(i as i32 * 7 + 3), i)));
+    let points = (0..n).map(|i| Fp.int_hom().map(3 * i as i32 + 1)).collect::<Vec<_>>();
+    let values = multipoint_evaluate(&FpX, &f, &points);
+    assert_eq!(n, values.len());
+    for i in (0..n).step_by(97) {
+        assert_el_eq!(&Fp, &FpX.evaluate(&f, &points[i], &Fp.identity()), &values[i]);
+    }
+}
+
+#[test]
+fn test_interpolate() {
+    let Fp = zn_64::Zn::new(65537).as_field().ok().unwrap();
+    let FpX = DensePolyRing::new(&Fp, "X");
+    let mut rng = oorandom::Rand64::new(1);
+    for n in [1, 2, 7, 33, 128, 200] {
+        let points = (0..n).map(|i| Fp.int_hom().map(3 * i + 1)).collect::<Vec<_>>();
+        let f = FpX.from_terms((0..n as usize).map(|i| (Fp.int_hom().map((rng.rand_u64() % 65537) as i32), i)));
+        let values = multipoint_evaluate(&FpX, &f, &points);
+        assert_el_eq!(&FpX, &f, &interpolate(&FpX, &points, &values));
+        assert_el_eq!(&FpX, &f, &lagrange_interpolate(&FpX, &points, &values));
+        assert_el_eq!(&FpX, &f, &newton_interpolate(&FpX, &points, &values));
+    }
+    assert_el_eq!(&FpX, &FpX.zero(), &interpolate(&FpX, &[], &[]));
+
+    // `Z/nZ` with `n` composite, the points must be distinct modulo `5` and `7`
+    let Zn = zn_64::Zn::new(35);
+    let ZnX = DensePolyRing::new(Zn, "X");
+    let points = [0, 1, 2, 3, 4].map(|a| Zn.int_hom().map(a));
+    let f = ZnX.from_terms([(Zn.int_hom().map(12), 0), (Zn.int_hom().map(-3), 2), (Zn.int_hom().map(2), 4)].into_iter());
+    let values = multipoint_evaluate(&ZnX, &f, &points);
+    assert_el_eq!(&ZnX, &f, &interpolate(&ZnX, &points, &values));
+    assert_el_eq!(&ZnX, &f, &lagrange_interpolate(&ZnX, &points, &values));
+}
+
+#[test]
+#[should_panic]
+fn test_interpolate_repeated_point() {
+    let F7 = Fp::<7>::RING;
+    let F7X = DensePolyRing::new(F7, "X");
+    newton_interpolate(&F7X, &[1, 2, 8], &[0, 1, 2]);
+}
+
+#[cfg(test)]
+fn bench_multipoint_evaluate_tree(bencher: &mut test::Bencher, n: usize) {
+    let Fp = zn_64::Zn::new(65537);
+    let FpX = DensePolyRing::new(Fp, "X");
+    let f = FpX.from_terms((0..n).map(|i| (Fp.int_hom().map(i as i32 * 7 + 3), i)));
+    let points = (0..n).map(|i| Fp.int_hom().map(3 * i as i32 + 1)).collect::<Vec<_>>();
+    bencher.iter(|| {
+        std::hint::black_box(multipoint_evaluate_tree(&FpX, &f, &subproduct_tree(&FpX, &points)));
+    });
+}
+
+#[cfg(test)]
+fn bench_multipoint_evaluate_naive(bencher: &mut test::Bencher, n: usize) {
+    let Fp = zn_64::Zn::new(65537);
+    let FpX = DensePolyRing::new(Fp, "X");
+    let f = FpX.from_terms((0..n).map(|i| (Fp.int_hom().map(i as i32 * 7 + 3), i)));
+    let points = (0..n).map(|i| Fp.int_hom().map(3 * i as i32 + 1)).collect::<Vec<_>>();
+    bencher.iter(|| {
+        std::hint::black_box(points.iter().map(|a| FpX.evaluate(&f, a, &Fp.identity())).collect::<Vec<_>>());
+    });
+}
+
+#[bench]
+fn bench_multipoint_evaluate_tree_1024(bencher: &mut test::Bencher) {
+    bench_multipoint_evaluate_tree(bencher, 1024);
+}
+
+#[bench]
+fn bench_multipoint_evaluate_naive_1024(bencher: &mut test::Bencher) {
+    bench_multipoint_evaluate_naive(bencher, 1024);
+}
+
+#[bench]
+fn bench_multipoint_evaluate_tree_1536(bencher: &mut test::Bencher) {
+    bench_multipoint_evaluate_tree(bencher, 1536);
+}
+
+#[bench]
+fn bench_multipoint_evaluate_naive_1536(bencher: &mut test::Bencher) {
+    bench_multipoint_evaluate_naive(bencher, 1536);
+}
+
+#[bench]
+fn bench_multipoint_evaluate_tree_2048(bencher: &mut test::Bencher) {
+    bench_multipoint_evaluate_tree(bencher, 2048);
+}
+
+#[bench]
+fn bench_multipoint_evaluate_naive_2048(bencher: &mut test::Bencher) {
+    bench_multipoint_evaluate_naive(bencher, 2048);
+}
+
+#[bench]
+fn bench_multipoint_evaluate_tree_2560(bencher: &mut test::Bencher) {
+    bench_multipoint_evaluate_tree(bencher, 2560);
+}
+
+#[bench]
+fn bench_multipoint_evaluate_naive_2560(bencher: &mut test::Bencher) {
+    bench_multipoint_evaluate_naive(bencher, 2560);
+}
+
+#[bench]
+fn bench_multipoint_evaluate_tree_3072(bencher: &mut test::Bencher) {
+    bench_multipoint_evaluate_tree(bencher, 3072);
+}
+
+#[bench]
+fn bench_multipoint_evaluate_naive_3072(bencher: &mut test::Bencher) {
+    bench_multipoint_evaluate_naive(bencher, 3072);
+}
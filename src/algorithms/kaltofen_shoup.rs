@@ -0,0 +1,127 @@
+use std::cmp::max;
+
+use crate::algorithms;
+use crate::algorithms::cantor_zassenhaus::pow_mod_f;
+use crate::divisibility::DivisibilityRingStore;
+use crate::field::Field;
+use crate::integer::*;
+use crate::pid::{EuclideanRing, EuclideanRingStore};
+use crate::ring::*;
+use crate::rings::finite::{FiniteRing, FiniteRingStore};
+use crate::rings::poly::{PolyRing, PolyRingStore};
+
+///
+/// Computes the distinct-degree factorization of a square-free polynomial `f` over a finite field
+/// `Fq`, using the baby-step giant-step algorithm of Kaltofen and Shoup. As in
+/// [`crate::algorithms::cantor_zassenhaus::distinct_degree_factorization()`], the `d`-th entry of
+/// the returned list is the product of all irreducible factors of `f` of degree `d`, and the `0`-th
+/// entry is a unit. The entries are only determined up to multiplication by units.
+///
+/// # Algorithm
+///
+/// For `n = deg(f)` and `l ~ sqrt(n / 2)`, we compute the "baby steps" `h_i = X^(q^i) mod f` for
+/// `0 <= i < l` and the "giant steps" `H_j = X^(q^(lj)) mod f`. Since `g^q = g(X^q)` for polynomials
+/// `g` over `Fq`, these can be computed by modular composition, instead of exponentiation.
+/// An irreducible polynomial of degree `d = lj - i` divides `H_j - h_i`, hence we first compute the
+/// "coarse" factors `gcd(f, prod_i (H_j - h_i))`, which are the products of all irreducible factors
+/// whose degree is in `(l(j - 1), lj]`, and then split these by `gcd(., H_j - h_i)`, in the order
+/// of increasing `lj - i`.
+///
+pub fn distinct_degree_factorization<P>(poly_ring: P, f: El<P>) -> Vec<El<P>>
+    where P: PolyRingStore,
+        P::Type: PolyRing + EuclideanRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: FiniteRing + Field
+{
+    assert!(!poly_ring.is_zero(&f));
+    let ZZ = BigIntRing::RING;
+    let n = poly_ring.degree(&f).unwrap();
+    if n == 0 {
+        return vec![f];
+    }
+    let l = max(1, ((n as f64) / 2.).sqrt().ceil() as usize);
+
+    let x_pow_q = pow_mod_f(&poly_ring, poly_ring.indeterminate(), &f, &poly_ring.base_ring().size(&ZZ), ZZ);
+    let mut baby_steps = Vec::with_capacity(l);
+    baby_steps.push(poly_ring.euclidean_rem(poly_ring.indeterminate(), &f));
+    for i in 1..l {
+        baby_steps.push(algorithms::poly_compose::modular_compose(&poly_ring, &baby_steps[i - 1], &x_pow_q, &f));
+    }
+    let giant_step = algorithms::poly_compose::modular_compose(&poly_ring, &baby_steps[l - 1], &x_pow_q, &f);
+
+    let mut result = (0..=n).map(|_| poly_ring.one()).collect::<Vec<_>>();
+    let mut remaining = poly_ring.clone_el(&f);
+    let mut current_giant_step = poly_ring.clone_el(&giant_step);
+    let mut j = 1;
+    // if there is no factor of degree `<= l(j - 1)`, and `deg(remaining) < 2 (l(j - 1) + 1)`, then `remaining` is irreducible
+    while poly_ring.degree(&remaining).unwrap() >= 2 * (l * (j - 1) + 1) {
+        let interval_poly = baby_steps.iter().fold(poly_ring.one(), |current, h|
+            poly_ring.euclidean_rem(poly_ring.mul(current, poly_ring.sub_ref(&current_giant_step, h)), &remaining)
+        );
+        let mut coarse_factor = algorithms::eea::gcd(poly_ring.clone_el(&remaining), interval_poly, &poly_ring);
+        if poly_ring.degree(&coarse_factor).unwrap() > 0 {
+            remaining = poly_ring.checked_div(&remaining, &coarse_factor).unwrap();
+            // `H_j - h_i` is divisible by all irreducible polynomials whose degree divides `lj - i`, 
+            // so we have to extract the factors of smaller degree first
+            for (i, h) in baby_steps.iter().enumerate().rev() {
+                if poly_ring.degree(&coarse_factor).unwrap() == 0 {
+                    break;
+                }
+                let fine_factor = algorithms::eea::gcd(poly_ring.clone_el(&coarse_factor), poly_ring.sub_ref(&current_giant_step, h), &poly_ring);
+                if poly_ring.degree(&fine_factor).unwrap() > 0 {
+                    coarse_factor = poly_ring.checked_div(&coarse_factor, &fine_factor).unwrap();
+                    result[l * j - i] = fine_factor;
+                }
+            }
+        }
+        current_giant_step = algorithms::poly_compose::modular_compose(&poly_ring, &current_giant_step, &giant_step, &f);
+        j += 1;
+    }
+    let remaining_degree = poly_ring.degree(&remaining).unwrap();
+    if remaining_degree > 0 {
+        result[remaining_degree] = remaining;
+    } else {
+        result[0] = remaining;
+    }
+    while result.len() > 1 && poly_ring.is_one(result.last().unwrap()) {
+        result.pop();
+    }
+    return result;
+}
+
+#[cfg(test)]
+use crate::rings::poly::dense_poly::DensePolyRing;
+#[cfg(test)]
+use crate::rings::zn::zn_64::Zn;
+#[cfg(test)]
+use crate::rings::zn::ZnRingStore;
+#[cfg(test)]
+use crate::rings::extension::galois_field::GaloisField;
+
+#[test]
+fn test_distinct_degree_factorization() {
+    let field = Zn::new(65537).as_field().ok().unwrap();
+    let ring = DensePolyRing::new(field, "X");
+    let mut rng = oorandom::Rand64::new(1);
+    let factors = [1, 1, 2, 3, 3, 5, 8, 13].into_iter()
+        .map(|d| algorithms::cantor_zassenhaus::random_irreducible(&ring, d, || rng.rand_u64()))
+        .collect::<Vec<_>>();
+    let f = ring.prod(factors.iter().map(|g| ring.clone_el(g)));
+    let result = distinct_degree_factorization(&ring, ring.clone_el(&f));
+    let expected = algorithms::cantor_zassenhaus::distinct_degree_factorization(&ring, f);
+    assert_eq!(expected.len(), result.len());
+    for (e, a) in expected.iter().zip(result.iter()) {
+        assert!(ring.checked_div(e, a).map(|u| ring.is_unit(&u)).unwrap_or(false));
+    }
+    assert_eq!(Some(6), ring.degree(&result[3]));
+    assert_eq!(Some(13), ring.degree(&result[13]));
+
+    let field = GaloisField::new(2, 2);
+    let ring = DensePolyRing::new(&field, "X");
+    // `X^(4^3) - X` is the product of all monic irreducible polynomials of degree `1` and `3` over `F4`
+    let f = ring.sub(ring.pow(ring.indeterminate(), 64), ring.indeterminate());
+    let result = distinct_degree_factorization(&ring, f);
+    assert_eq!(4, result.len());
+    assert_eq!(Some(4), ring.degree(&result[1]));
+    assert!(ring.is_unit(&result[2]));
+    assert_eq!(Some(60), ring.degree(&result[3]));
+}
@@ -0,0 +1,313 @@
+use crate::field::{Field, FieldStore};
+use crate::ring::*;
+use crate::rings::poly::{PolyRing, PolyRingStore};
+use crate::rings::poly::dense_poly::DensePolyRing;
+
+///
+/// Computes the minimal polynomial of the given sequence `s_0, ..., s_(N - 1)` over a field, using
+/// the algorithm of Berlekamp and Massey.
+///
+/// The minimal polynomial is the monic polynomial `m = X^L + a_(L - 1) X^(L - 1) + ... + a_0` of
+/// smallest degree such that `s_(i + L) + a_(L - 1) s_(i + L - 1) + ... + a_0 s_i = 0` for all
+/// `i` with `i + L < N`. If the sequence satisfies a linear recurrence of order `L`, then this
+/// recurrence is found from the first `2L` terms. The cost is `O(N^2)` field operations.
+///
+/// # Example
+/// ```
+/// # use feanor_math::assert_el_eq;
+/// # use feanor_math::ring::*;
+/// # use feanor_math::rings::poly::*;
+/// # use feanor_math::rings::poly::dense_poly::*;
+/// # use feanor_math::rings::zn::zn_static::*;
+/// # use feanor_math::algorithms::linear_recurrence::*;
+/// let F2 = Fp::<2>::RING;
+/// let P = DensePolyRing::new(F2, "X");
+/// // the output of the LFSR with feedback `s_(i + 3) = s_(i + 1) + s_i`
+/// let sequence = [1, 0, 0, 1, 0, 1, 1, 1, 0, 0];
+/// let minpoly = berlekamp_massey(&P, &sequence);
+/// assert_el_eq!(&P, &P.from_terms([(1, 0), (1, 1), (1, 3)].into_iter()), &minpoly);
+/// ```
+///
+pub fn berlekamp_massey<P>(poly_ring: P, sequence: &[El<<P::Type as RingExtension>::BaseRing>]) -> El<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: Field
+{
+    let field = poly_ring.base_ring();
+    // the connection polynomial `C = 1 + c_1 X + ... + c_L X^L`, stored as vector of coefficients
+    let mut current = vec![field.one()];
+    let mut previous = vec![field.one()];
+    let mut length = 0;
+    let mut shift = 1;
+    let mut previous_discrepancy = field.one();
+    for n in 0..sequence.len() {
+        let discrepancy = field.sum((0..=length).filter(|i| *i < current.len()).map(|i| field.mul_ref(&current[i], &sequence[n - i])));
+        if field.is_zero(&discrepancy) {
+            shift += 1;
+            continue;
+        }
+        let factor = field.div(&discrepancy, &previous_discrepancy);
+        let mut updated = current.iter().map(|c| field.clone_el(c)).collect::<Vec<_>>();
+        if updated.len() < previous.len() + shift {
+            updated.resize_with(previous.len() + shift, || field.zero());
+        }
+        for (i, b) in previous.iter().enumerate() {
+            field.sub_assign(&mut updated[i + shift], field.mul_ref(&factor, b));
+        }
+        if 2 * length <= n {
+            length = n + 1 - length;
+            previous = std::mem::replace(&mut current, updated);
+            previous_discrepancy = discrepancy;
+            shift = 1;
+        } else {
+            current = updated;
+            shift += 1;
+        }
+    }
+    // the minimal polynomial is the reversal `X^L C(1/X)`
+    return poly_ring.from_terms(current.into_iter().enumerate().filter(|(i, _)| *i <= length).map(|(i, c)| (c, length - i)));
+}
+
+fn even_odd_part<P>(poly_ring: P, f: &El<P>, parity: usize) -> El<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing
+{
+    poly_ring.from_terms(poly_ring.terms(f).filter(|(_, i)| *i % 2 == parity).map(|(c, i)| (poly_ring.base_ring().clone_el(c), i / 2)))
+}
+
+///
+/// Computes the `n`-th coefficient of the power series `P/Q`, using the algorithm of Bostan and Mori.
+/// This requires `Q` to have constant coefficient `1`, and `deg(P) < deg(Q)`.
+///
+/// The algorithm uses the identity `P(X)/Q(X) = P(X)Q(-X) / Q(X)Q(-X)`, where the denominator is a
+/// polynomial in `X^2`. Taking only the even resp. odd part of the numerator thus halves `n`, and
+/// the cost is `O(log(n))` multiplications of polynomials of degree `deg(Q)`. It works over any ring.
+///
+/// # Example
+/// ```
+/// # use feanor_math::assert_el_eq;
+/// # use feanor_math::ring::*;
+/// # use feanor_math::homomorphism::*;
+/// # use feanor_math::rings::poly::*;
+/// # use feanor_math::rings::poly::dense_poly::*;
+/// # use feanor_math::rings::zn::zn_64::*;
+/// # use feanor_math::algorithms::linear_recurrence::*;
+/// let Fp = Zn::new(1000000007);
+/// let P = DensePolyRing::new(Fp, "X");
+/// // the generating function of the Fibonacci numbers is `X / (1 - X - X^2)`
+/// let num = P.indeterminate();
+/// let den = P.from_terms([(Fp.one(), 0), (Fp.neg_one(), 1), (Fp.neg_one(), 2)].into_iter());
+/// assert_el_eq!(&Fp, &Fp.int_hom().map(209783453), &bostan_mori(&P, &num, &den, 1000000000000000000));
+/// ```
+///
+pub fn bostan_mori<P>(poly_ring: P, numerator: &El<P>, denominator: &El<P>, mut n: usize) -> El<<P::Type as RingExtension>::BaseRing>
+    where P: PolyRingStore,
+        P::Type: PolyRing
+{
+    assert!(poly_ring.base_ring().is_one(poly_ring.coefficient_at(denominator, 0)));
+    assert!(poly_ring.degree(numerator).map(|d| d < poly_ring.degree(denominator).unwrap()).unwrap_or(true));
+    let mut numerator = poly_ring.clone_el(numerator);
+    let mut denominator = poly_ring.clone_el(denominator);
+    while n > 0 {
+        let conjugate = poly_ring.from_terms(poly_ring.terms(&denominator).map(|(c, i)| (if i % 2 == 0 { poly_ring.base_ring().clone_el(c) } else { poly_ring.base_ring().negate(poly_ring.base_ring().clone_el(c)) }, i)));
+        numerator = even_odd_part(&poly_ring, &poly_ring.mul_ref(&numerator, &conjugate), n % 2);
+        denominator = even_odd_part(&poly_ring, &poly_ring.mul(denominator, conjugate), 0);
+        n /= 2;
+    }
+    // the constant coefficient of the denominator stays `1`
+    return poly_ring.base_ring().clone_el(poly_ring.coefficient_at(&numerator, 0));
+}
+
+///
+/// A linearly recurrent (also called C-recursive) sequence `s_0, s_1, ...` over a ring `R`, i.e.
+/// a sequence with `s_n = c_1 s_(n - 1) + ... + c_d s_(n - d)` for all `n >= d`.
+///
+/// Single terms can be computed in time `O(M(d) log(n))` via [`LinearRecurrence::nth()`], where
+/// `M(d)` is the cost of multiplying polynomials of degree `d`. Over fields, the shortest recurrence
+/// satisfied by a given sequence can be found using [`LinearRecurrence::from_sequence()`].
+///
+/// Note that [`LinearRecurrence::nth()`] computes with intermediate values that can be much larger
+/// than the result, so over fixed-size integers it might overflow. In this case, use
+/// [`LinearRecurrence::terms()`] or an arbitrary-precision integer ring like [`crate::integer::BigIntRing`].
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::primitive_int::*;
+/// # use feanor_math::algorithms::linear_recurrence::*;
+/// // the Fibonacci numbers
+/// let fibonacci = LinearRecurrence::new(StaticRing::<i64>::RING, vec![1, 1], vec![0, 1]);
+/// assert_eq!(vec![0, 1, 1, 2, 3, 5, 8], fibonacci.terms(7));
+/// assert_eq!(6765, fibonacci.nth(20));
+/// ```
+///
+pub struct LinearRecurrence<R: RingStore> {
+    ring: R,
+    coefficients: Vec<El<R>>,
+    initial_values: Vec<El<R>>
+}
+
+impl<R: RingStore> LinearRecurrence<R> {
+
+    ///
+    /// Creates the sequence with `s_n = c_1 s_(n - 1) + ... + c_d s_(n - d)`, where `coefficients`
+    /// contains `c_1, ..., c_d`, and the initial values `s_0, ..., s_(d - 1)` are given by `initial_values`.
+    ///
+    pub fn new(ring: R, coefficients: Vec<El<R>>, initial_values: Vec<El<R>>) -> Self {
+        assert_eq!(coefficients.len(), initial_values.len());
+        LinearRecurrence { ring, coefficients, initial_values }
+    }
+
+    pub fn ring(&self) -> &R {
+        &self.ring
+    }
+
+    ///
+    /// Returns the order `d` of the recurrence, i.e. the number of previous terms each term depends on.
+    ///
+    pub fn order(&self) -> usize {
+        self.coefficients.len()
+    }
+
+    ///
+    /// Returns the coefficients `c_1, ..., c_d` of the recurrence `s_n = c_1 s_(n - 1) + ... + c_d s_(n - d)`.
+    ///
+    pub fn coefficients(&self) -> &[El<R>] {
+        &self.coefficients
+    }
+
+    pub fn initial_values(&self) -> &[El<R>] {
+        &self.initial_values
+    }
+
+    ///
+    /// Returns the characteristic polynomial `X^d - c_1 X^(d - 1) - ... - c_d` of the recurrence.
+    ///
+    pub fn characteristic_poly<P>(&self, poly_ring: P) -> El<P>
+        where P: PolyRingStore,
+            P::Type: PolyRing,
+            <P::Type as RingExtension>::BaseRing: RingStore<Type = R::Type>
+    {
+        let d = self.order();
+        poly_ring.from_terms(self.coefficients.iter().enumerate()
+            .map(|(i, c)| (poly_ring.base_ring().negate(self.ring.clone_el(c)), d - 1 - i))
+            .chain(Some((poly_ring.base_ring().one(), d))))
+    }
+
+    ///
+    /// Returns the first `count` terms `s_0, ..., s_(count - 1)` of the sequence.
+    ///
+    pub fn terms(&self, count: usize) -> Vec<El<R>> {
+        let mut result = self.initial_values.iter().take(count).map(|x| self.ring.clone_el(x)).collect::<Vec<_>>();
+        while result.len() < count {
+            let n = result.len();
+            let next = self.ring.sum(self.coefficients.iter().enumerate().map(|(i, c)| self.ring.mul_ref(c, &result[n - 1 - i])));
+            result.push(next);
+        }
+        return result;
+    }
+
+    ///
+    /// Computes the `n`-th term `s_n` of the sequence, using [`bostan_mori()`].
+    ///
+    pub fn nth(&self, n: usize) -> El<R> {
+        let d = self.order();
+        if n < d {
+            return self.ring.clone_el(&self.initial_values[n]);
+        }
+        let poly_ring = DensePolyRing::new(&self.ring, "X");
+        // the generating function is `P/Q` with `Q = 1 - c_1 X - ... - c_d X^d` and `P = (s_0 + s_1 X + ...) Q mod X^d`
+        let denominator = poly_ring.from_terms(self.coefficients.iter().enumerate()
+            .map(|(i, c)| (self.ring.negate(self.ring.clone_el(c)), i + 1))
+            .chain(Some((self.ring.one(), 0))));
+        let initial = poly_ring.from_terms(self.initial_values.iter().enumerate().map(|(i, x)| (self.ring.clone_el(x), i)));
+        let product = poly_ring.mul_ref(&initial, &denominator);
+        let numerator = poly_ring.from_terms(poly_ring.terms(&product).filter(|(_, i)| *i < d).map(|(c, i)| (self.ring.clone_el(c), i)));
+        return bostan_mori(&poly_ring, &numerator, &denominator, n);
+    }
+}
+
+impl<R: RingStore> LinearRecurrence<R>
+    where R::Type: Field
+{
+    ///
+    /// Finds the linear recurrence of smallest order that generates the given sequence, using
+    /// [`berlekamp_massey()`]. If the sequence satisfies a linear recurrence of order `d`, the
+    /// result is guaranteed to be this recurrence if at least `2d` terms are given.
+    ///
+    pub fn from_sequence(ring: R, sequence: &[El<R>]) -> Self {
+        let poly_ring = DensePolyRing::new(&ring, "X");
+        let minpoly = berlekamp_massey(&poly_ring, sequence);
+        let d = poly_ring.degree(&minpoly).unwrap();
+        let coefficients = (0..d).map(|i| ring.negate(ring.clone_el(poly_ring.coefficient_at(&minpoly, d - 1 - i)))).collect();
+        let initial_values = sequence.iter().take(d).map(|x| ring.clone_el(x)).collect();
+        return LinearRecurrence::new(ring, coefficients, initial_values);
+    }
+}
+
+impl<R: RingStore + Clone> Clone for LinearRecurrence<R> {
+
+    fn clone(&self) -> Self {
+        LinearRecurrence {
+            ring: self.ring.clone(),
+            coefficients: self.coefficients.iter().map(|c| self.ring.clone_el(c)).collect(),
+            initial_values: self.initial_values.iter().map(|x| self.ring.clone_el(x)).collect()
+        }
+    }
+}
+
+#[cfg(test)]
+use crate::homomorphism::*;
+#[cfg(test)]
+use crate::primitive_int::StaticRing;
+#[cfg(test)]
+use crate::integer::BigIntRing;
+#[cfg(test)]
+use crate::rings::zn::zn_static::Fp;
+#[cfg(test)]
+use crate::rings::zn::zn_64;
+
+#[test]
+fn test_berlekamp_massey() {
+    let F2 = Fp::<2>::RING;
+    let F2X = DensePolyRing::new(F2, "X");
+    // the maximal-length LFSR with feedback polynomial `X^5 + X^2 + 1`
+    let lfsr = LinearRecurrence::new(F2, vec![0, 0, 1, 0, 1], vec![1, 0, 0, 0, 0]);
+    let sequence = lfsr.terms(20);
+    let minpoly = berlekamp_massey(&F2X, &sequence);
+    assert_el_eq!(&F2X, &lfsr.characteristic_poly(&F2X), &minpoly);
+    let recovered = LinearRecurrence::from_sequence(F2, &sequence[..10]);
+    assert_eq!(sequence, recovered.terms(20));
+
+    let F7 = Fp::<7>::RING;
+    let F7X = DensePolyRing::new(F7, "X");
+    assert_el_eq!(&F7X, &F7X.one(), &berlekamp_massey(&F7X, &[]));
+    assert_el_eq!(&F7X, &F7X.one(), &berlekamp_massey(&F7X, &[0, 0, 0]));
+    // `0, 0, 0, 1` does not satisfy any recurrence of order smaller than 4
+    assert_eq!(Some(4), F7X.degree(&berlekamp_massey(&F7X, &[0, 0, 0, 1])));
+    // `2^i + 3^i`
+    let sequence = (0..8).map(|i| F7.add(F7.pow(2, i), F7.pow(3, i))).collect::<Vec<_>>();
+    let expected = F7X.mul(F7X.from_terms([(5, 0), (1, 1)].into_iter()), F7X.from_terms([(4, 0), (1, 1)].into_iter()));
+    assert_el_eq!(&F7X, &expected, &berlekamp_massey(&F7X, &sequence));
+}
+
+#[test]
+fn test_bostan_mori() {
+    let Fp = zn_64::Zn::new(65537);
+    let mut rng = oorandom::Rand64::new(1);
+    let coefficients = (0..7).map(|_| Fp.int_hom().map((rng.rand_u64() % 65537) as i32)).collect::<Vec<_>>();
+    let initial_values = (0..7).map(|_| Fp.int_hom().map((rng.rand_u64() % 65537) as i32)).collect::<Vec<_>>();
+    let sequence = LinearRecurrence::new(Fp, coefficients, initial_values);
+    let terms = sequence.terms(300);
+    for n in [0, 1, 6, 7, 8, 100, 255, 256, 299] {
+        assert_el_eq!(&Fp, &terms[n], &sequence.nth(n));
+    }
+
+    let ZZ = BigIntRing::RING;
+    let fibonacci = LinearRecurrence::new(ZZ, vec![ZZ.one(), ZZ.one()], vec![ZZ.zero(), ZZ.one()]);
+    let terms = fibonacci.terms(200);
+    for n in 0..200 {
+        assert_el_eq!(&ZZ, &terms[n], &fibonacci.nth(n));
+    }
+    assert_eq!(6765, LinearRecurrence::new(StaticRing::<i64>::RING, vec![1, 1], vec![0, 1]).nth(20));
+}
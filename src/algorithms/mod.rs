@@ -1,5 +1,8 @@
 pub mod karatsuba;
 pub mod conv_mul;
+pub mod fft_mul;
+pub mod half_gcd;
+pub mod linear_recurrence;
 pub mod sqr_mul;
 pub mod eea;
 pub mod unity_root;
@@ -12,7 +15,19 @@ pub mod int_bisect;
 pub mod int_factor;
 pub mod cyclotomic;
 pub mod poly_div;
+pub mod poly_compose;
+pub mod poly_squarefree;
+pub mod interpolate;
 pub mod cantor_zassenhaus;
+pub mod berlekamp;
+pub mod kaltofen_shoup;
+pub mod poly_factor;
+pub mod finite_field_roots;
+pub mod zassenhaus;
+pub mod trager;
+pub mod resultant;
+pub mod real_roots;
+pub mod complex_roots;
 pub mod discrete_log;
 pub mod f4;
 pub mod sparse_invert;
@@ -0,0 +1,241 @@
+use std::cmp::max;
+
+use crate::pid::{EuclideanRing, EuclideanRingStore};
+use crate::ring::*;
+use crate::homomorphism::*;
+use crate::rings::poly::{PolyRing, PolyRingStore};
+
+const COMPOSE_HORNER_THRESHOLD: usize = 8;
+
+///
+/// Computes `sum_i f_(start + i) g^i` for `0 <= i < len`, where `g_powers[k] = g^(2^k)`.
+///
+fn compose_rec<P>(poly_ring: P, f: &El<P>, start: usize, len: usize, g: &El<P>, g_powers: &[El<P>]) -> El<P>
+    where P: PolyRingStore + Copy,
+        P::Type: PolyRing
+{
+    if len <= COMPOSE_HORNER_THRESHOLD {
+        let mut result = poly_ring.zero();
+        for i in (start..(start + len)).rev() {
+            result = poly_ring.mul_ref_snd(result, g);
+            poly_ring.add_assign(&mut result, poly_ring.inclusion().map_ref(poly_ring.coefficient_at(f, i)));
+        }
+        return result;
+    }
+    let k = (usize::BITS - (len - 1).leading_zeros() - 1) as usize;
+    let half = 1 << k;
+    let lower = compose_rec(poly_ring, f, start, half, g, g_powers);
+    let upper = compose_rec(poly_ring, f, start + half, len - half, g, g_powers);
+    return poly_ring.add(lower, poly_ring.mul_ref_snd(upper, &g_powers[k]));
+}
+
+///
+/// Computes the composition `f(g)` of two polynomials.
+///
+/// This uses a divide-and-conquer approach, i.e. writes `f = f0 + X^m f1` and computes `f0(g) + g^m f1(g)`
+/// recursively. Hence, in contrast to Horner's rule, most of the work is done in multiplications of
+/// polynomials of large degree, which can use fast multiplication algorithms.
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::rings::poly::*;
+/// # use feanor_math::rings::poly::dense_poly::*;
+/// # use feanor_math::primitive_int::*;
+/// # use feanor_math::assert_el_eq;
+/// # use feanor_math::algorithms::poly_compose::*;
+/// let P = DensePolyRing::new(StaticRing::<i64>::RING, "X");
+/// let f = P.from_terms([(1, 0), (1, 2)].into_iter());
+/// let g = P.from_terms([(1, 0), (1, 1)].into_iter());
+/// // `(X + 1)^2 + 1 = X^2 + 2X + 2`
+/// assert_el_eq!(&P, &P.from_terms([(2, 0), (2, 1), (1, 2)].into_iter()), &compose(&P, &f, &g));
+/// ```
+///
+pub fn compose<P>(poly_ring: P, f: &El<P>, g: &El<P>) -> El<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing
+{
+    let len = match poly_ring.degree(f) {
+        None => return poly_ring.zero(),
+        Some(d) => d + 1
+    };
+    let mut g_powers = vec![poly_ring.clone_el(g)];
+    while (1 << g_powers.len()) < len {
+        let square = poly_ring.pow(poly_ring.clone_el(g_powers.last().unwrap()), 2);
+        g_powers.push(square);
+    }
+    return compose_rec(&poly_ring, f, 0, len, g, &g_powers);
+}
+
+///
+/// Computes `f(g) mod h` using the baby-step giant-step method of Brent and Kung. This requires
+/// only about `2 sqrt(deg(f))` multiplications modulo `h`, as opposed to the `deg(f)` multiplications
+/// of Horner's rule.
+///
+/// A typical application is the computation of Frobenius images `X^(q^i) mod h` over a finite field
+/// `Fq`, since `X^(q^(i + 1)) = (X^(q^i))(X^q) mod h`. This is used for example in
+/// [`crate::algorithms::kaltofen_shoup::distinct_degree_factorization()`].
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::homomorphism::*;
+/// # use feanor_math::rings::poly::*;
+/// # use feanor_math::rings::poly::dense_poly::*;
+/// # use feanor_math::rings::zn::*;
+/// # use feanor_math::rings::zn::zn_64::*;
+/// # use feanor_math::pid::*;
+/// # use feanor_math::assert_el_eq;
+/// # use feanor_math::algorithms::poly_compose::*;
+/// let Fp = Zn::new(7).as_field().ok().unwrap();
+/// let P = DensePolyRing::new(Fp, "X");
+/// let h = P.from_terms([(Fp.int_hom().map(3), 0), (Fp.one(), 1), (Fp.one(), 5)].into_iter());
+/// let x_pow_p = P.euclidean_rem(P.pow(P.indeterminate(), 7), &h);
+/// // the Frobenius `g -> g^p` is a ring homomorphism, hence `X^(p^2) = (X^p)(X^p) mod h`
+/// let x_pow_p_sqr = modular_compose(&P, &x_pow_p, &x_pow_p, &h);
+/// assert_el_eq!(&P, &P.euclidean_rem(P.pow(P.indeterminate(), 49), &h), &x_pow_p_sqr);
+/// ```
+///
+pub fn modular_compose<P>(poly_ring: P, f: &El<P>, g: &El<P>, h: &El<P>) -> El<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing + EuclideanRing
+{
+    baby_step_giant_step_compose(&poly_ring, f, g, |x| poly_ring.euclidean_rem(x, h))
+}
+
+///
+/// Computes `f(g) mod X^n`, using the baby-step giant-step method of Brent and Kung as in
+/// [`modular_compose()`]. Hence, this requires about `2 sqrt(deg(f))` multiplications of polynomials
+/// of degree `< n`, and `O(n deg(f))` additional operations in the base ring.
+///
+/// In particular, this computes the composition of truncated power series.
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::rings::poly::*;
+/// # use feanor_math::rings::poly::dense_poly::*;
+/// # use feanor_math::primitive_int::*;
+/// # use feanor_math::assert_el_eq;
+/// # use feanor_math::algorithms::poly_compose::*;
+/// let P = DensePolyRing::new(StaticRing::<i64>::RING, "X");
+/// let f = P.from_terms([(1, 1), (1, 2)].into_iter());
+/// let g = P.from_terms([(1, 1), (1, 2)].into_iter());
+/// // `(X + X^2) + (X + X^2)^2 = X + 2X^2 + 2X^3 + X^4`
+/// assert_el_eq!(&P, &P.from_terms([(1, 1), (2, 2)].into_iter()), &truncated_compose(&P, &f, &g, 3));
+/// ```
+///
+pub fn truncated_compose<P>(poly_ring: P, f: &El<P>, g: &El<P>, n: usize) -> El<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing
+{
+    let base_ring = poly_ring.base_ring();
+    baby_step_giant_step_compose(&poly_ring, f, g, |x| if poly_ring.degree(&x).map(|d| d < n).unwrap_or(true) {
+        x
+    } else {
+        poly_ring.from_terms(poly_ring.terms(&x).filter(|(_, i)| *i < n).map(|(c, i)| (base_ring.clone_el(c), i)))
+    })
+}
+
+///
+/// Computes `reduce(f(g))` using the baby-step giant-step method of Brent and Kung, where `reduce`
+/// is a ring homomorphism that is applied after each multiplication.
+///
+fn baby_step_giant_step_compose<P, F>(poly_ring: P, f: &El<P>, g: &El<P>, mut reduce: F) -> El<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        F: FnMut(El<P>) -> El<P>
+{
+    let deg_f = match poly_ring.degree(f) {
+        None => return poly_ring.zero(),
+        Some(d) => d
+    };
+    let k = max(1, ((deg_f + 1) as f64).sqrt().ceil() as usize);
+    let mut g_powers = Vec::with_capacity(k + 1);
+    g_powers.push(reduce(poly_ring.one()));
+    let g = reduce(poly_ring.clone_el(g));
+    for i in 0..k {
+        let next = reduce(poly_ring.mul_ref(&g_powers[i], &g));
+        g_powers.push(next);
+    }
+    let g_pow_k = g_powers.pop().unwrap();
+
+    // write `f = sum_j f_j X^(kj)` with `deg(f_j) < k`, and evaluate `sum_j f_j(g) (g^k)^j` by Horner's rule
+    let mut result = poly_ring.zero();
+    for j in (0..=(deg_f / k)).rev() {
+        result = reduce(poly_ring.mul_ref_snd(result, &g_pow_k));
+        for i in 0..k {
+            if j * k + i > deg_f {
+                break;
+            }
+            let c = poly_ring.coefficient_at(f, j * k + i);
+            if !poly_ring.base_ring().is_zero(c) {
+                poly_ring.add_assign(&mut result, poly_ring.inclusion().mul_ref_map(&g_powers[i], c));
+            }
+        }
+    }
+    return result;
+}
+
+#[cfg(test)]
+use crate::rings::poly::dense_poly::DensePolyRing;
+#[cfg(test)]
+use crate::rings::zn::zn_64::Zn;
+#[cfg(test)]
+use crate::rings::zn::ZnRingStore;
+#[cfg(test)]
+use crate::integer::BigIntRing;
+
+#[test]
+fn test_compose() {
+    let ZZ = BigIntRing::RING;
+    let ring = DensePolyRing::new(ZZ, "X");
+    let g = ring.from_terms([(ZZ.int_hom().map(-2), 0), (ZZ.int_hom().map(3), 1), (ZZ.one(), 3)].into_iter());
+    for deg_f in [0, 1, 2, 7, 8, 9, 16, 17, 40] {
+        let f = ring.from_terms((0..=deg_f).map(|i| (ZZ.int_hom().map(i as i32 - 5), i)));
+        let expected = ring.evaluate(&f, &g, &ring.inclusion());
+        assert_el_eq!(&ring, &expected, &compose(&ring, &f, &g));
+    }
+    assert!(ring.is_zero(&compose(&ring, &ring.zero(), &g)));
+
+    let f = ring.from_terms([(ZZ.one(), 0), (ZZ.int_hom().map(2), 10)].into_iter());
+    assert_el_eq!(&ring, &ring.inclusion().map(ZZ.int_hom().map(2049)), &compose(&ring, &f, &ring.inclusion().map(ZZ.int_hom().map(2))));
+    assert_el_eq!(&ring, &f, &compose(&ring, &f, &ring.indeterminate()));
+}
+
+#[test]
+fn test_modular_compose() {
+    let field = Zn::new(17).as_field().ok().unwrap();
+    let ring = DensePolyRing::new(field, "X");
+    let h = ring.from_terms([(field.int_hom().map(3), 0), (field.one(), 1), (field.one(), 7)].into_iter());
+    let g = ring.from_terms([(field.int_hom().map(5), 0), (field.int_hom().map(2), 3), (field.one(), 6)].into_iter());
+    for deg_f in [0, 1, 3, 4, 10] {
+        let f = ring.from_terms((0..=deg_f).map(|i| (field.int_hom().map(i as i32 + 1), i)));
+        let expected = ring.euclidean_rem(ring.evaluate(&f, &g, &ring.inclusion()), &h);
+        assert_el_eq!(&ring, &expected, &modular_compose(&ring, &f, &g, &h));
+    }
+    assert!(ring.is_zero(&modular_compose(&ring, &ring.zero(), &g, &h)));
+
+    // `g` does not have to be reduced modulo `h`
+    let g = ring.from_terms([(field.one(), 0), (field.int_hom().map(2), 9)].into_iter());
+    let f = ring.from_terms([(field.one(), 0), (field.int_hom().map(3), 2), (field.one(), 5)].into_iter());
+    let expected = ring.euclidean_rem(compose(&ring, &f, &g), &h);
+    assert_el_eq!(&ring, &expected, &modular_compose(&ring, &f, &g, &h));
+}
+
+
+#[test]
+fn test_truncated_compose() {
+    let ZZ = BigIntRing::RING;
+    let ring = DensePolyRing::new(ZZ, "X");
+    let g = ring.from_terms([(ZZ.int_hom().map(-2), 1), (ZZ.int_hom().map(3), 2), (ZZ.one(), 5)].into_iter());
+    for deg_f in [0, 1, 2, 7, 9, 30] {
+        let f = ring.from_terms((0..=deg_f).map(|i| (ZZ.int_hom().map(i as i32 - 5), i)));
+        let full = compose(&ring, &f, &g);
+        for n in [1, 4, 10, 31] {
+            let expected = ring.from_terms(ring.terms(&full).filter(|(_, i)| *i < n).map(|(c, i)| (ZZ.clone_el(c), i)));
+            assert_el_eq!(&ring, &expected, &truncated_compose(&ring, &f, &g, n));
+        }
+    }
+    assert!(ring.is_zero(&truncated_compose(&ring, &ring.zero(), &g, 5)));
+}
@@ -39,4 +39,145 @@ pub fn sparse_poly_div<P, S, F, E, H>(mut lhs: El<P>, rhs: &El<S>, lhs_ring: P,
         lhs_ring.get_ring().add_assign_from_terms(&mut result, std::iter::once((quo, i)));
     }
     return Ok((result, lhs));
-}
\ No newline at end of file
+}
+
+///
+/// Returns `f mod X^len`, i.e. the polynomial consisting of the terms of `f` of degree `< len`.
+/// 
+fn truncate<P>(poly_ring: P, f: &El<P>, len: usize) -> El<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing
+{
+    poly_ring.from_terms(poly_ring.terms(f).filter(|(_, i)| *i < len).map(|(c, i)| (poly_ring.base_ring().clone_el(c), i)))
+}
+
+///
+/// Returns `X^deg f(1/X)`, assuming `f` has degree at most `deg`.
+/// 
+fn reverse<P>(poly_ring: P, f: &El<P>, deg: usize) -> El<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing
+{
+    poly_ring.from_terms(poly_ring.terms(f).map(|(c, i)| {
+        assert!(i <= deg);
+        (poly_ring.base_ring().clone_el(c), deg - i)
+    }))
+}
+
+///
+/// Computes the inverse of `f` in the ring of power series `R[[X]]`, up to precision `X^precision`.
+/// In other words, this returns the unique polynomial `g` of degree `< precision` with `fg = 1 mod X^precision`.
+/// 
+/// This requires the constant coefficient of `f` to be a unit, and its inverse must be given as
+/// `constant_coeff_inv`. The computation uses Newton iteration, so its cost is a small multiple
+/// of the cost of a multiplication of polynomials of degree `precision`.
+/// 
+pub fn invert_power_series<P>(poly_ring: P, f: &El<P>, constant_coeff_inv: &El<<P::Type as RingExtension>::BaseRing>, precision: usize) -> El<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing
+{
+    assert!(poly_ring.base_ring().is_one(&poly_ring.base_ring().mul_ref(poly_ring.coefficient_at(f, 0), constant_coeff_inv)));
+    if precision == 0 {
+        return poly_ring.zero();
+    }
+    let mut result = poly_ring.inclusion().map(poly_ring.base_ring().clone_el(constant_coeff_inv));
+    let mut current_precision = 1;
+    while current_precision < precision {
+        current_precision = std::cmp::min(2 * current_precision, precision);
+        // Newton step `g -> g + g(1 - fg)`; the error `1 - fg` is divisible by `X^(current_precision / 2)`
+        let error = poly_ring.sub(poly_ring.one(), truncate(&poly_ring, &poly_ring.mul_ref(&truncate(&poly_ring, f, current_precision), &result), current_precision));
+        let correction = truncate(&poly_ring, &poly_ring.mul_ref(&result, &error), current_precision);
+        poly_ring.add_assign(&mut result, correction);
+    }
+    return result;
+}
+
+///
+/// Computes the division with remainder of `lhs` by `rhs`, i.e. returns `(q, r)` such that `lhs = q * rhs + r`
+/// and `deg r < deg rhs`. The inverse of the leading coefficient of `rhs` must be given as `lc_inv`.
+/// 
+/// In contrast to [`sparse_poly_div()`], this computes the quotient by multiplying with the inverse of the
+/// reversed divisor in the ring of power series (computed using [`invert_power_series()`]). Hence, its cost is
+/// a small multiple of the cost of multiplying polynomials of degree `deg lhs`, which makes it much faster
+/// for large degrees, as long as the polynomial ring implements subquadratic multiplication.
+/// 
+/// # Example
+/// ```
+/// # use feanor_math::assert_el_eq;
+/// # use feanor_math::ring::*;
+/// # use feanor_math::rings::poly::*;
+/// # use feanor_math::rings::poly::dense_poly::*;
+/// # use feanor_math::primitive_int::*;
+/// # use feanor_math::algorithms::poly_div::*;
+/// let ZZ = StaticRing::<i64>::RING;
+/// let P = DensePolyRing::new(ZZ, "X");
+/// let f = P.from_terms([(1, 0), (2, 1), (3, 5)].into_iter());
+/// let g = P.from_terms([(-1, 0), (1, 2)].into_iter());
+/// let (q, r) = fast_poly_div_rem(&P, P.clone_el(&f), &g, &1);
+/// assert!(P.degree(&r).unwrap() < 2);
+/// assert_el_eq!(&P, &f, &P.add(P.mul(q, g), r));
+/// ```
+/// 
+pub fn fast_poly_div_rem<P>(poly_ring: P, lhs: El<P>, rhs: &El<P>, lc_inv: &El<<P::Type as RingExtension>::BaseRing>) -> (El<P>, El<P>)
+    where P: PolyRingStore,
+        P::Type: PolyRing
+{
+    let rhs_deg = poly_ring.degree(rhs).unwrap();
+    let lhs_deg = if let Some(d) = poly_ring.degree(&lhs) { d } else { return (poly_ring.zero(), lhs); };
+    if lhs_deg < rhs_deg {
+        return (poly_ring.zero(), lhs);
+    }
+    let quo_len = lhs_deg - rhs_deg + 1;
+    let rhs_rev_inv = invert_power_series(&poly_ring, &reverse(&poly_ring, rhs, rhs_deg), lc_inv, quo_len);
+    let quo_rev = truncate(&poly_ring, &poly_ring.mul(truncate(&poly_ring, &reverse(&poly_ring, &lhs, lhs_deg), quo_len), rhs_rev_inv), quo_len);
+    let quo = reverse(&poly_ring, &quo_rev, quo_len - 1);
+    let rem = poly_ring.sub(lhs, poly_ring.mul_ref(&quo, rhs));
+    debug_assert!(poly_ring.degree(&rem).map(|d| d < rhs_deg).unwrap_or(true));
+    return (quo, rem);
+}
+
+#[cfg(test)]
+use crate::primitive_int::StaticRing;
+#[cfg(test)]
+use crate::rings::poly::dense_poly::DensePolyRing;
+#[cfg(test)]
+use crate::rings::zn::zn_static::Fp;
+#[cfg(test)]
+use crate::divisibility::DivisibilityRingStore;
+
+#[test]
+fn test_invert_power_series() {
+    let ZZ = StaticRing::<i64>::RING;
+    let P = DensePolyRing::new(ZZ, "X");
+    // 1/(1 - X) = 1 + X + X^2 + ...
+    let f = P.from_terms([(1, 0), (-1, 1)].into_iter());
+    let expected = P.from_terms((0..10).map(|i| (1, i)));
+    assert_el_eq!(&P, &expected, &invert_power_series(&P, &f, &1, 10));
+
+    let f = P.from_terms([(-1, 0), (3, 1), (1, 2), (-7, 5), (2, 11)].into_iter());
+    for precision in [0, 1, 2, 5, 8, 13, 30] {
+        let g = invert_power_series(&P, &f, &-1, precision);
+        assert!(P.degree(&g).map(|d| d < precision).unwrap_or(true));
+        let product = truncate(&P, &P.mul_ref(&f, &g), precision);
+        assert_el_eq!(&P, &truncate(&P, &P.one(), precision), &product);
+    }
+}
+
+#[test]
+fn test_fast_poly_div_rem() {
+    let Fp7 = Fp::<7>::RING;
+    let P = DensePolyRing::new(Fp7, "X");
+    let lhs = P.from_terms((0..50).map(|i| (((i * i + 3) % 7) as u64, i)));
+    for rhs in [
+        P.from_terms([(1, 0), (1, 1)].into_iter()),
+        P.from_terms([(2, 0), (5, 3), (3, 17)].into_iter()),
+        P.from_terms((0..50).map(|i| ((i % 7) as u64, i))),
+        P.from_terms([(1, 60)].into_iter())
+    ] {
+        let lc_inv = Fp7.invert(P.coefficient_at(&rhs, P.degree(&rhs).unwrap())).unwrap();
+        let (quo, rem) = fast_poly_div_rem(&P, P.clone_el(&lhs), &rhs, &lc_inv);
+        let (expected_quo, expected_rem) = sparse_poly_div(P.clone_el(&lhs), &rhs, &P, &P, |x| Ok::<_, ()>(Fp7.mul_ref(x, &lc_inv)), &Fp7.identity()).unwrap();
+        assert_el_eq!(&P, &expected_quo, &quo);
+        assert_el_eq!(&P, &expected_rem, &rem);
+    }
+}
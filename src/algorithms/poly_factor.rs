@@ -0,0 +1,372 @@
+use crate::algorithms;
+use crate::divisibility::DivisibilityRingStore;
+use crate::field::{Field, FieldStore};
+use crate::integer::*;
+use crate::ordered::OrderedRingStore;
+use crate::pid::EuclideanRing;
+use crate::ring::*;
+use crate::homomorphism::*;
+use crate::rings::finite::{FiniteRing, FiniteRingStore};
+use crate::rings::poly::{PolyRing, PolyRingStore};
+use crate::rings::poly::dense_poly::DensePolyRing;
+use crate::rings::rational::RationalFieldBase;
+use crate::rings::extension::number_field::NumberFieldBase;
+
+///
+/// Fields of at most this size are handled by Berlekamp's algorithm in [`factor_over_finite_field()`].
+///
+pub const BERLEKAMP_MAX_FIELD_SIZE: i64 = 32;
+
+///
+/// Square-free polynomials of at least this degree are handled by the Kaltofen-Shoup distinct-degree
+/// factorization in [`factor_over_finite_field()`].
+///
+pub const KALTOFEN_SHOUP_MIN_DEGREE: usize = 64;
+
+fn make_monic<P>(poly_ring: P, mut f: El<P>) -> El<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: Field
+{
+    let lc_inv = poly_ring.base_ring().div(&poly_ring.base_ring().one(), poly_ring.lc(&f).unwrap());
+    poly_ring.inclusion().mul_assign_map_ref(&mut f, &lc_inv);
+    return f;
+}
+
+///
+/// Splits a polynomial whose irreducible factors are all distinct and of degree `d` into these factors.
+///
+fn equal_degree_factorization<P>(poly_ring: P, f: El<P>, d: usize) -> Vec<El<P>>
+    where P: PolyRingStore,
+        P::Type: PolyRing + EuclideanRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: FiniteRing + Field
+{
+    let mut result = Vec::new();
+    let mut stack = vec![f];
+    while let Some(current) = stack.pop() {
+        match poly_ring.degree(&current).unwrap() {
+            0 => {},
+            deg if deg == d => result.push(current),
+            _ => {
+                let factor = algorithms::cantor_zassenhaus::cantor_zassenhaus(&poly_ring, poly_ring.clone_el(&current), d);
+                stack.push(poly_ring.checked_div(&current, &factor).unwrap());
+                stack.push(factor);
+            }
+        }
+    }
+    return result;
+}
+
+///
+/// Factors a square-free polynomial over a finite field into monic irreducible factors.
+///
+fn factor_squarefree<P>(poly_ring: P, f: El<P>) -> Vec<El<P>>
+    where P: PolyRingStore,
+        P::Type: PolyRing + EuclideanRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: FiniteRing + Field
+{
+    let ZZ = BigIntRing::RING;
+    let small_field = ZZ.is_leq(&poly_ring.base_ring().size(&ZZ), &ZZ.int_hom().map(BERLEKAMP_MAX_FIELD_SIZE as i32));
+    let degree = poly_ring.degree(&f).unwrap();
+    let factors = if small_field && degree < KALTOFEN_SHOUP_MIN_DEGREE {
+        algorithms::berlekamp::berlekamp(&poly_ring, f)
+    } else {
+        let distinct_degree_factors = if degree >= KALTOFEN_SHOUP_MIN_DEGREE {
+            algorithms::kaltofen_shoup::distinct_degree_factorization(&poly_ring, f)
+        } else {
+            algorithms::cantor_zassenhaus::distinct_degree_factorization(&poly_ring, f)
+        };
+        distinct_degree_factors.into_iter().enumerate().skip(1)
+            .flat_map(|(d, factor)| equal_degree_factorization(&poly_ring, factor, d))
+            .collect()
+    };
+    return factors.into_iter().map(|g| make_monic(&poly_ring, g)).collect();
+}
+
+///
+/// Factors a polynomial over a finite field into irreducible factors, and returns a list of
+/// the monic irreducible factors together with their multiplicities. If the leading coefficient
+/// of `f` is not one, it is appended to the list as constant polynomial with multiplicity one,
+/// exactly as in [`crate::algorithms::cantor_zassenhaus::factor_complete()`].
+///
+/// After computing the square-free decomposition, the algorithm is chosen depending on the
+/// input:
+///  - over small fields, we use Berlekamp's algorithm [`crate::algorithms::berlekamp::berlekamp()`]
+///  - for large degrees, we use the baby-step giant-step distinct-degree factorization
+///    [`crate::algorithms::kaltofen_shoup::distinct_degree_factorization()`], followed by
+///    the equal-degree factorization of [`crate::algorithms::cantor_zassenhaus::cantor_zassenhaus()`]
+///  - otherwise, we use the standard Cantor-Zassenhaus algorithm
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::rings::poly::*;
+/// # use feanor_math::rings::poly::dense_poly::*;
+/// # use feanor_math::rings::zn::zn_static::*;
+/// # use feanor_math::algorithms::poly_factor::*;
+/// # use feanor_math::assert_el_eq;
+/// let ring = DensePolyRing::new(Fp::<5>::RING, "X");
+/// // `X^4 - 1 = (X - 1)(X - 2)(X - 3)(X - 4)` over `F5`
+/// let f = ring.sub(ring.pow(ring.indeterminate(), 4), ring.one());
+/// let factorization = factor_over_finite_field(&ring, ring.clone_el(&f));
+/// assert_eq!(4, factorization.len());
+/// assert!(factorization.iter().all(|(g, e)| ring.degree(g) == Some(1) && *e == 1));
+/// assert_el_eq!(&ring, &f, &ring.prod(factorization.into_iter().map(|(g, _)| g)));
+/// ```
+///
+pub fn factor_over_finite_field<P>(poly_ring: P, mut f: El<P>) -> Vec<(El<P>, usize)>
+    where P: PolyRingStore,
+        P::Type: PolyRing + EuclideanRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: FiniteRing + Field
+{
+    assert!(!poly_ring.is_zero(&f));
+    let unit = poly_ring.base_ring().clone_el(poly_ring.lc(&f).unwrap());
+    f = make_monic(&poly_ring, f);
+
+    let mut result: Vec<(El<P>, usize)> = Vec::new();
+    // the factors of the square-free decomposition are monic and pairwise coprime
+    for (sqrfree_part, power) in algorithms::poly_squarefree::squarefree_decomposition_finite_field(&poly_ring, &f) {
+        result.extend(factor_squarefree(&poly_ring, sqrfree_part).into_iter().map(|factor| (factor, power)));
+    }
+    if !poly_ring.base_ring().is_one(&unit) {
+        result.push((poly_ring.inclusion().map(unit), 1));
+    }
+    return result;
+}
+
+///
+/// Factors a nonzero polynomial over the integers into irreducible factors, and returns a list of the
+/// primitive irreducible factors with positive leading coefficient together with their multiplicities.
+/// If the content of `f` (with the sign of the leading coefficient) is not one, it is appended to the list
+/// as constant polynomial with multiplicity one.
+///
+/// This uses the algorithm of Zassenhaus, see [`crate::algorithms::zassenhaus::zassenhaus()`].
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::rings::poly::*;
+/// # use feanor_math::rings::poly::dense_poly::*;
+/// # use feanor_math::primitive_int::*;
+/// # use feanor_math::algorithms::poly_factor::*;
+/// # use feanor_math::assert_el_eq;
+/// let ring = DensePolyRing::new(StaticRing::<i64>::RING, "X");
+/// // `2 X^4 - 2 = 2 (X - 1)(X + 1)(X^2 + 1)` over `Z`
+/// let f = ring.from_terms([(-2, 0), (2, 4)].into_iter());
+/// let factorization = factor_over_integers(&ring, ring.clone_el(&f));
+/// assert_eq!(4, factorization.len());
+/// assert!(factorization.iter().any(|(g, _)| ring.eq_el(g, &ring.from_terms([(1, 0), (1, 2)].into_iter()))));
+/// assert!(factorization.iter().any(|(g, _)| ring.eq_el(g, &ring.from_terms([(2, 0)].into_iter()))));
+/// assert_el_eq!(&ring, &f, &ring.prod(factorization.into_iter().map(|(g, _)| g)));
+/// ```
+///
+pub fn factor_over_integers<P>(poly_ring: P, f: El<P>) -> Vec<(El<P>, usize)>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <P::Type as RingExtension>::BaseRing: IntegerRingStore,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: IntegerRing
+{
+    assert!(!poly_ring.is_zero(&f));
+    let ZZ = poly_ring.base_ring();
+    let ZZbig = BigIntRing::RING;
+    let ZZbigX = DensePolyRing::new(ZZbig, "X");
+    let f = ZZbigX.from_terms(poly_ring.terms(&f).map(|(c, i)| (int_cast(ZZ.clone_el(c), &ZZbig, ZZ), i)));
+    return algorithms::zassenhaus::factor_complete(&ZZbigX, f).into_iter()
+        .map(|(g, e)| (poly_ring.from_terms(ZZbigX.terms(&g).map(|(c, i)| (int_cast(ZZbig.clone_el(c), ZZ, &ZZbig), i))), e))
+        .collect();
+}
+
+///
+/// Factors a nonzero polynomial over the rationals into irreducible factors, and returns a list of
+/// the monic irreducible factors together with their multiplicities. If the leading coefficient
+/// of `f` is not one, it is appended to the list as constant polynomial with multiplicity one.
+///
+/// This clears denominators and then uses [`factor_over_integers()`], since by Gauss' lemma, the
+/// factorization over the rationals is the same as the one over the integers, up to units.
+///
+pub fn factor_over_rationals<P, I>(poly_ring: P, f: El<P>) -> Vec<(El<P>, usize)>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <P::Type as RingExtension>::BaseRing: RingStore<Type = RationalFieldBase<I>>,
+        I: IntegerRingStore,
+        I::Type: IntegerRing
+{
+    assert!(!poly_ring.is_zero(&f));
+    let QQ = poly_ring.base_ring();
+    let ZZ = QQ.get_ring().base_ring();
+    let ZZbig = BigIntRing::RING;
+    let ZZbigX = DensePolyRing::new(ZZbig, "X");
+    let unit = QQ.clone_el(poly_ring.lc(&f).unwrap());
+    let den = poly_ring.terms(&f).fold(ZZbig.one(), |current, (c, _)| algorithms::eea::lcm(current, int_cast(ZZ.clone_el(QQ.get_ring().den(c)), &ZZbig, ZZ), &ZZbig));
+    let f = ZZbigX.from_terms(poly_ring.terms(&f).map(|(c, i)| (
+        ZZbig.checked_div(&ZZbig.mul_ref_snd(int_cast(ZZ.clone_el(QQ.get_ring().num(c)), &ZZbig, ZZ), &den), &int_cast(ZZ.clone_el(QQ.get_ring().den(c)), &ZZbig, ZZ)).unwrap(), 
+        i
+    )));
+    let mut result = algorithms::zassenhaus::factor_complete(&ZZbigX, f).into_iter()
+        .filter(|(g, _)| ZZbigX.degree(g).unwrap() > 0)
+        .map(|(g, e)| (make_monic(&poly_ring, poly_ring.from_terms(ZZbigX.terms(&g).map(|(c, i)| (QQ.get_ring().from_fraction(int_cast(ZZbig.clone_el(c), ZZ, &ZZbig), ZZ.one()), i)))), e))
+        .collect::<Vec<_>>();
+    if !QQ.is_one(&unit) {
+        result.push((poly_ring.inclusion().map(unit), 1));
+    }
+    return result;
+}
+
+///
+/// Factors a nonzero polynomial over an algebraic number field into irreducible factors, and returns a list of
+/// the monic irreducible factors together with their multiplicities. If the leading coefficient of `f` is not one,
+/// it is appended to the list as constant polynomial with multiplicity one.
+///
+/// This uses the norm-based algorithm of Trager, see [`crate::algorithms::trager::trager()`].
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::homomorphism::*;
+/// # use feanor_math::integer::*;
+/// # use feanor_math::rings::poly::*;
+/// # use feanor_math::rings::poly::dense_poly::*;
+/// # use feanor_math::rings::extension::number_field::*;
+/// # use feanor_math::algorithms::poly_factor::*;
+/// let ZZ = BigIntRing::RING;
+/// // the field `Q(i)`, over which `X^2 + 1 = (X - i)(X + i)`
+/// let K = NumberField::new(ZZ, vec![ZZ.int_hom().map(-1), ZZ.zero()]);
+/// let ring = DensePolyRing::new(&K, "X");
+/// let f = ring.add(ring.pow(ring.indeterminate(), 2), ring.one());
+/// let factorization = factor_over_number_field(&ring, f);
+/// assert_eq!(2, factorization.len());
+/// assert!(factorization.iter().all(|(g, _)| ring.degree(g) == Some(1)));
+/// ```
+///
+pub fn factor_over_number_field<P, I>(poly_ring: P, f: El<P>) -> Vec<(El<P>, usize)>
+    where P: PolyRingStore,
+        P::Type: PolyRing + EuclideanRing,
+        <P::Type as RingExtension>::BaseRing: RingStore<Type = NumberFieldBase<I>>,
+        I: IntegerRingStore,
+        I::Type: IntegerRing
+{
+    algorithms::trager::factor_complete(poly_ring, f)
+}
+
+#[cfg(test)]
+use crate::rings::zn::zn_static::Fp;
+#[cfg(test)]
+use crate::primitive_int::StaticRing;
+#[cfg(test)]
+use crate::rings::rational::RationalField;
+#[cfg(test)]
+use crate::rings::zn::zn_64::Zn;
+#[cfg(test)]
+use crate::rings::zn::ZnRingStore;
+#[cfg(test)]
+use crate::rings::extension::galois_field::GaloisField;
+#[cfg(test)]
+use crate::rings::extension::FreeAlgebraStore;
+
+#[cfg(test)]
+fn assert_is_factorization<P>(poly_ring: P, f: &El<P>, factorization: &[(El<P>, usize)])
+    where P: PolyRingStore,
+        P::Type: PolyRing + EuclideanRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: FiniteRing + Field
+{
+    assert_el_eq!(&poly_ring, f, &poly_ring.prod(factorization.iter().map(|(g, e)| poly_ring.pow(poly_ring.clone_el(g), *e))));
+    for (g, _) in factorization.iter().filter(|(g, _)| poly_ring.degree(g).unwrap() > 0) {
+        assert!(poly_ring.base_ring().is_one(poly_ring.lc(g).unwrap()));
+        assert!(algorithms::cantor_zassenhaus::is_irreducible(&poly_ring, g));
+    }
+}
+
+#[test]
+fn test_factor_over_finite_field_small_field() {
+    let ring = DensePolyRing::new(Fp::<3>::RING, "X");
+    let f = ring.prod([
+        ring.from_terms([(1, 0), (1, 2)].into_iter()),
+        ring.from_terms([(1, 0), (1, 2)].into_iter()),
+        ring.from_terms([(1, 0), (1, 1)].into_iter()),
+        ring.from_terms([(1, 0), (2, 1)].into_iter()),
+        ring.from_terms([(1, 0), (2, 1), (1, 3)].into_iter())
+    ].into_iter());
+    let factorization = factor_over_finite_field(&ring, ring.clone_el(&f));
+    assert_is_factorization(&ring, &f, &factorization);
+    assert_eq!(5, factorization.len());
+    assert_eq!(Some(2), factorization.iter().filter(|(g, _)| ring.degree(g) == Some(2)).map(|(_, e)| *e).next());
+}
+
+#[test]
+fn test_factor_over_finite_field_large_degree() {
+    let field = Zn::new(257).as_field().ok().unwrap();
+    let ring = DensePolyRing::new(field, "X");
+    let mut rng = oorandom::Rand64::new(1);
+    let factors = [1, 2, 4, 7, 11, 17, 29].into_iter()
+        .map(|d| algorithms::cantor_zassenhaus::random_irreducible(&ring, d, || rng.rand_u64()))
+        .collect::<Vec<_>>();
+    let f = ring.prod(factors.iter().map(|g| ring.clone_el(g)).chain([ring.clone_el(&factors[1])].into_iter()));
+    let f = ring.inclusion().mul_map(f, field.int_hom().map(3));
+    let factorization = factor_over_finite_field(&ring, ring.clone_el(&f));
+    assert_is_factorization(&ring, &f, &factorization);
+    assert_eq!(8, factorization.len());
+    for g in &factors {
+        assert!(factorization.iter().any(|(h, _)| ring.eq_el(g, h)));
+    }
+}
+
+#[test]
+fn test_factor_over_finite_field_extension_field() {
+    let field = GaloisField::new(7, 2);
+    let ring = DensePolyRing::new(&field, "X");
+    let x = field.canonical_gen();
+    let f = ring.prod([
+        ring.from_terms([(field.clone_el(&x), 0), (field.one(), 3)].into_iter()),
+        ring.from_terms([(field.one(), 0), (field.clone_el(&x), 1), (field.one(), 2)].into_iter()),
+        ring.from_terms([(field.one(), 0), (field.clone_el(&x), 1), (field.one(), 2)].into_iter()),
+        ring.from_terms([(field.int_hom().map(3), 0), (field.one(), 5)].into_iter())
+    ].into_iter());
+    let factorization = factor_over_finite_field(&ring, ring.clone_el(&f));
+    assert_is_factorization(&ring, &f, &factorization);
+
+    let field = GaloisField::new(2, 4);
+    let ring = DensePolyRing::new(&field, "X");
+    let f = ring.sub(ring.pow(ring.indeterminate(), 16), ring.indeterminate());
+    let factorization = factor_over_finite_field(&ring, ring.clone_el(&f));
+    assert_is_factorization(&ring, &f, &factorization);
+    assert_eq!(16, factorization.len());
+}
+
+#[test]
+fn test_factor_over_integers() {
+    let ring = DensePolyRing::new(StaticRing::<i64>::RING, "X");
+    // `-12 (X^2 - 2) (X^3 + X + 1)^2 (3 X + 5)`
+    let f = ring.prod([
+        ring.from_terms([(-12, 0)].into_iter()),
+        ring.from_terms([(-2, 0), (1, 2)].into_iter()),
+        ring.from_terms([(1, 0), (1, 1), (1, 3)].into_iter()),
+        ring.from_terms([(1, 0), (1, 1), (1, 3)].into_iter()),
+        ring.from_terms([(5, 0), (3, 1)].into_iter())
+    ].into_iter());
+    let factorization = factor_over_integers(&ring, ring.clone_el(&f));
+    assert_el_eq!(&ring, &f, &ring.prod(factorization.iter().map(|(g, e)| ring.pow(ring.clone_el(g), *e))));
+    assert_eq!(4, factorization.len());
+    assert!(factorization.iter().any(|(g, e)| ring.eq_el(g, &ring.from_terms([(1, 0), (1, 1), (1, 3)].into_iter())) && *e == 2));
+    assert!(factorization.iter().any(|(g, e)| ring.eq_el(g, &ring.from_terms([(-12, 0)].into_iter())) && *e == 1));
+
+    let f = ring.from_terms([(1, 0), (1, 4)].into_iter());
+    assert_eq!(1, factor_over_integers(&ring, f).len());
+}
+
+#[test]
+fn test_factor_over_rationals() {
+    let ZZ = StaticRing::<i64>::RING;
+    let QQ = RationalField::new(ZZ);
+    let ring = DensePolyRing::new(QQ, "X");
+    let q = |num: i64, den: i64| QQ.from_fraction(num, den);
+    // `(2/3 X - 1/2) (X^2 - 1/4) = 2/3 (X - 3/4) (X - 1/2) (X + 1/2)`
+    let f = ring.mul(
+        ring.from_terms([(q(-1, 2), 0), (q(2, 3), 1)].into_iter()),
+        ring.from_terms([(q(-1, 4), 0), (q(1, 1), 2)].into_iter())
+    );
+    let factorization = factor_over_rationals(&ring, ring.clone_el(&f));
+    assert_el_eq!(&ring, &f, &ring.prod(factorization.iter().map(|(g, e)| ring.pow(ring.clone_el(g), *e))));
+    assert_eq!(4, factorization.len());
+    assert!(factorization.iter().any(|(g, _)| ring.eq_el(g, &ring.from_terms([(q(-3, 4), 0), (q(1, 1), 1)].into_iter()))));
+    assert!(factorization.iter().any(|(g, _)| ring.eq_el(g, &ring.from_terms([(q(2, 3), 0)].into_iter()))));
+}
@@ -0,0 +1,523 @@
+use crate::algorithms;
+use crate::divisibility::{DivisibilityRing, DivisibilityRingStore};
+use crate::field::Field;
+use crate::integer::{int_cast, BigIntRing, IntegerRing, IntegerRingStore};
+use crate::pid::{EuclideanRing, PrincipalIdealRing};
+use crate::primitive_int::StaticRing;
+use crate::ring::*;
+use crate::homomorphism::*;
+use crate::rings::extension::galois_field::GaloisFieldBase;
+use crate::rings::extension::gf2n::GF2nBase;
+use crate::rings::extension::FreeAlgebra;
+use crate::rings::field::AsFieldBase;
+use crate::rings::finite::{FiniteRing, FiniteRingStore};
+use crate::rings::poly::{PolyRing, PolyRingStore};
+use crate::rings::poly::dense_poly::DensePolyRing;
+use crate::rings::rational::RationalFieldBase;
+use crate::rings::zn::{zn_42, zn_64, zn_barett, zn_static};
+
+///
+/// Helper trait that gives rings of prime characteristic `p` the ability to compute `p`-th roots,
+/// which is required to compute square-free decompositions of polynomials over them, see
+/// [`squarefree_decomposition()`].
+///
+/// By default, this is implemented to return `None`, and it is specialized for prime fields (i.e.
+/// [`zn_static::Fp`] and implementations of `Z/pZ` wrapped in [`AsFieldBase`]), for [`GaloisFieldBase`]
+/// and for [`GF2nBase`]. For other finite fields, use [`squarefree_decomposition_finite_field()`].
+///
+pub trait PthRootHint: RingBase {
+
+    ///
+    /// Returns `y` with `y^p = x`, where `p` is the characteristic of the ring, or `None` if such a
+    /// root does not exist or cannot be computed.
+    ///
+    fn pth_root(&self, x: &Self::Element) -> Option<Self::Element>;
+}
+
+impl<R: RingBase + ?Sized> PthRootHint for R {
+
+    default fn pth_root(&self, _x: &Self::Element) -> Option<Self::Element> {
+        None
+    }
+}
+
+// on a prime field, the Frobenius is the identity; note that we cannot do this for `Z/nZ` in general,
+// since it is only correct if `n` is prime
+
+impl<const P: u64> PthRootHint for zn_static::ZnBase<P, true> {
+
+    fn pth_root(&self, x: &Self::Element) -> Option<Self::Element> {
+        Some(self.clone_el(x))
+    }
+}
+
+impl PthRootHint for AsFieldBase<zn_64::Zn> {
+
+    fn pth_root(&self, x: &Self::Element) -> Option<Self::Element> {
+        Some(self.clone_el(x))
+    }
+}
+
+impl PthRootHint for AsFieldBase<zn_42::Zn> {
+
+    fn pth_root(&self, x: &Self::Element) -> Option<Self::Element> {
+        Some(self.clone_el(x))
+    }
+}
+
+impl<I: IntegerRingStore> PthRootHint for AsFieldBase<zn_barett::Zn<I>>
+    where I::Type: IntegerRing
+{
+    fn pth_root(&self, x: &Self::Element) -> Option<Self::Element> {
+        Some(self.clone_el(x))
+    }
+}
+
+impl PthRootHint for GaloisFieldBase {
+
+    fn pth_root(&self, x: &Self::Element) -> Option<Self::Element> {
+        // the Frobenius has order `k` on `GF(p^k)`, so its inverse is its `(k - 1)`-th power
+        Some(self.frobenius(x, self.rank() - 1))
+    }
+}
+
+impl PthRootHint for GF2nBase {
+
+    fn pth_root(&self, x: &Self::Element) -> Option<Self::Element> {
+        let mut result = self.clone_el(x);
+        for _ in 1..self.rank() {
+            self.square(&mut result);
+        }
+        return Some(result);
+    }
+}
+
+///
+/// Helper trait that gives fields the ability to compute greatest common divisors of polynomials over them
+/// using the euclidean algorithm, which is then used by [`squarefree_decomposition()`].
+///
+/// By default, this is implemented to use the subresultant algorithm, which works over every principal ideal
+/// domain, and it is specialized to use [`poly_gcd_over_field()`] for the fields for which [`PthRootHint`] is
+/// specialized, and for [`RationalFieldBase`].
+///
+pub trait PolyGCDHint: RingBase {
+
+    ///
+    /// Computes a greatest common divisor of the polynomials `f` and `g` over this ring, as primitive polynomial.
+    ///
+    fn poly_gcd<P>(&self, poly_ring: P, f: &El<P>, g: &El<P>) -> El<P>
+        where P: PolyRingStore,
+            P::Type: PolyRing,
+            <P::Type as RingExtension>::BaseRing: RingStore<Type = Self>,
+            Self: PrincipalIdealRing;
+}
+
+impl<R: RingBase + ?Sized> PolyGCDHint for R {
+
+    default fn poly_gcd<P>(&self, poly_ring: P, f: &El<P>, g: &El<P>) -> El<P>
+        where P: PolyRingStore,
+            P::Type: PolyRing,
+            <P::Type as RingExtension>::BaseRing: RingStore<Type = Self>,
+            Self: PrincipalIdealRing
+    {
+        let prs = algorithms::resultant::subresultant_prs(&poly_ring, f, g);
+        match prs.into_iter().last() {
+            Some(d) => poly_ring.primitive_part(d),
+            None => poly_ring.zero()
+        }
+    }
+}
+
+macro_rules! impl_poly_gcd_hint_over_field {
+    () => {
+        fn poly_gcd<P>(&self, poly_ring: P, f: &El<P>, g: &El<P>) -> El<P>
+            where P: PolyRingStore,
+                P::Type: PolyRing,
+                <P::Type as RingExtension>::BaseRing: RingStore<Type = Self>
+        {
+            poly_gcd_over_field(poly_ring, f, g)
+        }
+    };
+}
+
+impl<const N: u64> PolyGCDHint for zn_static::ZnBase<N, true> {
+    impl_poly_gcd_hint_over_field!{}
+}
+
+impl<R: DivisibilityRingStore> PolyGCDHint for AsFieldBase<R>
+    where R::Type: PrincipalIdealRing
+{
+    impl_poly_gcd_hint_over_field!{}
+}
+
+impl PolyGCDHint for GaloisFieldBase {
+    impl_poly_gcd_hint_over_field!{}
+}
+
+impl PolyGCDHint for GF2nBase {
+    impl_poly_gcd_hint_over_field!{}
+}
+
+impl<I: IntegerRingStore> PolyGCDHint for RationalFieldBase<I>
+    where I::Type: IntegerRing
+{
+    impl_poly_gcd_hint_over_field!{}
+}
+
+///
+/// Computes the monic greatest common divisor of the polynomials `f` and `g` over a field, or zero if both
+/// are zero.
+///
+/// In contrast to the subresultant algorithm, this uses [`algorithms::eea::gcd()`] over a [`DensePolyRing`],
+/// so for large degrees, the half-gcd algorithm is used (see [`algorithms::eea::EEAHint`]).
+///
+pub fn poly_gcd_over_field<P>(poly_ring: P, f: &El<P>, g: &El<P>) -> El<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: Field
+{
+    // `poly_ring` is not necessarily euclidean, so compute the gcd in a dense polynomial ring
+    let base_ring = poly_ring.base_ring();
+    let dense_poly_ring = DensePolyRing::new(base_ring, "X");
+    let to_dense = |f: &El<P>| dense_poly_ring.from_terms(poly_ring.terms(f).map(|(c, i)| (base_ring.clone_el(c), i)));
+    let d = algorithms::eea::gcd(to_dense(f), to_dense(g), &dense_poly_ring);
+    return poly_ring.from_terms(dense_poly_ring.terms(&make_monic(&dense_poly_ring, d)).map(|(c, i)| (base_ring.clone_el(c), i)));
+}
+
+///
+/// Divides `f` by its leading coefficient, if `f` is nonzero.
+///
+fn make_monic<P>(poly_ring: P, f: El<P>) -> El<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: Field
+{
+    match poly_ring.lc(&f) {
+        Some(lc) => {
+            let lc_inv = poly_ring.base_ring().invert(lc).unwrap();
+            poly_ring.inclusion().mul_map(f, lc_inv)
+        },
+        None => f
+    }
+}
+
+///
+/// Assuming that `f` is a `p`-th power, where `p` is the characteristic of the base ring, computes its `p`-th root
+/// and returns it together with `p`. The `p`-th roots of the coefficients are computed by `pth_root`.
+///
+fn poly_pth_root<P, F>(poly_ring: P, f: &El<P>, pth_root: &F) -> (El<P>, usize)
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        F: Fn(&El<<P::Type as RingExtension>::BaseRing>, usize) -> Option<El<<P::Type as RingExtension>::BaseRing>>
+{
+    let base_ring = poly_ring.base_ring();
+    // if `f = g^p = g(X^p)`, then all exponents are divisible by the characteristic `p`, and `p` is the smallest
+    // integer `>= 2` with `p = 0` in the base ring
+    let exponent_gcd = poly_ring.terms(f).fold(0, |current, (_, i)| algorithms::eea::gcd(current as i64, i as i64, StaticRing::<i64>::RING) as usize);
+    let p = (2..=exponent_gcd).filter(|d| exponent_gcd % d == 0)
+        .filter(|d| base_ring.is_zero(&base_ring.int_hom().map(*d as i32)))
+        .next()
+        .expect("polynomial is not a p-th power, where p is the characteristic of the base ring");
+    let result = poly_ring.from_terms(poly_ring.terms(f).map(|(c, i)| (
+        pth_root(c, p).unwrap_or_else(|| panic!("computing square-free decompositions in characteristic {} requires {}-th roots in the base ring", p, p)),
+        i / p
+    )));
+    return (result, p);
+}
+
+fn squarefree_decomposition_rec<P, G, F>(poly_ring: P, f: El<P>, multiplicity: usize, gcd: &G, pth_root: &F, result: &mut Vec<(El<P>, usize)>)
+    where P: PolyRingStore + Copy,
+        P::Type: PolyRing + DivisibilityRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: PrincipalIdealRing,
+        G: Fn(&El<P>, &El<P>) -> El<P>,
+        F: Fn(&El<<P::Type as RingExtension>::BaseRing>, usize) -> Option<El<<P::Type as RingExtension>::BaseRing>>
+{
+    // `c` is the product of all `g^(i - 1)` resp. `g^i` for all factors `g` of multiplicity `i` not divisible resp. divisible by the
+    // characteristic; `w` is the product of all factors of multiplicity not divisible by the characteristic
+    let mut c = gcd(&f, &poly_ring.derivative(&f));
+    let mut w = poly_ring.checked_div(&f, &c).unwrap();
+    let mut i = 1;
+    while poly_ring.degree(&w).unwrap() > 0 {
+        let y = gcd(&w, &c);
+        let factor = poly_ring.checked_div(&w, &y).unwrap();
+        if poly_ring.degree(&factor).unwrap() > 0 {
+            result.push((factor, i * multiplicity));
+        }
+        c = poly_ring.checked_div(&c, &y).unwrap();
+        w = y;
+        i += 1;
+    }
+    if poly_ring.degree(&c).unwrap() > 0 {
+        let (root, p) = poly_pth_root(poly_ring, &c, pth_root);
+        squarefree_decomposition_rec(poly_ring, root, multiplicity * p, gcd, pth_root, result);
+    }
+}
+
+///
+/// Computes the square-free decomposition of `f`, using `gcd` to compute greatest common divisors of polynomials
+/// as primitive polynomials, and `pth_root` to compute `p`-th roots of elements of the base ring; the result is
+/// normalized as described in [`squarefree_decomposition()`].
+///
+fn squarefree_decomposition_with<P, G, F>(poly_ring: P, f: &El<P>, gcd: G, pth_root: F) -> Vec<(El<P>, usize)>
+    where P: PolyRingStore,
+        P::Type: PolyRing + DivisibilityRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: PrincipalIdealRing,
+        G: Fn(&El<P>, &El<P>) -> El<P>,
+        F: Fn(&El<<P::Type as RingExtension>::BaseRing>, usize) -> Option<El<<P::Type as RingExtension>::BaseRing>>
+{
+    assert!(!poly_ring.is_zero(f));
+    let mut factors = Vec::new();
+    if poly_ring.degree(f).unwrap() > 0 {
+        squarefree_decomposition_rec(&poly_ring, poly_ring.primitive_part(poly_ring.clone_el(f)), 1, &gcd, &pth_root, &mut factors);
+    }
+    factors.sort_by_key(|(_, e)| *e);
+    let mut result: Vec<(El<P>, usize)> = Vec::new();
+    for (factor, e) in factors {
+        match result.last_mut() {
+            Some((last, last_e)) if *last_e == e => poly_ring.mul_assign(last, factor),
+            _ => result.push((factor, e))
+        }
+    }
+    let base_ring = poly_ring.base_ring();
+    for (factor, _) in &mut result {
+        if let Some(lc_inv) = base_ring.invert(poly_ring.lc(factor).unwrap()) {
+            poly_ring.inclusion().mul_assign_map_ref(factor, &lc_inv);
+        }
+    }
+    return result;
+}
+
+///
+/// Computes the square-free decomposition of a nonzero polynomial `f`, i.e. a list of pairwise coprime square-free
+/// polynomials `f_i` with multiplicities `e_i`, such that `f = c prod_i f_i^e_i` for a constant `c`. The multiplicities
+/// are distinct and in ascending order, and each `f_i` is the product of all irreducible factors of `f` of multiplicity
+/// exactly `e_i`.
+///
+/// The base ring must be a unique factorization domain whose elements have greatest common divisors computable via
+/// [`PrincipalIdealRing`], e.g. the integers or a field. The factors `f_i` are primitive, and monic if their leading
+/// coefficient is a unit. If the base ring has characteristic `p`, computing the decomposition may require `p`-th
+/// roots of elements, which are provided by [`PthRootHint`].
+///
+/// The algorithm is the variant of Yun's algorithm that also works in positive characteristic, as described e.g. in
+/// Section 8.3 of Geddes, Czapor and Labahn, "Algorithms for Computer Algebra".
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::rings::poly::*;
+/// # use feanor_math::rings::poly::dense_poly::*;
+/// # use feanor_math::primitive_int::*;
+/// # use feanor_math::assert_el_eq;
+/// let P = DensePolyRing::new(StaticRing::<i64>::RING, "X");
+/// let f = P.from_terms([(1, 0), (1, 1)].into_iter());
+/// let g = P.from_terms([(-2, 0), (1, 2)].into_iter());
+/// let decomposition = P.squarefree_decomposition(&P.mul(P.pow(P.clone_el(&f), 3), P.clone_el(&g)));
+/// assert_eq!(2, decomposition.len());
+/// assert_el_eq!(&P, &g, &decomposition[0].0);
+/// assert_eq!(1, decomposition[0].1);
+/// assert_el_eq!(&P, &f, &decomposition[1].0);
+/// assert_eq!(3, decomposition[1].1);
+/// ```
+///
+pub fn squarefree_decomposition<P>(poly_ring: P, f: &El<P>) -> Vec<(El<P>, usize)>
+    where P: PolyRingStore,
+        P::Type: PolyRing + DivisibilityRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: PrincipalIdealRing
+{
+    let base_ring = poly_ring.base_ring().get_ring();
+    squarefree_decomposition_with(&poly_ring, f, |f, g| base_ring.poly_gcd(&poly_ring, f, g), |c, _| base_ring.pth_root(c))
+}
+
+///
+/// Computes the square-free decomposition of a nonzero polynomial `f` over a finite field, normalized as described
+/// in [`squarefree_decomposition()`].
+///
+/// In contrast to [`squarefree_decomposition()`], this works for every finite field, since `p`-th roots in a field
+/// with `q` elements can always be computed as `x^(q/p)`. Greatest common divisors are computed by [`algorithms::eea::gcd()`]
+/// in `poly_ring`, so the half-gcd algorithm is used for large inputs if `poly_ring` supports it.
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::rings::poly::*;
+/// # use feanor_math::rings::poly::dense_poly::*;
+/// # use feanor_math::rings::zn::*;
+/// # use feanor_math::rings::zn::zn_64::*;
+/// # use feanor_math::assert_el_eq;
+/// # use feanor_math::algorithms::poly_squarefree::*;
+/// let Fp = Zn::new(3).as_field().ok().unwrap();
+/// let P = DensePolyRing::new(Fp, "X");
+/// // `X^3 + 1 = (X + 1)^3` in characteristic 3
+/// let f = P.add(P.pow(P.indeterminate(), 3), P.one());
+/// let decomposition = squarefree_decomposition_finite_field(&P, &f);
+/// assert_eq!(1, decomposition.len());
+/// assert_el_eq!(&P, &P.add(P.indeterminate(), P.one()), &decomposition[0].0);
+/// assert_eq!(3, decomposition[0].1);
+/// ```
+///
+pub fn squarefree_decomposition_finite_field<P>(poly_ring: P, f: &El<P>) -> Vec<(El<P>, usize)>
+    where P: PolyRingStore,
+        P::Type: PolyRing + EuclideanRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: FiniteRing + Field
+{
+    let ZZ = BigIntRing::RING;
+    let base_ring = poly_ring.base_ring();
+    let q = base_ring.size(&ZZ);
+    let gcd = |f: &El<P>, g: &El<P>| make_monic(&poly_ring, algorithms::eea::gcd(poly_ring.clone_el(f), poly_ring.clone_el(g), &poly_ring));
+    squarefree_decomposition_with(&poly_ring, f, gcd, |c, p| {
+        let root_exp = ZZ.checked_div(&q, &int_cast(p as i64, &ZZ, &StaticRing::<i64>::RING)).unwrap();
+        Some(base_ring.pow_gen(base_ring.clone_el(c), &root_exp, &ZZ))
+    })
+}
+
+///
+/// Computes the square-free part of a nonzero polynomial `f`, i.e. the product of all its irreducible factors,
+/// each with multiplicity one. It is normalized as the factors returned by [`squarefree_decomposition()`].
+///
+pub fn squarefree_part<P>(poly_ring: P, f: &El<P>) -> El<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing + DivisibilityRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: PrincipalIdealRing
+{
+    let decomposition = squarefree_decomposition(&poly_ring, f);
+    poly_ring.prod(decomposition.into_iter().map(|(factor, _)| factor))
+}
+
+#[cfg(test)]
+use crate::rings::rational::RationalField;
+#[cfg(test)]
+use crate::rings::extension::galois_field::GaloisField;
+#[cfg(test)]
+use crate::rings::extension::gf2n::GF2n;
+#[cfg(test)]
+use crate::rings::poly::gf2_poly::GF2PolyRing;
+#[cfg(test)]
+use crate::rings::zn::ZnRingStore;
+#[cfg(test)]
+use crate::field::FieldStore;
+#[cfg(test)]
+use crate::rings::extension::FreeAlgebraStore;
+
+#[cfg(test)]
+fn assert_decomposition_eq<P>(poly_ring: P, expected: &[(El<P>, usize)], actual: &[(El<P>, usize)])
+    where P: PolyRingStore,
+        P::Type: PolyRing + DivisibilityRing
+{
+    assert_eq!(expected.len(), actual.len());
+    for ((e, e_mult), (a, a_mult)) in expected.iter().zip(actual.iter()) {
+        assert_eq!(e_mult, a_mult);
+        let quo = poly_ring.checked_div(e, a);
+        assert!(quo.is_some() && poly_ring.is_unit(&quo.unwrap()), "expected factor {}, got {}", poly_ring.format(e), poly_ring.format(a));
+    }
+}
+
+#[test]
+fn test_squarefree_decomposition_integers() {
+    let ZZ = BigIntRing::RING;
+    let ZZX = DensePolyRing::new(ZZ, "X");
+    let poly = |coeffs: &[i32]| ZZX.from_terms(coeffs.iter().enumerate().map(|(i, c)| (ZZ.int_hom().map(*c), i)));
+    let (f1, f2, f3) = (poly(&[-3, 2]), poly(&[2, 0, 1]), poly(&[1, 1]));
+    let f = ZZX.prod([ZZX.int_hom().map(6), ZZX.clone_el(&f1), ZZX.pow(ZZX.clone_el(&f2), 2), ZZX.pow(ZZX.clone_el(&f3), 4)].into_iter());
+    let expected = [(ZZX.clone_el(&f1), 1), (ZZX.clone_el(&f2), 2), (ZZX.clone_el(&f3), 4)];
+    assert_decomposition_eq(&ZZX, &expected, &ZZX.squarefree_decomposition(&f));
+    assert_decomposition_eq(&ZZX, &[(ZZX.prod([f1, f2, f3].into_iter()), 1)], &[(ZZX.squarefree_part(&f), 1)]);
+
+    assert_eq!(0, ZZX.squarefree_decomposition(&ZZX.int_hom().map(4)).len());
+    assert_el_eq!(&ZZX, &ZZX.one(), &ZZX.squarefree_part(&ZZX.int_hom().map(4)));
+}
+
+#[test]
+fn test_squarefree_decomposition_rationals() {
+    let QQ = RationalField::new(BigIntRing::RING);
+    let QQX = DensePolyRing::new(&QQ, "X");
+    let half = QQ.div(&QQ.one(), &QQ.int_hom().map(2));
+    let f1 = QQX.from_terms([(QQ.clone_el(&half), 0), (QQ.one(), 1)].into_iter());
+    let f2 = QQX.from_terms([(QQ.one(), 0), (QQ.one(), 3)].into_iter());
+    let f = QQX.prod([QQX.inclusion().map(half), QQX.pow(QQX.clone_el(&f1), 3), QQX.pow(QQX.clone_el(&f2), 5)].into_iter());
+    let actual = QQX.squarefree_decomposition(&f);
+    assert_decomposition_eq(&QQX, &[(QQX.clone_el(&f1), 3), (QQX.clone_el(&f2), 5)], &actual);
+    // factors over fields are monic
+    assert_el_eq!(&QQX, &f1, &actual[0].0);
+    assert_el_eq!(&QQX, &f2, &actual[1].0);
+}
+
+#[test]
+fn test_squarefree_decomposition_prime_field() {
+    let Fp = zn_64::Zn::new(7).as_field().ok().unwrap();
+    let FpX = DensePolyRing::new(Fp, "X");
+    let poly = |coeffs: &[i32]| FpX.from_terms(coeffs.iter().enumerate().map(|(i, c)| (Fp.int_hom().map(*c), i)));
+    let (f1, f2, f3, f4) = (poly(&[1, 1]), poly(&[1, 0, 1]), poly(&[-2, 1]), poly(&[3, 1, 0, 1]));
+    // multiplicities divisible by `p` require `p`-th roots
+    let f = FpX.prod([FpX.clone_el(&f1), FpX.pow(FpX.clone_el(&f2), 7), FpX.pow(FpX.clone_el(&f3), 8), FpX.pow(FpX.clone_el(&f4), 14)].into_iter());
+    let expected = [(f1, 1), (f2, 7), (f3, 8), (f4, 14)];
+    assert_decomposition_eq(&FpX, &expected, &FpX.squarefree_decomposition(&f));
+
+    let FpX = DensePolyRing::new(zn_static::Fp::<3>::RING, "X");
+    let f = FpX.from_terms([(1, 0), (1, 3)].into_iter());
+    let expected = [(FpX.from_terms([(1, 0), (1, 1)].into_iter()), 3)];
+    assert_decomposition_eq(&FpX, &expected, &FpX.squarefree_decomposition(&f));
+    let f = FpX.from_terms([(1, 0), (1, 9)].into_iter());
+    let expected = [(FpX.from_terms([(1, 0), (1, 1)].into_iter()), 9)];
+    assert_decomposition_eq(&FpX, &expected, &FpX.squarefree_decomposition(&f));
+
+    let F2X = GF2PolyRing::new("X");
+    let f = F2X.from_terms([(1, 0), (1, 2)].into_iter());
+    let expected = [(F2X.from_terms([(1, 0), (1, 1)].into_iter()), 2)];
+    assert_decomposition_eq(&F2X, &expected, &F2X.squarefree_decomposition(&f));
+}
+
+#[test]
+fn test_squarefree_decomposition_large_prime_field() {
+    // the degrees are large enough that the gcds are computed using the half-gcd algorithm
+    let Fp = zn_64::Zn::new(65537).as_field().ok().unwrap();
+    let FpX = DensePolyRing::new(Fp, "X");
+    let g = FpX.from_terms([(Fp.int_hom().map(-3), 0), (Fp.one(), 600)].into_iter());
+    let h = FpX.from_terms([(Fp.one(), 0), (Fp.int_hom().map(5), 1), (Fp.one(), 1000)].into_iter());
+    let f = FpX.prod([FpX.clone_el(&g), FpX.clone_el(&g), FpX.clone_el(&h)].into_iter());
+    let expected = [(h, 1), (g, 2)];
+
+    let half_gcd_calls = algorithms::half_gcd::half_gcd_eea_calls();
+    assert_decomposition_eq(&FpX, &expected, &FpX.squarefree_decomposition(&f));
+    assert!(algorithms::half_gcd::half_gcd_eea_calls() > half_gcd_calls);
+
+    let half_gcd_calls = algorithms::half_gcd::half_gcd_eea_calls();
+    assert_decomposition_eq(&FpX, &expected, &squarefree_decomposition_finite_field(&FpX, &f));
+    assert!(algorithms::half_gcd::half_gcd_eea_calls() > half_gcd_calls);
+}
+
+#[test]
+fn test_squarefree_decomposition_galois_field() {
+    let F = GaloisField::new(2, 3);
+    let FX = DensePolyRing::new(&F, "X");
+    let a = F.canonical_gen();
+    let f1 = FX.from_terms([(F.clone_el(&a), 0), (F.one(), 1)].into_iter());
+    let f2 = FX.from_terms([(F.pow(F.clone_el(&a), 3), 0), (F.one(), 1)].into_iter());
+    let f = FX.prod([FX.pow(FX.clone_el(&f1), 2), FX.pow(FX.clone_el(&f2), 5)].into_iter());
+    let expected = [(f1, 2), (f2, 5)];
+    assert_decomposition_eq(&FX, &expected, &FX.squarefree_decomposition(&f));
+}
+
+#[test]
+fn test_squarefree_decomposition_gf2n() {
+    let F = GF2n::new(4);
+    let FX = DensePolyRing::new(&F, "X");
+    let a = F.canonical_gen();
+    let f1 = FX.from_terms([(F.clone_el(&a), 0), (F.one(), 1)].into_iter());
+    let f2 = FX.from_terms([(F.one(), 0), (F.one(), 1), (F.one(), 2)].into_iter());
+    let f = FX.prod([FX.clone_el(&f1), FX.pow(FX.clone_el(&f2), 6)].into_iter());
+    let expected = [(f1, 1), (f2, 6)];
+    assert_decomposition_eq(&FX, &expected, &FX.squarefree_decomposition(&f));
+}
+
+#[test]
+fn test_squarefree_decomposition_finite_field() {
+    let F = GaloisField::new(3, 2);
+    let FX = DensePolyRing::new(&F, "X");
+    let a = F.canonical_gen();
+    let f1 = FX.from_terms([(F.clone_el(&a), 0), (F.one(), 1)].into_iter());
+    let f2 = FX.from_terms([(F.one(), 0), (F.clone_el(&a), 1), (F.one(), 2)].into_iter());
+    let f = FX.prod([FX.pow(FX.clone_el(&f1), 3), FX.pow(FX.clone_el(&f2), 4)].into_iter());
+    let expected = [(f1, 3), (f2, 4)];
+    assert_decomposition_eq(&FX, &expected, &squarefree_decomposition_finite_field(&FX, &f));
+    assert_decomposition_eq(&FX, &expected, &FX.squarefree_decomposition(&f));
+}
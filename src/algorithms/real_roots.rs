@@ -0,0 +1,327 @@
+use std::cmp::Ordering;
+
+use crate::field::FieldStore;
+use crate::integer::*;
+use crate::ordered::OrderedRingStore;
+use crate::ring::*;
+use crate::homomorphism::*;
+use crate::rings::poly::{PolyRing, PolyRingStore};
+use crate::rings::poly::dense_poly::DensePolyRing;
+use crate::rings::rational::RationalField;
+
+///
+/// Returns the square-free part of the given nonzero polynomial with integer coefficients, as primitive
+/// polynomial with positive leading coefficient, stored as vector of its coefficients.
+///
+fn squarefree_part<P>(ZZX: P, f: &El<P>) -> Vec<El<BigIntRing>>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: IntegerRing
+{
+    assert!(!ZZX.is_zero(f));
+    let ZZ = BigIntRing::RING;
+    let ZZX_big = DensePolyRing::new(ZZ, "X");
+    let f = ZZX_big.from_terms(ZZX.terms(f).map(|(c, i)| (int_cast(ZZX.base_ring().clone_el(c), &ZZ, ZZX.base_ring()), i)));
+    let squarefree = ZZX_big.squarefree_part(&f);
+    let d = ZZX_big.degree(&squarefree).unwrap();
+    let sign = if ZZ.is_neg(ZZX_big.lc(&squarefree).unwrap()) { -1 } else { 1 };
+    return (0..=d).map(|i| ZZ.int_hom().mul_ref_fst_map(ZZX_big.coefficient_at(&squarefree, i), sign)).collect();
+}
+
+///
+/// Replaces `f(X)` by `f(X + 1)`.
+///
+fn taylor_shift_one(f: &mut [El<BigIntRing>]) {
+    let ZZ = BigIntRing::RING;
+    let n = f.len();
+    for i in 0..n {
+        for j in (i..(n - 1)).rev() {
+            let summand = ZZ.clone_el(&f[j + 1]);
+            ZZ.add_assign(&mut f[j], summand);
+        }
+    }
+}
+
+fn sign_variations<'a, I>(coefficients: I) -> usize
+    where I: Iterator<Item = &'a El<BigIntRing>>
+{
+    let ZZ = BigIntRing::RING;
+    let mut result = 0;
+    let mut last_sign = None;
+    for c in coefficients.filter(|c| !ZZ.is_zero(c)) {
+        let sign = ZZ.is_pos(c);
+        if last_sign.is_some() && last_sign != Some(sign) {
+            result += 1;
+        }
+        last_sign = Some(sign);
+    }
+    return result;
+}
+
+///
+/// Returns an upper bound on the number of roots of `f` in the open interval `(0, 1)`, which is exact
+/// if it is `0` or `1`. This is Descartes' rule of signs applied to `(X + 1)^n f(1/(X + 1))`.
+///
+fn descartes_bound(f: &[El<BigIntRing>]) -> usize {
+    let ZZ = BigIntRing::RING;
+    let mut transformed = f.iter().rev().map(|c| ZZ.clone_el(c)).collect::<Vec<_>>();
+    taylor_shift_one(&mut transformed);
+    return sign_variations(transformed.iter());
+}
+
+///
+/// Isolates the roots of the square-free polynomial `f` in the open interval `(0, 1)`. The roots are
+/// returned as triples `(c, depth, exact)`, describing the interval `(c/2^depth, (c + 1)/2^depth)` resp.
+/// the exact root `c/2^depth` if `exact` is set.
+///
+/// The flags `lower_is_root` and `upper_is_root` indicate whether `0` resp. `1` are roots of the original
+/// polynomial. In this case, we continue bisecting until the isolating interval does not contain this
+/// endpoint anymore, which is guaranteed to happen after finitely many steps.
+///
+fn isolate_roots_unit_interval(f: Vec<El<BigIntRing>>, c: El<BigIntRing>, depth: usize, lower_is_root: bool, upper_is_root: bool, out: &mut Vec<(El<BigIntRing>, usize, bool)>) {
+    let ZZ = BigIntRing::RING;
+    let variations = descartes_bound(&f);
+    if variations == 0 {
+        return;
+    } else if variations == 1 && !lower_is_root && !upper_is_root {
+        out.push((c, depth, false));
+        return;
+    }
+    let n = f.len() - 1;
+    // the left half is given by `2^n f(X/2)` and the right half by `2^n f((X + 1)/2)`
+    let left = f.into_iter().enumerate().map(|(i, mut a)| {
+        ZZ.mul_pow_2(&mut a, n - i);
+        a
+    }).collect::<Vec<_>>();
+    let mut right = left.iter().map(|a| ZZ.clone_el(a)).collect::<Vec<_>>();
+    taylor_shift_one(&mut right);
+    let left_c = ZZ.int_hom().mul_map(c, 2);
+    let right_c = ZZ.add_ref_fst(&left_c, ZZ.one());
+    let midpoint_is_root = ZZ.is_zero(&right[0]);
+    isolate_roots_unit_interval(left, left_c, depth + 1, lower_is_root, midpoint_is_root, out);
+    if midpoint_is_root {
+        out.push((ZZ.clone_el(&right_c), depth + 1, true));
+        right.remove(0);
+    }
+    isolate_roots_unit_interval(right, right_c, depth + 1, midpoint_is_root, upper_is_root, out);
+}
+
+///
+/// Isolates the positive roots of the square-free polynomial `f`, with `f(0) != 0`. If `zero_is_root`
+/// is set, the returned isolating intervals are chosen to not contain `0` as endpoint.
+///
+fn isolate_positive_roots(f: &[El<BigIntRing>], zero_is_root: bool) -> Vec<(El<RationalField<BigIntRing>>, El<RationalField<BigIntRing>>)> {
+    let ZZ = BigIntRing::RING;
+    let QQ = RationalField::new(ZZ);
+    let n = f.len() - 1;
+    // by Cauchy's bound, all roots have absolute value `< 1 + max |f_i / f_n| <= 2^log2_bound`
+    let lc = ZZ.abs(ZZ.clone_el(&f[n]));
+    let max = f[..n].iter().map(|c| ZZ.abs(ZZ.clone_el(c))).max_by(|a, b| ZZ.cmp(a, b)).unwrap_or(ZZ.zero());
+    let bound = ZZ.add_ref_fst(&lc, max);
+    let mut log2_bound = 0;
+    while ZZ.is_leq(&ZZ.mul_ref_fst(&lc, ZZ.power_of_two(log2_bound)), &bound) {
+        log2_bound += 1;
+    }
+    let scaled = f.iter().enumerate().map(|(i, c)| {
+        let mut result = ZZ.clone_el(c);
+        ZZ.mul_pow_2(&mut result, i * log2_bound);
+        result
+    }).collect::<Vec<_>>();
+    let mut roots = Vec::new();
+    isolate_roots_unit_interval(scaled, ZZ.zero(), 0, zero_is_root, false, &mut roots);
+    return roots.into_iter().map(|(c, depth, exact)| {
+        let to_rational = |c: El<BigIntRing>| QQ.from_fraction(ZZ.mul(c, ZZ.power_of_two(log2_bound)), ZZ.power_of_two(depth));
+        if exact {
+            (to_rational(ZZ.clone_el(&c)), to_rational(c))
+        } else {
+            (to_rational(ZZ.clone_el(&c)), to_rational(ZZ.add(c, ZZ.one())))
+        }
+    }).collect();
+}
+
+///
+/// Returns the sign of `f(x)` for a rational number `x`.
+///
+fn sign_at(f: &[El<BigIntRing>], x: &El<RationalField<BigIntRing>>) -> Ordering {
+    let ZZ = BigIntRing::RING;
+    let QQ = RationalField::new(ZZ);
+    let (num, den) = (QQ.num(x), QQ.den(x));
+    // `den^n f(num/den)` has the same sign as `f(num/den)`, since `den > 0`
+    let mut result = ZZ.zero();
+    let mut den_power = ZZ.one();
+    for c in f.iter().rev() {
+        ZZ.mul_assign_ref(&mut result, num);
+        ZZ.add_assign(&mut result, ZZ.mul_ref(c, &den_power));
+        ZZ.mul_assign_ref(&mut den_power, den);
+    }
+    return ZZ.cmp(&result, &ZZ.zero());
+}
+
+///
+/// Computes isolating intervals for the real roots of the nonzero polynomial `f` with integer coefficients.
+///
+/// The result contains one pair `(a, b)` of rational numbers for each distinct real root of `f`, sorted in
+/// increasing order. If `a = b`, the corresponding root is exactly `a`. Otherwise, `a < b` and `f` has exactly
+/// one root in the open interval `(a, b)`, and none at `a` or `b`. The intervals can then be refined using
+/// [`refine_real_root()`].
+///
+/// This uses the Vincent-Collins-Akritas algorithm, i.e. Descartes' rule of signs together with bisection.
+/// All computations are done with arbitrary-precision integers, independent of the integer ring of `f`.
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::field::*;
+/// # use feanor_math::homomorphism::*;
+/// # use feanor_math::ordered::*;
+/// # use feanor_math::integer::*;
+/// # use feanor_math::rings::poly::*;
+/// # use feanor_math::rings::poly::dense_poly::*;
+/// # use feanor_math::rings::rational::*;
+/// # use feanor_math::algorithms::real_roots::*;
+/// let ZZ = BigIntRing::RING;
+/// let ZZX = DensePolyRing::new(ZZ, "X");
+/// let QQ = RationalField::new(ZZ);
+/// // `X^3 - 2X` has the roots `-sqrt(2), 0, sqrt(2)`
+/// let f = ZZX.from_terms([(ZZ.int_hom().map(-2), 1), (ZZ.one(), 3)].into_iter());
+/// let roots = isolate_real_roots(&ZZX, &f);
+/// assert_eq!(3, roots.len());
+/// assert!(QQ.is_zero(&roots[1].0) && QQ.is_zero(&roots[1].1));
+/// let (a, b) = refine_real_root(&ZZX, &f, &roots[2], 20);
+/// assert!(QQ.is_lt(&QQ.mul_ref(&a, &a), &QQ.int_hom().map(2)) && QQ.is_gt(&QQ.mul_ref(&b, &b), &QQ.int_hom().map(2)));
+/// assert!(QQ.is_leq(&QQ.sub(b, a), &QQ.div(&QQ.one(), &QQ.int_hom().map(1 << 20))));
+/// ```
+///
+pub fn isolate_real_roots<P>(ZZX: P, f: &El<P>) -> Vec<(El<RationalField<BigIntRing>>, El<RationalField<BigIntRing>>)>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: IntegerRing
+{
+    let ZZ = BigIntRing::RING;
+    let QQ = RationalField::new(ZZ);
+    let mut f = squarefree_part(&ZZX, f);
+    let mut has_zero_root = false;
+    if ZZ.is_zero(&f[0]) {
+        has_zero_root = true;
+        f.remove(0);
+    }
+    if f.len() == 1 {
+        return if has_zero_root { vec![(QQ.zero(), QQ.zero())] } else { Vec::new() };
+    }
+    let reflected = f.iter().enumerate().map(|(i, c)| if i % 2 == 0 { ZZ.clone_el(c) } else { ZZ.negate(ZZ.clone_el(c)) }).collect::<Vec<_>>();
+    let mut result = isolate_positive_roots(&reflected, has_zero_root).into_iter().rev().map(|(a, b)| (QQ.negate(b), QQ.negate(a))).collect::<Vec<_>>();
+    if has_zero_root {
+        result.push((QQ.zero(), QQ.zero()));
+    }
+    result.extend(isolate_positive_roots(&f, has_zero_root));
+    return result;
+}
+
+///
+/// Refines an isolating interval of a real root of the nonzero polynomial `f` with integer coefficients,
+/// as returned by [`isolate_real_roots()`], until its length is at most `2^-precision`.
+///
+/// As for [`isolate_real_roots()`], the result is either an exact root `(a, a)`, or an interval `(a, b)`
+/// with `a < b` such that `f` has exactly one root in `(a, b)` and none at `a` or `b`. The refinement
+/// is done using bisection.
+///
+pub fn refine_real_root<P>(ZZX: P, f: &El<P>, interval: &(El<RationalField<BigIntRing>>, El<RationalField<BigIntRing>>), precision: usize) -> (El<RationalField<BigIntRing>>, El<RationalField<BigIntRing>>)
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: IntegerRing
+{
+    let ZZ = BigIntRing::RING;
+    let QQ = RationalField::new(ZZ);
+    let f = squarefree_part(&ZZX, f);
+    let (mut lower, mut upper) = (QQ.clone_el(&interval.0), QQ.clone_el(&interval.1));
+    if QQ.eq_el(&lower, &upper) {
+        return (lower, upper);
+    }
+    let lower_sign = sign_at(&f, &lower);
+    assert!(lower_sign != Ordering::Equal && sign_at(&f, &upper) == lower_sign.reverse(), "not an isolating interval");
+    let max_length = QQ.from_fraction(ZZ.one(), ZZ.power_of_two(precision));
+    let two_inv = QQ.div(&QQ.one(), &QQ.int_hom().map(2));
+    while QQ.is_gt(&QQ.sub_ref(&upper, &lower), &max_length) {
+        let midpoint = QQ.mul(QQ.add_ref(&lower, &upper), QQ.clone_el(&two_inv));
+        let midpoint_sign = sign_at(&f, &midpoint);
+        if midpoint_sign == Ordering::Equal {
+            return (QQ.clone_el(&midpoint), midpoint);
+        } else if midpoint_sign == lower_sign {
+            lower = midpoint;
+        } else {
+            upper = midpoint;
+        }
+    }
+    return (lower, upper);
+}
+
+#[cfg(test)]
+use crate::primitive_int::StaticRing;
+
+#[cfg(test)]
+fn assert_isolating<P>(ZZX: P, f: &El<P>, roots: &[(El<RationalField<BigIntRing>>, El<RationalField<BigIntRing>>)], expected: &[f64])
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: IntegerRing
+{
+    let ZZ = BigIntRing::RING;
+    let QQ = RationalField::new(ZZ);
+    let to_f64 = |x: &El<RationalField<BigIntRing>>| ZZ.to_float_approx(QQ.num(x)) / ZZ.to_float_approx(QQ.den(x));
+    assert_eq!(expected.len(), roots.len());
+    for i in 0..roots.len() {
+        assert!(QQ.is_leq(&roots[i].0, &roots[i].1));
+        if i + 1 < roots.len() {
+            assert!(QQ.is_leq(&roots[i].1, &roots[i + 1].0));
+        }
+        let refined = refine_real_root(&ZZX, f, &roots[i], 60);
+        assert!(QQ.is_leq(&roots[i].0, &refined.0) && QQ.is_leq(&refined.1, &roots[i].1));
+        assert!((to_f64(&refined.0) - expected[i]).abs() < 1e-12 * expected[i].abs().max(1.));
+        assert!((to_f64(&refined.1) - expected[i]).abs() < 1e-12 * expected[i].abs().max(1.));
+    }
+}
+
+#[test]
+fn test_isolate_real_roots() {
+    let ZZ = StaticRing::<i64>::RING;
+    let ZZX = DensePolyRing::new(ZZ, "X");
+    let QQ = RationalField::new(BigIntRing::RING);
+
+    // `(X^2 - 2)(2X - 1)(X + 3)^2 X`
+    let f = ZZX.prod([
+        ZZX.from_terms([(-2, 0), (1, 2)].into_iter()),
+        ZZX.from_terms([(-1, 0), (2, 1)].into_iter()),
+        ZZX.pow(ZZX.from_terms([(3, 0), (1, 1)].into_iter()), 2),
+        ZZX.indeterminate()
+    ].into_iter());
+    let roots = isolate_real_roots(&ZZX, &f);
+    assert_isolating(&ZZX, &f, &roots, &[-3., -2f64.sqrt(), 0., 0.5, 2f64.sqrt()]);
+    assert!(QQ.is_zero(&roots[2].0) && QQ.is_zero(&roots[2].1));
+
+    // `(X - 1)(X - 2)...(X - 12)`
+    let f = ZZX.prod((1..=12).map(|i| ZZX.from_terms([(-i, 0), (1, 1)].into_iter())));
+    assert_isolating(&ZZX, &f, &isolate_real_roots(&ZZX, &f), &(1..=12).map(|i| i as f64).collect::<Vec<_>>());
+
+    // close roots
+    let f = ZZX.from_terms([(1, 0), (-2001, 1), (1001000, 2)].into_iter());
+    assert_isolating(&ZZX, &f, &isolate_real_roots(&ZZX, &f), &[1. / 1001., 1. / 1000.]);
+
+    // no real roots
+    assert_eq!(0, isolate_real_roots(&ZZX, &ZZX.from_terms([(1, 0), (1, 2)].into_iter())).len());
+    assert_eq!(0, isolate_real_roots(&ZZX, &ZZX.int_hom().map(5)).len());
+    assert_eq!(1, isolate_real_roots(&ZZX, &ZZX.pow(ZZX.indeterminate(), 3)).len());
+}
+
+#[test]
+fn test_refine_real_root() {
+    let ZZ = BigIntRing::RING;
+    let ZZX = DensePolyRing::new(ZZ, "X");
+    let QQ = RationalField::new(ZZ);
+    // `X^5 - X - 1` has a single real root that is not expressible by radicals
+    let f = ZZX.from_terms([(ZZ.neg_one(), 0), (ZZ.neg_one(), 1), (ZZ.one(), 5)].into_iter());
+    let roots = isolate_real_roots(&ZZX, &f);
+    assert_eq!(1, roots.len());
+    let (a, b) = refine_real_root(&ZZX, &f, &roots[0], 200);
+    assert!(QQ.is_leq(&QQ.sub_ref(&b, &a), &QQ.from_fraction(ZZ.one(), ZZ.power_of_two(200))));
+    assert_eq!(Ordering::Less, sign_at(&squarefree_part(&ZZX, &f), &a));
+    assert_eq!(Ordering::Greater, sign_at(&squarefree_part(&ZZX, &f), &b));
+}
@@ -0,0 +1,494 @@
+use crate::algorithms;
+use crate::divisibility::{DivisibilityRing, DivisibilityRingStore};
+use crate::field::Field;
+use crate::integer::*;
+use crate::ordered::OrderedRingStore;
+use crate::primitive_int::StaticRing;
+use crate::ring::*;
+use crate::homomorphism::*;
+use crate::rings::poly::{PolyRing, PolyRingStore};
+use crate::rings::poly::dense_poly::DensePolyRing;
+use crate::rings::zn::{ZnRingStore, zn_64};
+
+///
+/// Helper trait that gives rings the ability to provide a specialized algorithm for computing
+/// resultants of polynomials over them, which is then used by [`resultant()`].
+///
+/// This is default implemented for all rings to use the subresultant algorithm, and specialized
+/// for arbitrary-precision integers to use a modular algorithm (see [`resultant_over_integers()`]),
+/// and for the fields [`crate::rings::field::AsFieldBase`], [`crate::rings::zn::zn_static::Fp`],
+/// [`crate::rings::rational::RationalField`], [`crate::rings::extension::galois_field::GaloisField`] and
+/// [`crate::rings::extension::gf2n::GF2n`] to use the euclidean algorithm (see [`resultant_over_field()`]).
+/// For other fields, [`resultant_over_field()`] can be called directly.
+///
+pub trait ResultantHint: RingBase {
+
+    fn compute_resultant<P>(&self, poly_ring: P, f: &El<P>, g: &El<P>) -> Self::Element
+        where P: PolyRingStore,
+            P::Type: PolyRing,
+            <P::Type as RingExtension>::BaseRing: RingStore<Type = Self>,
+            Self: DivisibilityRing;
+}
+
+impl<R: RingBase + ?Sized> ResultantHint for R {
+
+    default fn compute_resultant<P>(&self, poly_ring: P, f: &El<P>, g: &El<P>) -> Self::Element
+        where P: PolyRingStore,
+            P::Type: PolyRing,
+            <P::Type as RingExtension>::BaseRing: RingStore<Type = Self>,
+            Self: DivisibilityRing
+    {
+        subresultant_resultant(poly_ring, f, g)
+    }
+}
+
+fn is_odd(n: usize) -> bool {
+    n % 2 == 1
+}
+
+///
+/// Computes the pseudo-remainder of `lhs` by `rhs`, i.e. the remainder of the division of
+/// `lc(rhs)^(deg(lhs) - deg(rhs) + 1) * lhs` by `rhs`. This requires `deg(lhs) >= deg(rhs)`.
+///
+fn pseudo_rem<P>(poly_ring: P, lhs: &El<P>, rhs: &El<P>) -> El<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: DivisibilityRing
+{
+    let base_ring = poly_ring.base_ring();
+    let lc = poly_ring.lc(rhs).unwrap();
+    let scale = base_ring.pow(base_ring.clone_el(lc), poly_ring.degree(lhs).unwrap() - poly_ring.degree(rhs).unwrap() + 1);
+    let scaled_lhs = poly_ring.inclusion().mul_ref_map(lhs, &scale);
+    // all divisions are exact, since the quotient of `scaled_lhs` by `rhs` has coefficients in the base ring
+    let (_, rem) = algorithms::poly_div::sparse_poly_div(
+        scaled_lhs,
+        rhs,
+        &poly_ring,
+        &poly_ring,
+        |x| base_ring.checked_div(x, lc).ok_or(()),
+        &base_ring.identity()
+    ).unwrap();
+    return rem;
+}
+
+///
+/// Divides all coefficients of `f` by `c`, assuming the division is exact.
+///
+fn div_exact<P>(poly_ring: P, f: El<P>, c: &El<<P::Type as RingExtension>::BaseRing>) -> El<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: DivisibilityRing
+{
+    let base_ring = poly_ring.base_ring();
+    poly_ring.from_terms(poly_ring.terms(&f).map(|(a, i)| (base_ring.checked_div(a, c).unwrap(), i)))
+}
+
+///
+/// Computes the subresultant pseudo-remainder sequence of `f` and `g`, over an integral domain `R`.
+///
+/// This is the sequence `r0 = f, r1 = g, r2, ..., rk` where `r(i + 1)` is the pseudo-remainder of `r(i - 1)` by `ri`,
+/// divided by a factor that is chosen such that the sequence consists exactly of the subresultants of `f` and `g`
+/// (up to sign). In particular, all these polynomials have coefficients in `R`, and their size grows only linearly.
+/// The last element `rk` is a greatest common divisor of `f` and `g` over the fraction field of `R`. If `deg(f) < deg(g)`,
+/// the sequence starts with `g`.
+///
+/// The algorithm follows Algorithm 3.3.1 from Cohen's "A Course in Computational Algebraic Number Theory", without
+/// removing contents.
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::rings::poly::*;
+/// # use feanor_math::rings::poly::dense_poly::*;
+/// # use feanor_math::primitive_int::*;
+/// # use feanor_math::algorithms::resultant::*;
+/// let ZZ = StaticRing::<i64>::RING;
+/// let P = DensePolyRing::new(ZZ, "X");
+/// let common_factor = P.from_terms([(1, 0), (2, 1)].into_iter());
+/// let f = P.mul_ref(&common_factor, &P.from_terms([(-1, 0), (3, 3)].into_iter()));
+/// let g = P.mul_ref(&common_factor, &P.from_terms([(5, 0), (1, 1), (1, 2)].into_iter()));
+/// let prs = subresultant_prs(&P, &f, &g);
+/// assert_eq!(Some(1), P.degree(prs.last().unwrap()));
+/// ```
+///
+pub fn subresultant_prs<P>(poly_ring: P, f: &El<P>, g: &El<P>) -> Vec<El<P>>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: DivisibilityRing
+{
+    let base_ring = poly_ring.base_ring();
+    let (f, g) = if poly_ring.degree(f) < poly_ring.degree(g) { (g, f) } else { (f, g) };
+    if poly_ring.is_zero(g) {
+        return if poly_ring.is_zero(f) { Vec::new() } else { vec![poly_ring.clone_el(f)] };
+    }
+    let mut result = vec![poly_ring.clone_el(f), poly_ring.clone_el(g)];
+    let mut scale_g = base_ring.one();
+    let mut scale_h = base_ring.one();
+    loop {
+        let a = &result[result.len() - 2];
+        let b = &result[result.len() - 1];
+        if poly_ring.degree(b).unwrap() == 0 {
+            return result;
+        }
+        let delta = poly_ring.degree(a).unwrap() - poly_ring.degree(b).unwrap();
+        let r = pseudo_rem(&poly_ring, a, b);
+        if poly_ring.is_zero(&r) {
+            return result;
+        }
+        let r = div_exact(&poly_ring, r, &base_ring.mul_ref_fst(&scale_g, base_ring.pow(base_ring.clone_el(&scale_h), delta)));
+        scale_g = base_ring.clone_el(poly_ring.lc(b).unwrap());
+        scale_h = if delta == 0 {
+            scale_h
+        } else {
+            base_ring.checked_div(&base_ring.pow(base_ring.clone_el(&scale_g), delta), &base_ring.pow(scale_h, delta - 1)).unwrap()
+        };
+        result.push(r);
+    }
+}
+
+///
+/// Computes the resultant of `f` and `g` using the subresultant algorithm, see [`subresultant_prs()`].
+///
+fn subresultant_resultant<P>(poly_ring: P, f: &El<P>, g: &El<P>) -> El<<P::Type as RingExtension>::BaseRing>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: DivisibilityRing
+{
+    let base_ring = poly_ring.base_ring();
+    if poly_ring.is_zero(f) || poly_ring.is_zero(g) {
+        return base_ring.zero();
+    }
+    let mut negate = false;
+    let (mut a, mut b) = if poly_ring.degree(f) < poly_ring.degree(g) {
+        negate = is_odd(poly_ring.degree(f).unwrap()) && is_odd(poly_ring.degree(g).unwrap());
+        (poly_ring.clone_el(g), poly_ring.clone_el(f))
+    } else {
+        (poly_ring.clone_el(f), poly_ring.clone_el(g))
+    };
+    let mut scale_g = base_ring.one();
+    let mut scale_h = base_ring.one();
+    // invariant: `res(f, g) = +/- scale * res(a, b)`, where `scale` is determined by `scale_h`, see Cohen, Algorithm 3.3.7
+    while poly_ring.degree(&b).unwrap() > 0 {
+        let delta = poly_ring.degree(&a).unwrap() - poly_ring.degree(&b).unwrap();
+        if is_odd(poly_ring.degree(&a).unwrap()) && is_odd(poly_ring.degree(&b).unwrap()) {
+            negate = !negate;
+        }
+        let r = pseudo_rem(&poly_ring, &a, &b);
+        if poly_ring.is_zero(&r) {
+            return base_ring.zero();
+        }
+        a = std::mem::replace(&mut b, div_exact(&poly_ring, r, &base_ring.mul_ref_fst(&scale_g, base_ring.pow(base_ring.clone_el(&scale_h), delta))));
+        scale_g = base_ring.clone_el(poly_ring.lc(&a).unwrap());
+        scale_h = if delta == 0 {
+            scale_h
+        } else {
+            base_ring.checked_div(&base_ring.pow(base_ring.clone_el(&scale_g), delta), &base_ring.pow(scale_h, delta - 1)).unwrap()
+        };
+    }
+    let deg_a = poly_ring.degree(&a).unwrap();
+    if deg_a == 0 {
+        // both inputs are constants
+        return base_ring.one();
+    }
+    let result = base_ring.checked_div(&base_ring.pow(base_ring.clone_el(poly_ring.lc(&b).unwrap()), deg_a), &base_ring.pow(scale_h, deg_a - 1)).unwrap();
+    return if negate { base_ring.negate(result) } else { result };
+}
+
+///
+/// Computes the resultant of `f` and `g` over an integral domain `R`, i.e. the determinant of their Sylvester matrix.
+/// In particular, the resultant is zero if and only if `f` and `g` have a nontrivial common factor.
+///
+/// By default, this uses the subresultant algorithm, which avoids computations in the fraction field of `R`,
+/// but rings can provide more efficient algorithms via [`ResultantHint`]. In particular, a modular algorithm
+/// is used for arbitrary-precision integers, and the euclidean algorithm is used for the fields listed there.
+///
+/// By convention, the resultant of the zero polynomial with any other polynomial is zero, and the resultant
+/// of two nonzero constants is one.
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::rings::poly::*;
+/// # use feanor_math::rings::poly::dense_poly::*;
+/// # use feanor_math::primitive_int::*;
+/// # use feanor_math::algorithms::resultant::*;
+/// let ZZ = StaticRing::<i64>::RING;
+/// let P = DensePolyRing::new(ZZ, "X");
+/// // the resultant of `X^2 + 1` and `X - a` is `a^2 + 1`
+/// let f = P.from_terms([(1, 0), (1, 2)].into_iter());
+/// let g = P.from_terms([(-3, 0), (1, 1)].into_iter());
+/// assert_eq!(10, resultant(&P, &f, &g));
+/// ```
+///
+pub fn resultant<P>(poly_ring: P, f: &El<P>, g: &El<P>) -> El<<P::Type as RingExtension>::BaseRing>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: DivisibilityRing
+{
+    poly_ring.base_ring().get_ring().compute_resultant(&poly_ring, f, g)
+}
+
+///
+/// Computes the resultant of `f` and `g` over a field, using the euclidean algorithm.
+///
+/// This is the algorithm used by [`resultant()`] for the fields listed in [`ResultantHint`], but it can
+/// also be called directly for other fields.
+///
+pub fn resultant_over_field<P>(poly_ring: P, f: &El<P>, g: &El<P>) -> El<<P::Type as RingExtension>::BaseRing>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: Field
+{
+    let base_ring = poly_ring.base_ring();
+    if poly_ring.is_zero(f) || poly_ring.is_zero(g) {
+        return base_ring.zero();
+    }
+    let mut result = base_ring.one();
+    let (mut a, mut b) = (poly_ring.clone_el(f), poly_ring.clone_el(g));
+    // invariant: `res(f, g) = result * res(a, b)`
+    while poly_ring.degree(&b).unwrap() > 0 {
+        let (deg_a, deg_b) = (poly_ring.degree(&a).unwrap(), poly_ring.degree(&b).unwrap());
+        let lc_b = base_ring.clone_el(poly_ring.lc(&b).unwrap());
+        let monic_b = poly_ring.inclusion().mul_ref_map(&b, &base_ring.invert(&lc_b).unwrap());
+        let (_, r) = poly_ring.div_rem_monic(a, &monic_b);
+        if poly_ring.is_zero(&r) {
+            return base_ring.zero();
+        }
+        // we have `res(a, b) = (-1)^(deg(a) deg(b)) lc(b)^(deg(a) - deg(r)) res(b, r)`
+        base_ring.mul_assign(&mut result, base_ring.pow(lc_b, deg_a - poly_ring.degree(&r).unwrap()));
+        if is_odd(deg_a) && is_odd(deg_b) {
+            base_ring.negate_inplace(&mut result);
+        }
+        a = std::mem::replace(&mut b, r);
+    }
+    base_ring.mul_assign(&mut result, base_ring.pow(base_ring.clone_el(poly_ring.lc(&b).unwrap()), poly_ring.degree(&a).unwrap()));
+    return result;
+}
+
+///
+/// Computes the resultant of `f` and `g` over the integers, by computing it modulo sufficiently many primes
+/// (using [`resultant_over_field()`]) and combining the results using the Chinese remainder theorem.
+///
+/// This is the algorithm used by [`resultant()`] for arbitrary-precision integers, but it can also be
+/// called directly for other integer rings.
+///
+pub fn resultant_over_integers<P>(ZZX: P, f: &El<P>, g: &El<P>) -> El<<P::Type as RingExtension>::BaseRing>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: IntegerRing
+{
+    let ZZ = ZZX.base_ring();
+    if ZZX.is_zero(f) || ZZX.is_zero(g) {
+        return ZZ.zero();
+    }
+    let ZZbig = BigIntRing::RING;
+    let (deg_f, deg_g) = (ZZX.degree(f).unwrap(), ZZX.degree(g).unwrap());
+    // Hadamard's bound gives `|res(f, g)| <= |f|^deg(g) |g|^deg(f)`, where `|.|` is the 2-norm of the coefficient vector
+    let log2_norm = |h: &El<P>, deg: usize| ZZX.terms(h).map(|(c, _)| ZZ.abs_highest_set_bit(c).unwrap() + 1).max().unwrap() as f64 + ((deg + 1) as f64).log2() / 2.;
+    let log2_bound = deg_g as f64 * log2_norm(f, deg_f) + deg_f as f64 * log2_norm(g, deg_g);
+    let required_bits = log2_bound.ceil() as usize + 2;
+
+    let lc_f = int_cast(ZZ.clone_el(ZZX.lc(f).unwrap()), &ZZbig, ZZ);
+    let lc_g = int_cast(ZZ.clone_el(ZZX.lc(g).unwrap()), &ZZbig, ZZ);
+    let mut current = ZZbig.zero();
+    let mut modulus = ZZbig.one();
+    let mut p: i64 = 1 << 57;
+    while ZZbig.abs_highest_set_bit(&modulus).unwrap_or(0) < required_bits {
+        p -= 1;
+        if !algorithms::miller_rabin::is_prime(StaticRing::<i64>::RING, &p, 10) {
+            continue;
+        }
+        let p_big = int_cast(p, &ZZbig, &StaticRing::<i64>::RING);
+        // primes dividing a leading coefficient have to be skipped, as the degree drops modulo them
+        if ZZbig.checked_div(&lc_f, &p_big).is_some() || ZZbig.checked_div(&lc_g, &p_big).is_some() {
+            continue;
+        }
+        let Fp = zn_64::Zn::new(p as u64).as_field().ok().unwrap();
+        let FpX = DensePolyRing::new(Fp, "X");
+        let reduce = |h: &El<P>| FpX.from_terms(ZZX.terms(h).map(|(c, i)| (Fp.coerce(&ZZbig, int_cast(ZZ.clone_el(c), &ZZbig, ZZ)), i)));
+        let result_mod_p = resultant_over_field(&FpX, &reduce(f), &reduce(g));
+
+        // update `current` such that it is congruent to `result_mod_p` modulo `p`
+        let correction = Fp.mul(
+            Fp.sub(result_mod_p, Fp.coerce(&ZZbig, ZZbig.clone_el(&current))),
+            Fp.invert(&Fp.coerce(&ZZbig, ZZbig.clone_el(&modulus))).unwrap()
+        );
+        let correction = int_cast(Fp.smallest_positive_lift(correction), &ZZbig, Fp.integer_ring());
+        ZZbig.add_assign(&mut current, ZZbig.mul_ref_snd(correction, &modulus));
+        ZZbig.mul_assign(&mut modulus, p_big);
+    }
+    // take the representative of smallest absolute value
+    if ZZbig.is_gt(&ZZbig.mul(ZZbig.clone_el(&current), ZZbig.int_hom().map(2)), &modulus) {
+        ZZbig.sub_assign(&mut current, modulus);
+    }
+    return int_cast(current, ZZ, &ZZbig);
+}
+
+///
+/// Computes the discriminant of `f` over an integral domain `R`, i.e. `(-1)^(n(n - 1)/2) res(f, f') / lc(f)`
+/// where `n = deg(f)`. In particular, the discriminant is zero if and only if `f` is not squarefree.
+///
+/// The resultant is computed using [`resultant()`]. Note that in the case of positive characteristic, the
+/// discriminant can also vanish if the derivative of `f` has lower degree than expected.
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::rings::poly::*;
+/// # use feanor_math::rings::poly::dense_poly::*;
+/// # use feanor_math::primitive_int::*;
+/// # use feanor_math::algorithms::resultant::*;
+/// let ZZ = StaticRing::<i64>::RING;
+/// let P = DensePolyRing::new(ZZ, "X");
+/// // the discriminant of `aX^2 + bX + c` is `b^2 - 4ac`
+/// let f = P.from_terms([(1, 0), (5, 1), (2, 2)].into_iter());
+/// assert_eq!(17, discriminant(&P, &f));
+/// ```
+///
+pub fn discriminant<P>(poly_ring: P, f: &El<P>) -> El<<P::Type as RingExtension>::BaseRing>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <<P::Type as RingExtension>::BaseRing as RingStore>::Type: DivisibilityRing
+{
+    let base_ring = poly_ring.base_ring();
+    let n = poly_ring.degree(f).unwrap();
+    if n == 0 {
+        return base_ring.one();
+    }
+    let derivative = poly_ring.derivative(f);
+    // if the characteristic divides `n`, the derivative has smaller degree, so `res(f, f')` cannot be computed
+    // as resultant of `f` and `f'` interpreted as polynomials of lower degree
+    let res = if poly_ring.degree(&derivative) == Some(n - 1) {
+        resultant(&poly_ring, f, &derivative)
+    } else if poly_ring.is_zero(&derivative) {
+        base_ring.zero()
+    } else {
+        // the Sylvester matrix of `f` and `f'` (considered as a polynomial of degree `n - 1`) has an upper left triangular
+        // block with diagonal `lc(f)`; hence we get an additional factor of `lc(f)^(n - 1 - deg(f'))`, up to sign
+        let deg_derivative = poly_ring.degree(&derivative).unwrap();
+        let sign_correction = if is_odd(n) && is_odd(n - 1 - deg_derivative) { base_ring.negate(base_ring.one()) } else { base_ring.one() };
+        base_ring.mul(
+            sign_correction,
+            base_ring.mul(base_ring.pow(base_ring.clone_el(poly_ring.lc(f).unwrap()), n - 1 - deg_derivative), resultant(&poly_ring, f, &derivative))
+        )
+    };
+    let result = base_ring.checked_div(&res, poly_ring.lc(f).unwrap()).unwrap();
+    return if is_odd(n * (n - 1) / 2) { base_ring.negate(result) } else { result };
+}
+
+#[cfg(test)]
+use crate::rings::rational::RationalField;
+#[cfg(test)]
+use crate::rings::zn::zn_static::Fp;
+#[cfg(test)]
+use crate::rings::extension::galois_field::GaloisField;
+
+#[test]
+fn test_resultant() {
+    let ZZ = StaticRing::<i64>::RING;
+    let ZZX = DensePolyRing::new(ZZ, "X");
+    // `f = 2(X - 1)(X - 2)(X + 3)` and `g = X^2 + 1`, hence `res(f, g) = 2^2 g(1) g(2) g(-3) = 400`
+    let f = ZZX.from_terms([(12, 0), (-14, 1), (0, 2), (2, 3)].into_iter());
+    let g = ZZX.from_terms([(1, 0), (1, 2)].into_iter());
+    assert_eq!(400, resultant(&ZZX, &f, &g));
+    assert_eq!(400, resultant(&ZZX, &g, &f));
+    assert_eq!(400, resultant_over_integers(&ZZX, &f, &g));
+
+    // `res(g, f) = (-1)^(deg(f) deg(g)) res(f, g)`
+    let h = ZZX.from_terms([(3, 0), (-1, 1), (1, 3)].into_iter());
+    let res_fh = resultant(&ZZX, &f, &h);
+    assert!(res_fh != 0);
+    assert_eq!(-res_fh, resultant(&ZZX, &h, &f));
+    assert_eq!(res_fh, resultant_over_integers(&ZZX, &f, &h));
+    assert_eq!(-res_fh, resultant_over_integers(&ZZX, &h, &f));
+
+    // common factor
+    assert_eq!(0, resultant(&ZZX, &ZZX.mul_ref(&f, &g), &ZZX.mul_ref(&g, &h)));
+    assert_eq!(0, resultant_over_integers(&ZZX, &ZZX.mul_ref(&f, &g), &ZZX.mul_ref(&g, &h)));
+
+    // constants
+    assert_eq!(1, resultant(&ZZX, &ZZX.int_hom().map(3), &ZZX.int_hom().map(5)));
+    assert_eq!(27, resultant(&ZZX, &f, &ZZX.int_hom().map(3)));
+    assert_eq!(0, resultant(&ZZX, &f, &ZZX.zero()));
+
+    let QQ = RationalField::new(ZZ);
+    let QQX = DensePolyRing::new(&QQ, "X");
+    let to_QQX = |p: &El<DensePolyRing<StaticRing<i64>>>| QQX.from_terms(ZZX.terms(p).map(|(c, i)| (QQ.from_fraction(*c, 1), i)));
+    assert!(QQ.eq_el(&QQ.from_fraction(res_fh, 1), &resultant_over_field(&QQX, &to_QQX(&f), &to_QQX(&h))));
+    assert!(QQ.eq_el(&QQ.from_fraction(res_fh, 1), &resultant(&QQX, &to_QQX(&f), &to_QQX(&h))));
+
+    let F7 = Fp::<7>::RING;
+    let F7X = DensePolyRing::new(F7, "X");
+    let to_F7X = |p: &El<DensePolyRing<StaticRing<i64>>>| F7X.from_terms(ZZX.terms(p).map(|(c, i)| (F7.int_hom().map(*c as i32), i)));
+    assert_el_eq!(&F7, &F7.int_hom().map(res_fh as i32), &resultant_over_field(&F7X, &to_F7X(&f), &to_F7X(&h)));
+    assert_el_eq!(&F7, &F7.int_hom().map(res_fh as i32), &resultant(&F7X, &to_F7X(&f), &to_F7X(&h)));
+
+    let F9 = GaloisField::new(3, 2);
+    let F9X = DensePolyRing::new(&F9, "X");
+    let to_F9X = |p: &El<DensePolyRing<StaticRing<i64>>>| F9X.from_terms(ZZX.terms(p).map(|(c, i)| (F9.int_hom().map(c.rem_euclid(3) as i32), i)));
+    assert_el_eq!(&F9, &F9.int_hom().map(res_fh.rem_euclid(3) as i32), &resultant(&F9X, &to_F9X(&f), &to_F9X(&h)));
+
+    let Fp = zn_64::Zn::new(65537).as_field().ok().unwrap();
+    let FpX = DensePolyRing::new(&Fp, "X");
+    let to_FpX = |p: &El<DensePolyRing<StaticRing<i64>>>| FpX.from_terms(ZZX.terms(p).map(|(c, i)| (Fp.int_hom().map(*c as i32), i)));
+    assert_el_eq!(&Fp, &Fp.int_hom().map(res_fh as i32), &resultant(&FpX, &to_FpX(&f), &to_FpX(&h)));
+}
+
+#[test]
+fn test_resultant_large() {
+    let ZZ = BigIntRing::RING;
+    let ZZX = DensePolyRing::new(ZZ, "X");
+    let f = ZZX.from_terms((0..=20).map(|i| (ZZ.int_hom().map((i * i * 37 + 11) % 1000 - 500), i as usize)));
+    let g = ZZX.from_terms((0..=15).map(|i| (ZZ.int_hom().map((i * 71 + 5) % 1000 - 500), i as usize)));
+    let expected = subresultant_resultant(&ZZX, &f, &g);
+    assert!(ZZ.abs_highest_set_bit(&expected).unwrap() > 128);
+    assert_el_eq!(&ZZ, &expected, &resultant(&ZZX, &f, &g));
+    assert_el_eq!(&ZZ, &expected, &resultant_over_integers(&ZZX, &f, &g));
+}
+
+#[test]
+fn test_subresultant_prs() {
+    let ZZ = StaticRing::<i64>::RING;
+    let ZZX = DensePolyRing::new(ZZ, "X");
+    // example from Knuth, "The Art of Computer Programming", Vol. 2, Section 4.6.1
+    let f = ZZX.from_terms([(-5, 0), (2, 1), (8, 2), (-3, 3), (-3, 4), (1, 6), (1, 8)].into_iter());
+    let g = ZZX.from_terms([(21, 0), (-9, 1), (-4, 2), (5, 4), (3, 6)].into_iter());
+    let prs = subresultant_prs(&ZZX, &f, &g);
+    let expected_degrees = [8, 6, 4, 2, 1, 0];
+    assert_eq!(expected_degrees.len(), prs.len());
+    for (p, d) in prs.iter().zip(expected_degrees.iter()) {
+        assert_eq!(Some(*d), ZZX.degree(p));
+    }
+    assert_eq!(260708, ZZ.abs(*ZZX.coefficient_at(&prs[5], 0)));
+
+    let common_factor = ZZX.from_terms([(1, 0), (2, 1), (1, 3)].into_iter());
+    let prs = subresultant_prs(&ZZX, &ZZX.mul_ref(&f, &common_factor), &ZZX.mul_ref(&g, &common_factor));
+    let gcd = prs.last().unwrap();
+    assert_eq!(Some(3), ZZX.degree(gcd));
+    assert!(ZZX.checked_div(gcd, &common_factor).is_some());
+}
+
+#[test]
+fn test_discriminant() {
+    let ZZ = StaticRing::<i64>::RING;
+    let ZZX = DensePolyRing::new(ZZ, "X");
+    // discriminant of `X^3 + pX + q` is `-4p^3 - 27q^2`
+    let f = ZZX.from_terms([(5, 0), (-2, 1), (1, 3)].into_iter());
+    assert_eq!(-4 * -8 - 27 * 25, discriminant(&ZZX, &f));
+    // discriminant of `aX^2 + bX + c` is `b^2 - 4ac`
+    let f = ZZX.from_terms([(-2, 0), (1, 1), (3, 2)].into_iter());
+    assert_eq!(1 + 24, discriminant(&ZZX, &f));
+    // non-squarefree
+    let f = ZZX.from_terms([(1, 0), (2, 1), (1, 2)].into_iter());
+    assert_eq!(0, discriminant(&ZZX, &ZZX.mul_ref(&f, &ZZX.indeterminate())));
+
+    // `X^3 + 2` over `F3`, the derivative is zero
+    let F3 = Fp::<3>::RING;
+    let F3X = DensePolyRing::new(F3, "X");
+    assert_el_eq!(&F3, &F3.zero(), &discriminant(&F3X, &F3X.from_terms([(2, 0), (1, 3)].into_iter())));
+    // `X^4 + X + 1` over `F2`, the derivative has degree `0`; the discriminant of `X^4 + X + 1` over `Z` is `229`
+    let F2 = Fp::<2>::RING;
+    let F2X = DensePolyRing::new(F2, "X");
+    assert_el_eq!(&F2, &F2.one(), &discriminant(&F2X, &F2X.from_terms([(1, 0), (1, 1), (1, 4)].into_iter())));
+    assert_eq!(229, discriminant(&ZZX, &ZZX.from_terms([(1, 0), (1, 1), (1, 4)].into_iter())));
+}
@@ -1,6 +1,7 @@
 use std::cmp::min;
 
 use crate::divisibility::DivisibilityRingStore;
+use crate::field::{Field, FieldStore};
 use crate::matrix::{TransformTarget, Matrix};
 use crate::ring::*;
 use crate::pid::{PrincipalIdealRing, PrincipalIdealRingStore};
@@ -102,6 +103,83 @@ pub fn solve_right<R>(A: &mut DenseMatrix<R::Type>, mut rhs: DenseMatrix<R::Type
     return Some(R.mul(&rhs, ring));
 }
 
+///
+/// Computes the inverse of the square matrix `A`, or returns `None` if `A` is not invertible.
+/// 
+pub fn invert<R>(A: &mut DenseMatrix<R::Type>, ring: R) -> Option<DenseMatrix<R::Type>>
+    where R: RingStore + Copy,
+        R::Type: PrincipalIdealRing
+{
+    assert_eq!(A.row_count(), A.col_count());
+    solve_right(A, DenseMatrix::identity(A.row_count(), ring), ring)
+}
+
+///
+/// Computes the determinant of the square matrix `A`. During this process, `A` is transformed
+/// into a diagonal matrix with the same determinant.
+/// 
+pub fn determinant<R>(A: &mut DenseMatrix<R::Type>, ring: R) -> El<R>
+    where R: RingStore + Copy,
+        R::Type: PrincipalIdealRing
+{
+    assert_eq!(A.row_count(), A.col_count());
+    // all transformations performed by `pre_smith()` have determinant `1`
+    pre_smith(ring, &mut DiscardTransform, &mut DiscardTransform, A);
+    ring.prod((0..A.row_count()).map(|i| ring.clone_el(A.at(i, i))))
+}
+
+///
+/// Computes a basis of the kernel of `A`, i.e. of the space of all vectors `x` with `Ax = 0`, where 
+/// `A` is a matrix over a field. During this process, `A` is transformed into its reduced row echelon form.
+/// 
+/// Each basis vector `v` is returned together with an index `i` such that `v_i = 1` and `v_j = 0` for the
+/// indices `j` belonging to the other basis vectors.
+/// 
+pub fn kernel<R>(A: &mut DenseMatrix<R::Type>, ring: R) -> Vec<(usize, Vec<El<R>>)>
+    where R: RingStore + Copy,
+        R::Type: Field
+{
+    let mut pivot_cols = Vec::new();
+    for j in 0..A.col_count() {
+        let row = pivot_cols.len();
+        if let Some(pivot) = (row..A.row_count()).filter(|i| !ring.is_zero(A.at(*i, j))).next() {
+            if pivot != row {
+                TransformRows(A).swap_rows(ring.get_ring(), row, pivot);
+            }
+            let inv = ring.div(&ring.one(), A.at(row, j));
+            for k in j..A.col_count() {
+                ring.mul_assign_ref(A.at_mut(row, k), &inv);
+            }
+            for i in 0..A.row_count() {
+                if i != row && !ring.is_zero(A.at(i, j)) {
+                    let factor = ring.clone_el(A.at(i, j));
+                    TransformRows(A).subtract(ring.get_ring(), row, i, &factor);
+                }
+            }
+            pivot_cols.push(j);
+        }
+    }
+    // each non-pivot column gives a kernel vector
+    (0..A.col_count()).filter(|j| !pivot_cols.contains(j)).map(|free_col| {
+        let mut result = (0..A.col_count()).map(|_| ring.zero()).collect::<Vec<_>>();
+        result[free_col] = ring.one();
+        for (row, pivot_col) in pivot_cols.iter().enumerate() {
+            result[*pivot_col] = ring.negate(ring.clone_el(A.at(row, free_col)));
+        }
+        (free_col, result)
+    }).collect()
+}
+
+struct DiscardTransform;
+
+impl<R> TransformTarget<R> for DiscardTransform
+    where R: ?Sized + RingBase
+{
+    fn transform(&mut self, _: &R, _: usize, _: usize, _: &[<R as RingBase>::Element; 4]) {}
+
+    fn subtract(&mut self, _: &R, _: usize, _: usize, _: &<R as RingBase>::Element) {}
+}
+
 pub struct DenseMatrix<R>
     where R: ?Sized + RingBase
 {
@@ -129,6 +207,15 @@ impl<R> DenseMatrix<R>
         return result;
     }
 
+    pub fn from_fn<F>(row_count: usize, col_count: usize, mut f: F) -> Self
+        where F: FnMut(usize, usize) -> R::Element
+    {
+        DenseMatrix {
+            data: (0..row_count).flat_map(|i| (0..col_count).map(move |j| (i, j))).map(|(i, j)| f(i, j)).collect::<Vec<_>>().into_boxed_slice(),
+            col_count
+        }
+    }
+
     pub fn zero<S>(n: usize, m: usize, ring: S) -> Self
         where S: RingStore<Type = R>
     {
@@ -322,4 +409,56 @@ fn test_solve_int() {
     let solution = solve_right(&mut A.clone_matrix(ring), B.clone_matrix(ring), ring).unwrap();
 
     assert_matrix_eq!(&ring, &A.mul(&solution, &ring), &B);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_determinant_invert() {
+    let ring = StaticRing::<i64>::RING;
+    let A = DenseMatrix {
+        data: vec![2, 3, 1,
+                   4, 1, 5,
+                   0, 2, 3].into_boxed_slice(),
+        col_count: 3
+    };
+    assert_eq!(-42, determinant(&mut A.clone_matrix(ring), ring));
+    assert!(invert(&mut A.clone_matrix(ring), ring).is_none());
+
+    let B = DenseMatrix {
+        data: vec![2, 3,
+                   1, 1].into_boxed_slice(),
+        col_count: 2
+    };
+    assert_eq!(-1, determinant(&mut B.clone_matrix(ring), ring));
+    let B_inv = invert(&mut B.clone_matrix(ring), ring).unwrap();
+    assert_matrix_eq!(&ring, &B.mul(&B_inv, &ring), &DenseMatrix::identity(2, ring));
+
+    let ring = zn_static::Fp::<7>::RING;
+    let C = DenseMatrix::from_fn(3, 3, |i, j| ((i * 3 + j) * (i + 1)) as u64 % 7);
+    assert_eq!(0, determinant(&mut C.clone_matrix(ring), ring));
+    assert_eq!(0, determinant(&mut DenseMatrix::zero(2, 2, ring), ring));
+}
+
+#[test]
+fn test_kernel() {
+    let ring = zn_static::Fp::<7>::RING;
+    let A = DenseMatrix {
+        data: vec![1, 2, 3, 4,
+                   2, 4, 6, 1,
+                   3, 6, 3, 5].into_boxed_slice(),
+        col_count: 4
+    };
+    let basis = kernel(&mut A.clone_matrix(ring), ring);
+    assert_eq!(2, basis.len());
+    for (i, v) in &basis {
+        assert_eq!(1, v[*i]);
+        assert!(basis.iter().all(|(j, w)| j == i || v[*j] == 0 && w[*i] == 0));
+        let v = DenseMatrix::from_fn(4, 1, |j, _| v[j]);
+        assert_matrix_eq!(&ring, &A.mul(&v, &ring), &DenseMatrix::zero(3, 1, ring));
+    }
+
+    let basis = kernel(&mut DenseMatrix::zero(2, 3, ring), ring);
+    assert_eq!(3, basis.len());
+    for (i, v) in &basis {
+        assert!((0..3).all(|j| v[j] == if j == *i { 1 } else { 0 }));
+    }
+}
@@ -0,0 +1,208 @@
+use crate::algorithms;
+use crate::field::FieldStore;
+use crate::integer::*;
+use crate::pid::EuclideanRing;
+use crate::ring::*;
+use crate::homomorphism::*;
+use crate::rings::extension::FreeAlgebraStore;
+use crate::rings::extension::number_field::NumberFieldBase;
+use crate::rings::poly::{PolyRing, PolyRingStore};
+use crate::rings::poly::dense_poly::DensePolyRing;
+use crate::rings::rational::RationalFieldBase;
+
+fn make_monic<P, I>(KX: P, mut f: El<P>) -> El<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <P::Type as RingExtension>::BaseRing: RingStore<Type = NumberFieldBase<I>>,
+        I: IntegerRingStore,
+        I::Type: IntegerRing
+{
+    let K = KX.base_ring();
+    let lc_inv = K.div(&K.one(), KX.lc(&f).unwrap());
+    KX.inclusion().mul_assign_map_ref(&mut f, &lc_inv);
+    return f;
+}
+
+///
+/// Computes `g(X + a)`.
+///
+fn shift<P>(poly_ring: P, g: &El<P>, a: &El<<P::Type as RingExtension>::BaseRing>) -> El<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing
+{
+    let shifted_indeterminate = poly_ring.add(poly_ring.indeterminate(), poly_ring.inclusion().map_ref(a));
+    algorithms::poly_compose::compose(&poly_ring, g, &shifted_indeterminate)
+}
+
+///
+/// Computes the norm `N(g) = prod_σ σ(g)` of `g in K[X]` as polynomial in `Q[X]`, where `σ` runs
+/// through the embeddings of `K` into an algebraic closure.
+///
+/// Since `N(g)(a) = N(g(a))` for every rational `a`, we can compute `N(g)` by computing the norms
+/// of `g(0), ..., g(nd)` in `K`, and interpolating.
+///
+fn norm_poly<P, Q, I>(KX: P, g: &El<P>, QQX: Q) -> El<Q>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <P::Type as RingExtension>::BaseRing: RingStore<Type = NumberFieldBase<I>>,
+        Q: PolyRingStore,
+        Q::Type: PolyRing,
+        <Q::Type as RingExtension>::BaseRing: RingStore<Type = RationalFieldBase<I>>,
+        I: IntegerRingStore,
+        I::Type: IntegerRing
+{
+    let K = KX.base_ring();
+    let QQ = QQX.base_ring();
+    let degree = K.rank() * KX.degree(g).unwrap();
+    let points = (0..=degree).map(|a| QQ.int_hom().map(a as i32)).collect::<Vec<_>>();
+    let values = (0..=degree).map(|a| K.get_ring().norm(&KX.evaluate(g, &K.int_hom().map(a as i32), &K.identity()))).collect::<Vec<_>>();
+    return algorithms::interpolate::newton_interpolate(&QQX, &points, &values);
+}
+
+///
+/// Factors a square-free polynomial over an algebraic number field `K` into its monic irreducible
+/// factors, using the norm-based algorithm of Trager.
+///
+/// # Algorithm
+///
+/// Consider `g in K[X]` and let `N(g) = prod_σ σ(g)` be its norm, where `σ` runs through the embeddings
+/// of `K` into an algebraic closure. Then `N(g)` has rational coefficients, and if `N(g)` is square-free,
+/// then each irreducible factor `h` of `N(g)` over `Q` gives the irreducible factor `gcd(g, h)` of `g` over `K`.
+/// Since `N(g(X - sθ))` is square-free for all but finitely many integers `s`, we can thus factor
+/// `g(X - sθ)` by factoring `N(g(X - sθ))` over the rationals, using [`crate::algorithms::zassenhaus::zassenhaus()`],
+/// and then substitute `X + sθ` in the factors.
+///
+/// Since computations in number fields quickly lead to large coefficients, it is usually best to use
+/// an arbitrary-precision integer ring like [`BigIntRing`] for the number field.
+///
+pub fn trager<P, I>(KX: P, f: &El<P>) -> Vec<El<P>>
+    where P: PolyRingStore,
+        P::Type: PolyRing + EuclideanRing,
+        <P::Type as RingExtension>::BaseRing: RingStore<Type = NumberFieldBase<I>>,
+        I: IntegerRingStore,
+        I::Type: IntegerRing
+{
+    let K = KX.base_ring();
+    let f = make_monic(&KX, KX.clone_el(f));
+    if KX.degree(&f).unwrap() <= 1 {
+        return vec![f];
+    }
+    let QQ = K.base_ring();
+    let QQX = DensePolyRing::new(QQ, "X");
+    let theta = K.canonical_gen();
+    // try `s = 0, 1, -1, 2, -2, ...`
+    let (s, shifted_f, norm) = (0..).map(|k: i32| if k % 2 == 0 { -k / 2 } else { (k + 1) / 2 }).map(|s| {
+        let shifted_f = shift(&KX, &f, &K.int_hom().mul_ref_map(&theta, &-s));
+        let norm = norm_poly(&KX, &shifted_f, &QQX);
+        (s, shifted_f, norm)
+    }).filter(|(_, _, norm)| QQX.degree(&algorithms::eea::gcd(QQX.clone_el(norm), QQX.derivative(norm), &QQX)) == Some(0)).next().unwrap();
+
+    let norm_factors = algorithms::poly_factor::factor_over_rationals(&QQX, norm).into_iter()
+        .filter(|(h, _)| QQX.degree(h).unwrap() > 0)
+        .collect::<Vec<_>>();
+    if norm_factors.len() == 1 {
+        return vec![f];
+    }
+    let back_shift = K.int_hom().mul_ref_map(&theta, &s);
+    return norm_factors.into_iter().map(|(h, _)| {
+        let h = KX.from_terms(QQX.terms(&h).map(|(c, i)| (K.inclusion().map_ref(c), i)));
+        let factor = algorithms::eea::gcd(KX.clone_el(&shifted_f), h, &KX);
+        make_monic(&KX, shift(&KX, &factor, &back_shift))
+    }).collect();
+}
+
+///
+/// Factors a nonzero polynomial over an algebraic number field into irreducible factors, and returns a list of
+/// the monic irreducible factors together with their multiplicities. If the leading coefficient of `f` is not one,
+/// it is appended to the list as constant polynomial with multiplicity one.
+///
+pub fn factor_complete<P, I>(KX: P, f: El<P>) -> Vec<(El<P>, usize)>
+    where P: PolyRingStore,
+        P::Type: PolyRing + EuclideanRing,
+        <P::Type as RingExtension>::BaseRing: RingStore<Type = NumberFieldBase<I>>,
+        I: IntegerRingStore,
+        I::Type: IntegerRing
+{
+    assert!(!KX.is_zero(&f));
+    let K = KX.base_ring();
+    let unit = K.clone_el(KX.lc(&f).unwrap());
+
+    let mut result: Vec<(El<P>, usize)> = Vec::new();
+    // the factors of the square-free decomposition are monic and pairwise coprime
+    for (sqrfree_part, power) in KX.squarefree_decomposition(&f) {
+        result.extend(trager(&KX, &sqrfree_part).into_iter().map(|factor| (factor, power)));
+    }
+    if !K.is_one(&unit) {
+        result.push((KX.inclusion().map(unit), 1));
+    }
+    return result;
+}
+
+#[cfg(test)]
+use crate::rings::extension::number_field::NumberField;
+
+#[test]
+fn test_norm_poly() {
+    let ZZ = BigIntRing::RING;
+    // `K = Q(i)`
+    let K = NumberField::new(ZZ, vec![ZZ.int_hom().map(-1), ZZ.zero()]);
+    let KX = DensePolyRing::new(&K, "X");
+    let QQX = DensePolyRing::new(K.base_ring(), "X");
+    let i = K.canonical_gen();
+    // `N(X - i) = X^2 + 1`
+    let g = KX.sub(KX.indeterminate(), KX.inclusion().map_ref(&i));
+    assert_el_eq!(&QQX, &QQX.add(QQX.pow(QQX.indeterminate(), 2), QQX.one()), &norm_poly(&KX, &g, &QQX));
+    // `N(X^2 + iX + 1) = (X^2 + 1)^2 + X^2`
+    let g = KX.from_terms([(K.one(), 0), (K.clone_el(&i), 1), (K.one(), 2)].into_iter());
+    let expected = QQX.add(QQX.pow(QQX.add(QQX.pow(QQX.indeterminate(), 2), QQX.one()), 2), QQX.pow(QQX.indeterminate(), 2));
+    assert_el_eq!(&QQX, &expected, &norm_poly(&KX, &g, &QQX));
+}
+
+#[test]
+fn test_trager() {
+    let ZZ = BigIntRing::RING;
+    // `K = Q(sqrt(2))`
+    let K = NumberField::new(ZZ, vec![ZZ.int_hom().map(2), ZZ.zero()]);
+    let KX = DensePolyRing::new(&K, "X");
+    let sqrt2 = K.canonical_gen();
+    // `X^4 - 4` = `(X - sqrt(2))(X + sqrt(2))(X^2 + 2)`; here the norm of `f` is not square-free
+    let f = KX.sub(KX.pow(KX.indeterminate(), 4), KX.int_hom().map(4));
+    let factors = trager(&KX, &f);
+    assert_eq!(3, factors.len());
+    assert!(factors.iter().any(|g| KX.eq_el(g, &KX.sub(KX.indeterminate(), KX.inclusion().map_ref(&sqrt2)))));
+    assert!(factors.iter().any(|g| KX.eq_el(g, &KX.add(KX.indeterminate(), KX.inclusion().map_ref(&sqrt2)))));
+    assert!(factors.iter().any(|g| KX.eq_el(g, &KX.add(KX.pow(KX.indeterminate(), 2), KX.int_hom().map(2)))));
+
+    // `X^2 - 3` remains irreducible over `Q(sqrt(2))`
+    let f = KX.sub(KX.pow(KX.indeterminate(), 2), KX.int_hom().map(3));
+    assert_eq!(1, trager(&KX, &f).len());
+}
+
+#[test]
+fn test_factor_complete_cyclotomic() {
+    let ZZ = BigIntRing::RING;
+    // `K = Q(i) = Q(zeta_4)`, over which `X^4 - 1` splits into linear factors, but `X^4 + 1 = (X^2 - i)(X^2 + i)` does not
+    let K = NumberField::new(ZZ, vec![ZZ.int_hom().map(-1), ZZ.zero()]);
+    let KX = DensePolyRing::new(&K, "X");
+    let f = KX.sub(KX.pow(KX.indeterminate(), 4), KX.one());
+    let factorization = factor_complete(&KX, KX.clone_el(&f));
+    assert_eq!(4, factorization.len());
+    assert!(factorization.iter().all(|(g, e)| KX.degree(g) == Some(1) && *e == 1));
+    assert_el_eq!(&KX, &f, &KX.prod(factorization.into_iter().map(|(g, _)| g)));
+    let f = KX.add(KX.pow(KX.indeterminate(), 4), KX.one());
+    let factorization = factor_complete(&KX, KX.clone_el(&f));
+    assert_eq!(2, factorization.len());
+    assert!(factorization.iter().all(|(g, e)| KX.degree(g) == Some(2) && *e == 1));
+
+    // `K = Q(sqrt(5))`, which is the quadratic subfield of `Q(zeta_5)`, and `Phi_5 = (X^2 + (1 - sqrt(5))/2 X + 1)(X^2 + (1 + sqrt(5))/2 X + 1)`
+    let K = NumberField::new(ZZ, vec![ZZ.int_hom().map(5), ZZ.zero()]);
+    let KX = DensePolyRing::new(&K, "X");
+    let f = algorithms::cyclotomic::cyclotomic_polynomial(&KX, 5);
+    let f = KX.mul(KX.clone_el(&f), KX.pow(KX.sub(KX.indeterminate(), KX.one()), 2));
+    let f = KX.inclusion().mul_map(f, K.int_hom().map(3));
+    let factorization = factor_complete(&KX, KX.clone_el(&f));
+    assert_eq!(4, factorization.len());
+    assert_el_eq!(&KX, &f, &KX.prod(factorization.iter().map(|(g, e)| KX.pow(KX.clone_el(g), *e))));
+    assert!(factorization.iter().any(|(g, e)| KX.degree(g) == Some(1) && *e == 2));
+    assert_eq!(2, factorization.iter().filter(|(g, _)| KX.degree(g) == Some(2)).count());
+}
@@ -0,0 +1,477 @@
+use crate::algorithms;
+use crate::divisibility::{DivisibilityRing, DivisibilityRingStore};
+use crate::field::FieldStore;
+use crate::rings::field::AsField;
+use crate::integer::*;
+use crate::ordered::OrderedRingStore;
+use crate::pid::EuclideanRingStore;
+use crate::primitive_int::StaticRing;
+use crate::ring::*;
+use crate::homomorphism::*;
+use crate::rings::poly::{PolyRing, PolyRingStore};
+use crate::rings::poly::dense_poly::DensePolyRing;
+use crate::rings::zn::{ZnRingStore, zn_64, zn_barett};
+
+///
+/// The number of primes `p` for which we factor `f mod p`, to find the one giving the fewest factors.
+///
+const PRIME_CANDIDATES: usize = 5;
+
+///
+/// Returns the gcd of all coefficients of `f`, with the same sign as the leading coefficient of `f`.
+///
+fn signed_content<P>(ZZX: P, f: &El<P>) -> El<BigIntRing>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <P::Type as RingExtension>::BaseRing: RingStore<Type = BigIntRingBase>
+{
+    let ZZ = BigIntRing::RING;
+    let content = ZZ.abs(ZZX.content(f));
+    match ZZX.lc(f) {
+        Some(lc) if ZZ.is_neg(lc) => ZZ.negate(content),
+        _ => content
+    }
+}
+
+///
+/// Returns `f` divided by its content, normalized to have a positive leading coefficient.
+///
+fn primitive_part<P>(ZZX: P, f: El<P>) -> El<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <P::Type as RingExtension>::BaseRing: RingStore<Type = BigIntRingBase>
+{
+    let ZZ = BigIntRing::RING;
+    let f = ZZX.primitive_part(f);
+    match ZZX.lc(&f) {
+        Some(lc) if ZZ.is_neg(lc) => ZZX.negate(f),
+        _ => f
+    }
+}
+
+///
+/// Returns a table whose `d`-th entry is `true` if and only if `d` is the sum of the degrees of some
+/// subset of the given factors.
+///
+fn subset_degree_sums<I>(degrees: I, n: usize) -> Vec<bool>
+    where I: Iterator<Item = usize>
+{
+    let mut result = (0..=n).map(|d| d == 0).collect::<Vec<_>>();
+    for d in degrees {
+        for i in (d..=n).rev() {
+            result[i] |= result[i - d];
+        }
+    }
+    return result;
+}
+
+///
+/// Chooses a prime `p` such that `f mod p` is square-free of the same degree as `f`, and returns `p` together
+/// with the monic irreducible factors of `f mod p`.
+///
+/// Furthermore, returns a table whose `d`-th entry is `false` if we found that `f` cannot have a factor of
+/// degree `d`. This is the case if `d` is not a sum of degrees of factors of `f mod p`, for some of the considered
+/// primes `p`.
+///
+fn choose_prime<P>(ZZX: P, f: &El<P>) -> (El<BigIntRing>, Vec<El<P>>, Vec<bool>)
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <P::Type as RingExtension>::BaseRing: RingStore<Type = BigIntRingBase>
+{
+    let ZZ = BigIntRing::RING;
+    let n = ZZX.degree(f).unwrap();
+    let mut possible_degrees = vec![true; n + 1];
+    let mut best: Option<(i64, Vec<El<P>>)> = None;
+    let mut good_primes = 0;
+    let mut p = 1;
+    while good_primes < PRIME_CANDIDATES {
+        p += 1;
+        if !algorithms::miller_rabin::is_prime(StaticRing::<i64>::RING, &p, 10) || ZZ.checked_div(ZZX.lc(f).unwrap(), &ZZ.int_hom().map(p as i32)).is_some() {
+            continue;
+        }
+        let Fp = zn_64::Zn::new(p as u64).as_field().ok().unwrap();
+        let FpX = DensePolyRing::new(Fp, "X");
+        let f_mod_p = FpX.from_terms(ZZX.terms(f).map(|(c, i)| (Fp.coerce(&ZZ, ZZ.clone_el(c)), i)));
+        if FpX.degree(&algorithms::eea::gcd(FpX.clone_el(&f_mod_p), FpX.derivative(&f_mod_p), &FpX)) != Some(0) {
+            continue;
+        }
+        good_primes += 1;
+        let factors = algorithms::poly_factor::factor_over_finite_field(&FpX, f_mod_p).into_iter()
+            .filter(|(g, _)| FpX.degree(g).unwrap() > 0)
+            .map(|(g, _)| ZZX.from_terms(FpX.terms(&g).map(|(c, i)| (int_cast(Fp.smallest_positive_lift(Fp.clone_el(c)), &ZZ, Fp.integer_ring()), i))))
+            .collect::<Vec<_>>();
+        for (possible, found) in possible_degrees.iter_mut().zip(subset_degree_sums(factors.iter().map(|g| ZZX.degree(g).unwrap()), n)) {
+            *possible &= found;
+        }
+        if best.as_ref().map(|(_, best_factors)| factors.len() < best_factors.len()).unwrap_or(true) {
+            best = Some((p, factors));
+        }
+        if best.as_ref().unwrap().1.len() == 1 || possible_degrees[1..n].iter().all(|possible| !possible) {
+            break;
+        }
+    }
+    let (p, factors) = best.unwrap();
+    return (ZZ.int_hom().map(p as i32), factors, possible_degrees);
+}
+
+fn reduce_poly<P, Q>(ZZX: P, f: &El<P>, ZnX: Q) -> El<Q>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <P::Type as RingExtension>::BaseRing: RingStore<Type = BigIntRingBase>,
+        Q: PolyRingStore,
+        Q::Type: PolyRing,
+        <Q::Type as RingExtension>::BaseRing: RingStore<Type = zn_barett::ZnBase<BigIntRing>>
+{
+    let ZZ = BigIntRing::RING;
+    let Zn = ZnX.base_ring();
+    ZnX.from_terms(ZZX.terms(f).map(|(c, i)| (Zn.coerce(&ZZ, ZZ.clone_el(c)), i)))
+}
+
+fn lift_poly<P, Q>(ZZX: P, f: &El<Q>, ZnX: Q) -> El<P>
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <P::Type as RingExtension>::BaseRing: RingStore<Type = BigIntRingBase>,
+        Q: PolyRingStore,
+        Q::Type: PolyRing,
+        <Q::Type as RingExtension>::BaseRing: RingStore<Type = zn_barett::ZnBase<BigIntRing>>
+{
+    let Zn = ZnX.base_ring();
+    ZZX.from_terms(ZnX.terms(f).map(|(c, i)| (Zn.smallest_positive_lift(Zn.clone_el(c)), i)))
+}
+
+///
+/// Given `f = g h mod p` with `h` monic and `g, h` coprime modulo `p`, computes `g', h'` such that
+/// `f = g' h' mod p^(2^k)`, where `p^(2^k) >= bound`, using quadratic Hensel lifting as in
+/// Algorithm 15.10 of "Modern Computer Algebra" by von zur Gathen and Gerhard.
+///
+/// Returns `g', h'` and the modulus `p^(2^k)`.
+///
+fn hensel_lift_two_factors<P>(ZZX: P, f: &El<P>, g: El<P>, h: El<P>, p: &El<BigIntRing>, bound: &El<BigIntRing>) -> (El<P>, El<P>, El<BigIntRing>)
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <P::Type as RingExtension>::BaseRing: RingStore<Type = BigIntRingBase>
+{
+    let ZZ = BigIntRing::RING;
+    let (mut s, mut t) = {
+        let Fp = zn_64::Zn::new(int_cast(ZZ.clone_el(p), StaticRing::<i64>::RING, &ZZ) as u64).as_field().ok().unwrap();
+        let FpX = DensePolyRing::new(Fp, "X");
+        let to_FpX = |x: &El<P>| FpX.from_terms(ZZX.terms(x).map(|(c, i)| (Fp.coerce(&ZZ, ZZ.clone_el(c)), i)));
+        let from_FpX = |x: &El<DensePolyRing<AsField<zn_64::Zn>>>| ZZX.from_terms(FpX.terms(x).map(|(c, i)| (int_cast(Fp.smallest_positive_lift(Fp.clone_el(c)), &ZZ, Fp.integer_ring()), i)));
+        let g_mod_p = to_FpX(&g);
+        let h_mod_p = to_FpX(&h);
+        let (s, _, d) = algorithms::eea::eea(FpX.clone_el(&g_mod_p), FpX.clone_el(&h_mod_p), &FpX);
+        assert_eq!(Some(0), FpX.degree(&d));
+        let d_inv = Fp.div(&Fp.one(), FpX.coefficient_at(&d, 0));
+        // choose `s, t` with `deg(s) < deg(h)` and `deg(t) < deg(g)`
+        let s = FpX.div_rem_monic(FpX.inclusion().mul_map(s, d_inv), &h_mod_p).1;
+        let t = FpX.div_rem_monic(FpX.sub(FpX.one(), FpX.mul_ref(&s, &g_mod_p)), &h_mod_p).0;
+        (from_FpX(&s), from_FpX(&t))
+    };
+    let (mut g, mut h) = (g, h);
+    let mut modulus = ZZ.clone_el(p);
+    while ZZ.is_lt(&modulus, bound) {
+        modulus = ZZ.pow(modulus, 2);
+        let Zm = zn_barett::Zn::new(ZZ, ZZ.clone_el(&modulus));
+        let ZmX = DensePolyRing::new(&Zm, "X");
+        let f_mod = reduce_poly(&ZZX, f, &ZmX);
+        let (g_mod, h_mod) = (reduce_poly(&ZZX, &g, &ZmX), reduce_poly(&ZZX, &h, &ZmX));
+        let (s_mod, t_mod) = (reduce_poly(&ZZX, &s, &ZmX), reduce_poly(&ZZX, &t, &ZmX));
+
+        let e = ZmX.sub_ref_fst(&f_mod, ZmX.mul_ref(&g_mod, &h_mod));
+        let (q, r) = ZmX.div_rem_monic(ZmX.mul_ref(&s_mod, &e), &h_mod);
+        let new_g = ZmX.add(ZmX.add_ref_fst(&g_mod, ZmX.mul_ref(&t_mod, &e)), ZmX.mul_ref(&q, &g_mod));
+        let new_h = ZmX.add_ref_fst(&h_mod, r);
+        let b = ZmX.sub(ZmX.add(ZmX.mul_ref(&s_mod, &new_g), ZmX.mul_ref(&t_mod, &new_h)), ZmX.one());
+        let (c, d) = ZmX.div_rem_monic(ZmX.mul_ref(&s_mod, &b), &new_h);
+        let new_s = ZmX.sub(s_mod, d);
+        let new_t = ZmX.sub(ZmX.sub_ref_fst(&t_mod, ZmX.mul_ref(&t_mod, &b)), ZmX.mul_ref(&c, &new_g));
+
+        g = lift_poly(&ZZX, &new_g, &ZmX);
+        h = lift_poly(&ZZX, &new_h, &ZmX);
+        s = lift_poly(&ZZX, &new_s, &ZmX);
+        t = lift_poly(&ZZX, &new_t, &ZmX);
+    }
+    return (g, h, modulus);
+}
+
+///
+/// Lifts the factorization `f = lc(f) g1 ... gr mod p` into monic, pairwise coprime factors to a
+/// factorization modulo some `p^k >= bound`. Returns the lifted monic factors and `p^k`.
+///
+fn hensel_lift<P>(ZZX: P, f: &El<P>, factors: &[El<P>], p: &El<BigIntRing>, bound: &El<BigIntRing>) -> (Vec<El<P>>, El<BigIntRing>)
+    where P: PolyRingStore,
+        P::Type: PolyRing,
+        <P::Type as RingExtension>::BaseRing: RingStore<Type = BigIntRingBase>
+{
+    let ZZ = BigIntRing::RING;
+    let lc = ZZX.lc(f).unwrap();
+    let mut current = ZZX.clone_el(f);
+    let mut result = Vec::new();
+    let mut modulus = ZZ.clone_el(p);
+    for i in 0..(factors.len() - 1) {
+        let rest = ZZX.inclusion().mul_ref_map(&ZZX.prod(factors[(i + 1)..].iter().map(|g| ZZX.clone_el(g))), lc);
+        let (new_current, lifted_factor, new_modulus) = hensel_lift_two_factors(&ZZX, &current, rest, ZZX.clone_el(&factors[i]), p, bound);
+        result.push(lifted_factor);
+        current = new_current;
+        modulus = new_modulus;
+    }
+    // now `current = lc(f) g_r mod p^k`
+    let Zm = zn_barett::Zn::new(ZZ, ZZ.clone_el(&modulus));
+    let ZmX = DensePolyRing::new(&Zm, "X");
+    let lc_inv = Zm.checked_div(&Zm.one(), &Zm.coerce(&ZZ, ZZ.clone_el(lc))).unwrap();
+    result.push(lift_poly(&ZZX, &ZmX.inclusion().mul_map(reduce_poly(&ZZX, &current, &ZmX), lc_inv), &ZmX));
+    return (result, modulus);
+}
+
+///
+/// Returns the next subset of `{0, ..., n - 1}` of the same size in lexicographic order, or `false`
+/// if the given subset was the last one.
+///
+fn next_subset(subset: &mut [usize], n: usize) -> bool {
+    let k = subset.len();
+    for i in (0..k).rev() {
+        if subset[i] < n - k + i {
+            subset[i] += 1;
+            for j in (i + 1)..k {
+                subset[j] = subset[j - 1] + 1;
+            }
+            return true;
+        }
+    }
+    return false;
+}
+
+///
+/// Finds the true factors of `f` over the integers, given monic factors `g1, ..., gr` with `f = lc(f) g1 ... gr`
+/// modulo `modulus`, by trying all products of subsets of the `gi`.
+///
+/// Before computing the product of a subset, we check that its degree is contained in `possible_degrees` and
+/// that its second-highest coefficient (which is the sum of the second-highest coefficients of the `gi`) is at most
+/// `coeff_bound` in absolute value, after multiplying by `lc(f)`. Here `coeff_bound` must be a bound on the coefficients
+/// of `lc(f) / lc(g) g`, for every factor `g` of `f`.
+///
+fn recombine<P>(ZZX: P, f: &El<P>, mut factors: Vec<El<P>>, modulus: &El<BigIntRing>, possible_degrees: &[bool], coeff_bound: &El<BigIntRing>) -> Vec<El<P>>
+    where P: PolyRingStore,
+        P::Type: PolyRing + DivisibilityRing,
+        <P::Type as RingExtension>::BaseRing: RingStore<Type = BigIntRingBase>
+{
+    let ZZ = BigIntRing::RING;
+    let half_modulus = ZZ.half_exact(ZZ.sub_ref_fst(modulus, ZZ.abs_is_bit_set(modulus, 0).then(|| ZZ.one()).unwrap_or(ZZ.zero())));
+    let symmetric_mod = |x: El<BigIntRing>| {
+        let x = ZZ.euclidean_rem(x, modulus);
+        if ZZ.is_gt(&x, &half_modulus) { ZZ.sub_ref_snd(x, modulus) } else { x }
+    };
+    let mut f = ZZX.clone_el(f);
+    let mut result = Vec::new();
+    let mut subset_size = 1;
+    'subset_sizes: while 2 * subset_size <= factors.len() {
+        let mut subset = (0..subset_size).collect::<Vec<_>>();
+        loop {
+            let lc = ZZX.lc(&f).unwrap();
+            let degree = subset.iter().map(|i| ZZX.degree(&factors[*i]).unwrap()).sum::<usize>();
+            // the second-highest coefficient of the candidate can be computed without the product, and must satisfy the coefficient bound
+            let trace = symmetric_mod(ZZ.mul_ref_snd(ZZ.sum(subset.iter().map(|i| ZZ.clone_el(ZZX.coefficient_at(&factors[*i], ZZX.degree(&factors[*i]).unwrap() - 1)))), lc));
+            if !possible_degrees[degree] || ZZ.is_gt(&ZZ.abs(trace), coeff_bound) {
+                if !next_subset(&mut subset, factors.len()) {
+                    break;
+                }
+                continue;
+            }
+            let candidate = ZZX.inclusion().mul_ref_map(&ZZX.prod(subset.iter().map(|i| ZZX.clone_el(&factors[*i]))), lc);
+            let candidate = primitive_part(&ZZX, ZZX.from_terms(ZZX.terms(&candidate).map(|(c, i)| (symmetric_mod(ZZ.clone_el(c)), i))));
+            // checking the constant coefficient first is much cheaper than a polynomial division
+            let constant_coeff_divides = ZZ.checked_div(ZZX.coefficient_at(&f, 0), ZZX.coefficient_at(&candidate, 0)).is_some();
+            if let Some(quo) = constant_coeff_divides.then(|| ZZX.checked_div(&f, &candidate)).flatten() {
+                result.push(candidate);
+                f = quo;
+                let mut i = 0;
+                factors.retain(|_| { i += 1; !subset.contains(&(i - 1)) });
+                continue 'subset_sizes;
+            }
+            if !next_subset(&mut subset, factors.len()) {
+                break;
+            }
+        }
+        subset_size += 1;
+    }
+    result.push(f);
+    return result;
+}
+
+///
+/// Factors a primitive and square-free polynomial `f` over the integers into irreducible factors,
+/// using the algorithm of Zassenhaus. The factors are primitive and have positive leading coefficient.
+///
+/// # Algorithm
+///
+/// We first choose a prime `p` that does not divide the leading coefficient of `f` and such that
+/// `f mod p` is square-free, and factor `f mod p` over `Fp`. Then we lift this factorization to a
+/// factorization modulo `p^k` using Hensel lifting, where `p^k` is larger than twice Mignotte's bound
+/// on the coefficients of factors of `f`. Finally, every true factor of `f` corresponds to the product
+/// of a subset of the lifted factors, so we find them by trying all subsets. Before computing the product
+/// of a subset, we discard it if its degree is impossible for a factor of `f` (by comparing the factorizations
+/// of `f` modulo multiple primes), or if its second-highest coefficient violates the coefficient bound.
+///
+/// Note that the last step still takes exponential time in the worst case, which occurs when `f` has many
+/// factors modulo every prime, e.g. for Swinnerton-Dyer polynomials. Replacing it by the lattice-based
+/// recombination of van Hoeij, which runs in polynomial time, is left for future work.
+///
+pub fn zassenhaus<P>(ZZX: P, f: &El<P>) -> Vec<El<P>>
+    where P: PolyRingStore,
+        P::Type: PolyRing + DivisibilityRing,
+        <P::Type as RingExtension>::BaseRing: RingStore<Type = BigIntRingBase>
+{
+    let ZZ = BigIntRing::RING;
+    let f = primitive_part(&ZZX, ZZX.clone_el(f));
+    let n = ZZX.degree(&f).unwrap();
+    assert!(n > 0);
+    if n == 1 {
+        return vec![f];
+    }
+    let (p, factors_mod_p, possible_degrees) = choose_prime(&ZZX, &f);
+    if factors_mod_p.len() == 1 || possible_degrees[1..n].iter().all(|possible| !possible) {
+        return vec![f];
+    }
+    // by Mignotte's bound, every factor `g` of `f` satisfies `|g|_inf <= 2^deg(g) |f|_2 <= 2^n sqrt(n + 1) |f|_inf`;
+    // since we multiply candidates by `lc(f)`, we need the modulus to be larger than twice `|lc(f)|` times this bound
+    let max_coeff = ZZX.terms(&f).map(|(c, _)| ZZ.abs(ZZ.clone_el(c))).max_by(|a, b| ZZ.cmp(a, b)).unwrap();
+    let coeff_bound = ZZ.prod([
+        ZZ.abs(ZZ.clone_el(ZZX.lc(&f).unwrap())),
+        ZZ.pow(ZZ.int_hom().map(2), n),
+        ZZ.int_hom().map(n as i32 + 1),
+        max_coeff
+    ].into_iter());
+    let bound = ZZ.int_hom().mul_ref_map(&coeff_bound, &2);
+    let (lifted_factors, modulus) = hensel_lift(&ZZX, &f, &factors_mod_p, &p, &bound);
+    return recombine(&ZZX, &f, lifted_factors, &modulus, &possible_degrees, &coeff_bound);
+}
+
+///
+/// Factors a nonzero polynomial over the integers into irreducible factors, and returns a list of
+/// the primitive irreducible factors with positive leading coefficient together with their multiplicities.
+/// If the content of `f` (with the sign of the leading coefficient) is not one, it is appended to the list as
+/// constant polynomial with multiplicity one.
+///
+/// Note that only the irreducible factors of positive degree are computed, i.e. the content is not factored.
+///
+pub fn factor_complete<P>(ZZX: P, f: El<P>) -> Vec<(El<P>, usize)>
+    where P: PolyRingStore,
+        P::Type: PolyRing + DivisibilityRing,
+        <P::Type as RingExtension>::BaseRing: RingStore<Type = BigIntRingBase>
+{
+    assert!(!ZZX.is_zero(&f));
+    let ZZ = BigIntRing::RING;
+    let content = signed_content(&ZZX, &f);
+
+    let mut result: Vec<(El<P>, usize)> = Vec::new();
+    // the factors of the square-free decomposition are pairwise coprime
+    for (sqrfree_part, power) in ZZX.squarefree_decomposition(&f) {
+        let sqrfree_part = primitive_part(&ZZX, sqrfree_part);
+        result.extend(zassenhaus(&ZZX, &sqrfree_part).into_iter().map(|factor| (factor, power)));
+    }
+    if !ZZ.is_one(&content) {
+        result.push((ZZX.inclusion().map(content), 1));
+    }
+    return result;
+}
+
+#[cfg(test)]
+fn assert_is_factorization<P>(ZZX: P, f: &El<P>, factorization: &[(El<P>, usize)])
+    where P: PolyRingStore,
+        P::Type: PolyRing + DivisibilityRing,
+        <P::Type as RingExtension>::BaseRing: RingStore<Type = BigIntRingBase>
+{
+    assert_el_eq!(&ZZX, f, &ZZX.prod(factorization.iter().map(|(g, e)| ZZX.pow(ZZX.clone_el(g), *e))));
+    for (g, _) in factorization.iter().filter(|(g, _)| ZZX.degree(g).unwrap() > 0) {
+        assert!(ZZX.base_ring().is_pos(ZZX.lc(g).unwrap()));
+        assert!(ZZX.base_ring().is_one(&signed_content(&ZZX, g)));
+    }
+}
+
+#[test]
+fn test_hensel_lift() {
+    let ZZ = BigIntRing::RING;
+    let ZZX = DensePolyRing::new(ZZ, "X");
+    // `X^4 - 10 X^2 + 1` is irreducible over `Z`, but factors into two quadratics modulo every prime
+    let f = ZZX.from_terms([(ZZ.int_hom().map(1), 0), (ZZ.int_hom().map(-10), 2), (ZZ.int_hom().map(1), 4)].into_iter());
+    let (p, factors, possible_degrees) = choose_prime(&ZZX, &f);
+    assert!(factors.len() > 1);
+    assert!(possible_degrees[2]);
+    let bound = ZZ.power_of_two(100);
+    let (lifted, modulus) = hensel_lift(&ZZX, &f, &factors, &p, &bound);
+    assert!(ZZ.is_geq(&modulus, &bound));
+    let Zm = zn_barett::Zn::new(ZZ, modulus);
+    let ZmX = DensePolyRing::new(&Zm, "X");
+    assert_el_eq!(&ZmX, &reduce_poly(&ZZX, &f, &ZmX), &ZmX.prod(lifted.iter().map(|g| reduce_poly(&ZZX, g, &ZmX))));
+}
+
+#[test]
+fn test_possible_degrees() {
+    let ZZ = BigIntRing::RING;
+    let ZZX = DensePolyRing::new(ZZ, "X");
+    let poly = |coeffs: &[i32]| ZZX.from_terms(coeffs.iter().enumerate().map(|(i, c)| (ZZ.int_hom().map(*c), i)));
+
+    assert_eq!(vec![true, false, true, true, false, true], subset_degree_sums([2, 3].into_iter(), 5));
+
+    // `(X^2 - 2)(X^3 - 3)` must allow the degrees of its factors
+    let f = ZZX.mul(poly(&[-2, 0, 1]), poly(&[-3, 0, 0, 1]));
+    let (_, _, possible_degrees) = choose_prime(&ZZX, &f);
+    assert!(possible_degrees[0] && possible_degrees[2] && possible_degrees[3] && possible_degrees[5]);
+
+    // `X^4 + 1` splits into two quadratics modulo every prime, so a linear factor is impossible
+    let f = poly(&[1, 0, 0, 0, 1]);
+    let (_, _, possible_degrees) = choose_prime(&ZZX, &f);
+    assert!(!possible_degrees[1] && !possible_degrees[3]);
+}
+
+#[test]
+fn test_zassenhaus() {
+    let ZZ = BigIntRing::RING;
+    let ZZX = DensePolyRing::new(ZZ, "X");
+    let poly = |coeffs: &[i32]| ZZX.from_terms(coeffs.iter().enumerate().map(|(i, c)| (ZZ.int_hom().map(*c), i)));
+
+    let f = poly(&[1, 0, -10, 0, 1]);
+    assert_eq!(1, zassenhaus(&ZZX, &f).len());
+
+    // the Swinnerton-Dyer polynomial for `sqrt(2), sqrt(3), sqrt(5)` is irreducible, but has at least 4 factors modulo every prime
+    let f = poly(&[576, 0, -960, 0, 352, 0, -40, 0, 1]);
+    assert_eq!(1, zassenhaus(&ZZX, &f).len());
+
+    let factors = [poly(&[1, 0, -10, 0, 1]), poly(&[-2, 0, 3]), poly(&[5, 1]), poly(&[1, 1, 1, 1, 1, 1, 1]), poly(&[7, 0, 0, 0, 0, 0, 0, 0, 0, 2])];
+    let f = ZZX.prod(factors.iter().map(|g| ZZX.clone_el(g)));
+    let actual = zassenhaus(&ZZX, &f);
+    assert_eq!(factors.len(), actual.len());
+    for g in &factors {
+        assert!(actual.iter().any(|h| ZZX.eq_el(g, h)));
+    }
+}
+
+#[test]
+fn test_factor_complete() {
+    let ZZ = BigIntRing::RING;
+    let ZZX = DensePolyRing::new(ZZ, "X");
+    let poly = |coeffs: &[i32]| ZZX.from_terms(coeffs.iter().enumerate().map(|(i, c)| (ZZ.int_hom().map(*c), i)));
+
+    // `-6 (X - 1)^3 (X + 1) (X^2 + 1)^2 X`
+    let f = ZZX.prod([poly(&[-6]), poly(&[-1, 1]), poly(&[-1, 1]), poly(&[-1, 1]), poly(&[1, 1]), poly(&[1, 0, 1]), poly(&[1, 0, 1]), poly(&[0, 1])].into_iter());
+    let factorization = factor_complete(&ZZX, ZZX.clone_el(&f));
+    assert_is_factorization(&ZZX, &f, &factorization);
+    assert_eq!(5, factorization.len());
+    assert!(factorization.iter().any(|(g, e)| ZZX.eq_el(g, &poly(&[-1, 1])) && *e == 3));
+    assert!(factorization.iter().any(|(g, e)| ZZX.eq_el(g, &poly(&[1, 0, 1])) && *e == 2));
+    assert!(factorization.iter().any(|(g, e)| ZZX.eq_el(g, &poly(&[-6])) && *e == 1));
+
+    // `X^12 - 1` is the product of the cyclotomic polynomials `Phi_d` for `d | 12`
+    let f = ZZX.sub(ZZX.pow(ZZX.indeterminate(), 12), ZZX.one());
+    let factorization = factor_complete(&ZZX, ZZX.clone_el(&f));
+    assert_is_factorization(&ZZX, &f, &factorization);
+    assert_eq!(6, factorization.len());
+
+    // a polynomial with large coefficients
+    let g = poly(&[1 << 20, -(1 << 25) + 1, 3]);
+    let h = poly(&[-(1 << 29), 0, 0, 1 << 18, 1]);
+    let f = ZZX.mul_ref(&g, &h);
+    let factorization = factor_complete(&ZZX, ZZX.clone_el(&f));
+    assert_is_factorization(&ZZX, &f, &factorization);
+    assert_eq!(2, factorization.len());
+}
@@ -8,7 +8,8 @@ use crate::pid::{EuclideanRing, PrincipalIdealRing};
 use crate::divisibility::DivisibilityRing;
 use crate::ordered::OrderedRing;
 use crate::integer::*;
-use crate::algorithms::conv_mul::KaratsubaHint;
+use crate::algorithms::conv_mul::{KaratsubaHint, FFTConvolutionHint};
+use crate::algorithms::fft_mul::add_assign_integer_fft_convoluted_mul;
 
 ///
 /// Trait for `i8` to `i128`.
@@ -244,6 +245,30 @@ impl KaratsubaHint for StaticRingBase<i128> {
     fn karatsuba_threshold(&self) -> usize { 3 }
 }
 
+impl FFTConvolutionHint for StaticRingBase<i32> {
+    fn fft_convolution_threshold(&self) -> usize { 1024 }
+
+    fn add_assign_fft_convoluted_mul(&self, dst: &mut [i32], lhs: &[i32], rhs: &[i32]) -> bool {
+        add_assign_integer_fft_convoluted_mul(RingRef::new(self), dst, lhs, rhs)
+    }
+}
+
+impl FFTConvolutionHint for StaticRingBase<i64> {
+    fn fft_convolution_threshold(&self) -> usize { 1024 }
+
+    fn add_assign_fft_convoluted_mul(&self, dst: &mut [i64], lhs: &[i64], rhs: &[i64]) -> bool {
+        add_assign_integer_fft_convoluted_mul(RingRef::new(self), dst, lhs, rhs)
+    }
+}
+
+impl FFTConvolutionHint for StaticRingBase<i128> {
+    fn fft_convolution_threshold(&self) -> usize { 1024 }
+
+    fn add_assign_fft_convoluted_mul(&self, dst: &mut [i128], lhs: &[i128], rhs: &[i128]) -> bool {
+        add_assign_integer_fft_convoluted_mul(RingRef::new(self), dst, lhs, rhs)
+    }
+}
+
 ///
 /// The ring of integers `Z`, using the arithmetic of the primitive integer type `T`.
 /// 
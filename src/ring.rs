@@ -160,10 +160,13 @@ pub trait RingBase: PartialEq {
     /// This would usually be the case for rings that are based on `f32` or
     /// `f64`, to represent real or complex numbers.
     /// 
-    /// Note that these rings cannot provide implementations for [`Self::eq_el()`], 
-    /// [`Self::is_zero()`] etc, and hence are of limited use in this crate.
+    /// Note that these rings usually cannot provide implementations for [`Self::eq_el()`], 
+    /// [`Self::is_zero()`] etc, and hence are of limited use in this crate. An exception is
+    /// [`crate::rings::float_real::Real64`], which implements equality as exact equality of
+    /// the underlying `f64` values, so that it can be used for polynomials and linear algebra.
     /// Currently, the only way how approximate rings are used is a complex-valued
-    /// fast Fourier transform, via [`crate::rings::float_complex::Complex64`].
+    /// fast Fourier transform, via [`crate::rings::float_complex::Complex64`] or
+    /// [`crate::rings::float_mp::ComplexMP`].
     /// 
     fn is_approximate(&self) -> bool { false }
 
@@ -0,0 +1,699 @@
+use std::cmp::Ordering;
+
+use crate::algorithms::fft::complex_fft::ApproxComplexRing;
+use crate::divisibility::DivisibilityRing;
+use crate::field::{Field, FieldStore};
+use crate::homomorphism::*;
+use crate::integer::*;
+use crate::pid::{EuclideanRing, PrincipalIdealRing};
+use crate::ring::*;
+
+///
+/// Returns an upper bound on the rounding error that occured when computing `x`
+/// by a single floating point operation (using round-to-nearest).
+///
+fn rounding_error(x: f64) -> f64 {
+    // the smallest subnormal number accounts for underflow
+    (x.abs() * f64::EPSILON).next_up() + f64::from_bits(1)
+}
+
+fn add_up(lhs: f64, rhs: f64) -> f64 {
+    (lhs + rhs).next_up()
+}
+
+fn mul_up(lhs: f64, rhs: f64) -> f64 {
+    (lhs * rhs).next_up()
+}
+
+fn div_up(lhs: f64, rhs: f64) -> f64 {
+    (lhs / rhs).next_up()
+}
+
+///
+/// An upper bound on `|2 pi - 2 * PI|`, where `PI` is [`std::f64::consts::PI`].
+///
+const TWO_PI_ERROR: f64 = 2.5e-16;
+
+///
+/// An upper bound on the absolute error of `f64::sin()` and `f64::cos()`.
+/// 
+/// The standard library does not guarantee any accuracy here, but all common implementations
+/// of `libm` are accurate to within one ulp. Since the results are bounded by `1` in absolute
+/// value, one ulp is at most `EPSILON`, and we add a safety margin of one more ulp.
+///
+const SIN_COS_ERROR: f64 = 2. * f64::EPSILON;
+
+///
+/// Computes an upper bound to `|x|` for a complex number `x`.
+///
+fn complex_abs_up(re: f64, im: f64) -> f64 {
+    // `hypot()` is not guaranteed to be correctly rounded
+    mul_up(re.hypot(im), 1. + 4. * f64::EPSILON)
+}
+
+///
+/// Computes a lower bound to `|x|` for a complex number `x`.
+///
+fn complex_abs_down(re: f64, im: f64) -> f64 {
+    (re.hypot(im) * (1. - 4. * f64::EPSILON)).next_down()
+}
+
+///
+/// The ring of real balls, i.e. real intervals `[m - r, m + r]`, which are used to approximate
+/// real numbers in a rigorous way.
+///
+/// More concretely, midpoint `m` and radius `r` are stored as `f64`, and all operations
+/// round the radius outwards, such that the result of an operation is guaranteed to contain
+/// the result of the operation when applied to any elements of the input balls.
+///
+/// This ring is approximate (see [`RingBase::is_approximate()`]) and does not support
+/// equality checks. However, using [`RealBall::certified_precision()`] one can query how
+/// precise a result is, and [`RealBall::contains()`] and [`RealBall::cmp_certified()`] can
+/// be used to draw rigorous conclusions about the represented real numbers.
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::homomorphism::*;
+/// # use feanor_math::rings::ball::*;
+/// let R = RealBall::RING;
+/// let x = R.sqrt(R.int_hom().map(2));
+/// let y = R.sub(R.mul_ref(&x, &x), R.int_hom().map(2));
+/// assert!(R.contains(&y, 0.));
+/// assert!(R.certified_precision(&x) > 50.);
+/// ```
+///
+#[derive(Clone, Copy, PartialEq)]
+pub struct RealBall;
+
+#[derive(Clone, Copy, Debug)]
+pub struct RealBallEl {
+    mid: f64,
+    rad: f64
+}
+
+impl RealBall {
+
+    pub const RING: RingValue<RealBall> = RingValue::from(RealBall);
+
+    ///
+    /// Returns the ball with given midpoint and radius.
+    ///
+    pub fn ball(&self, mid: f64, rad: f64) -> RealBallEl {
+        assert!(mid.is_finite());
+        assert!(rad >= 0.);
+        RealBallEl { mid, rad }
+    }
+
+    ///
+    /// Returns the ball containing exactly the given value.
+    ///
+    pub fn from_f64(&self, x: f64) -> RealBallEl {
+        self.ball(x, 0.)
+    }
+
+    pub fn midpoint(&self, x: &RealBallEl) -> f64 {
+        x.mid
+    }
+
+    pub fn radius(&self, x: &RealBallEl) -> f64 {
+        x.rad
+    }
+
+    pub fn contains(&self, x: &RealBallEl, value: f64) -> bool {
+        (x.mid - value).abs().next_down() <= x.rad
+    }
+
+    pub fn contains_zero(&self, x: &RealBallEl) -> bool {
+        self.contains(x, 0.)
+    }
+
+    ///
+    /// Returns whether the balls have a nonempty intersection, i.e. whether they might
+    /// represent the same real number.
+    ///
+    pub fn overlaps(&self, lhs: &RealBallEl, rhs: &RealBallEl) -> bool {
+        (lhs.mid - rhs.mid).abs().next_down() <= add_up(lhs.rad, rhs.rad)
+    }
+
+    ///
+    /// Returns the ordering of the represented real numbers, if it can be determined
+    /// with certainty. In other words, returns `None` if the balls overlap.
+    ///
+    pub fn cmp_certified(&self, lhs: &RealBallEl, rhs: &RealBallEl) -> Option<Ordering> {
+        if self.overlaps(lhs, rhs) {
+            None
+        } else {
+            lhs.mid.partial_cmp(&rhs.mid)
+        }
+    }
+
+    ///
+    /// Returns the number of bits of the represented real number that are certainly correct,
+    /// i.e. the value `-log2(r / |m|)` for the midpoint `m` and radius `r`.
+    ///
+    /// If the ball contains zero, we cannot certify any bits, thus this returns `0`. On the
+    /// other hand, if the ball is exact (i.e. has radius zero), this returns `infinity`.
+    ///
+    pub fn certified_precision(&self, x: &RealBallEl) -> f64 {
+        if x.rad == 0. {
+            f64::INFINITY
+        } else if self.contains_zero(x) {
+            0.
+        } else {
+            f64::max(0., -(x.rad / x.mid.abs()).log2())
+        }
+    }
+
+    pub fn abs(&self, x: RealBallEl) -> RealBallEl {
+        RealBallEl { mid: x.mid.abs(), rad: x.rad }
+    }
+
+    pub fn sqrt(&self, x: RealBallEl) -> RealBallEl {
+        assert!(x.mid + x.rad >= 0., "Cannot compute the square root of a negative number");
+        let lower = (x.mid - x.rad).next_down();
+        if lower <= 0. {
+            // the ball contains `[0, sqrt(m + r)]`
+            let upper = add_up(x.mid, x.rad).sqrt().next_up();
+            return RealBallEl { mid: upper / 2., rad: div_up(upper, 2.).next_up() };
+        }
+        // `|sqrt(x) - sqrt(m)| = |x - m| / (sqrt(x) + sqrt(m)) <= r / (sqrt(m - r) + sqrt(m))`
+        let mid = x.mid.sqrt();
+        let denominator = (lower.sqrt().next_down() + mid.next_down()).next_down();
+        RealBallEl { mid, rad: add_up(div_up(x.rad, denominator), rounding_error(mid)) }
+    }
+}
+
+impl RingValue<RealBall> {
+
+    pub fn ball(&self, mid: f64, rad: f64) -> RealBallEl { self.get_ring().ball(mid, rad) }
+
+    pub fn from_f64(&self, x: f64) -> RealBallEl { self.get_ring().from_f64(x) }
+
+    pub fn midpoint(&self, x: &RealBallEl) -> f64 { self.get_ring().midpoint(x) }
+
+    pub fn radius(&self, x: &RealBallEl) -> f64 { self.get_ring().radius(x) }
+
+    pub fn contains(&self, x: &RealBallEl, value: f64) -> bool { self.get_ring().contains(x, value) }
+
+    pub fn contains_zero(&self, x: &RealBallEl) -> bool { self.get_ring().contains_zero(x) }
+
+    pub fn overlaps(&self, lhs: &RealBallEl, rhs: &RealBallEl) -> bool { self.get_ring().overlaps(lhs, rhs) }
+
+    pub fn cmp_certified(&self, lhs: &RealBallEl, rhs: &RealBallEl) -> Option<Ordering> { self.get_ring().cmp_certified(lhs, rhs) }
+
+    pub fn certified_precision(&self, x: &RealBallEl) -> f64 { self.get_ring().certified_precision(x) }
+
+    pub fn abs(&self, x: RealBallEl) -> RealBallEl { self.get_ring().abs(x) }
+
+    pub fn sqrt(&self, x: RealBallEl) -> RealBallEl { self.get_ring().sqrt(x) }
+}
+
+impl RingBase for RealBall {
+
+    type Element = RealBallEl;
+
+    fn clone_el(&self, val: &Self::Element) -> Self::Element {
+        *val
+    }
+
+    fn add_assign(&self, lhs: &mut Self::Element, rhs: Self::Element) {
+        lhs.mid += rhs.mid;
+        lhs.rad = add_up(add_up(lhs.rad, rhs.rad), rounding_error(lhs.mid));
+    }
+
+    fn negate_inplace(&self, lhs: &mut Self::Element) {
+        lhs.mid = -lhs.mid;
+    }
+
+    fn mul_assign(&self, lhs: &mut Self::Element, rhs: Self::Element) {
+        // `|(a + x)(b + y) - ab| <= |a| r_b + |b| r_a + r_a r_b`
+        let rad = add_up(add_up(mul_up(lhs.mid.abs(), rhs.rad), mul_up(rhs.mid.abs(), lhs.rad)), mul_up(lhs.rad, rhs.rad));
+        lhs.mid *= rhs.mid;
+        lhs.rad = add_up(rad, rounding_error(lhs.mid));
+    }
+
+    fn from_int(&self, value: i32) -> Self::Element {
+        RealBallEl { mid: value as f64, rad: 0. }
+    }
+
+    fn eq_el(&self, _: &Self::Element, _: &Self::Element) -> bool {
+        panic!("Cannot provide equality on approximate rings")
+    }
+
+    fn is_commutative(&self) -> bool { true }
+
+    fn is_noetherian(&self) -> bool { true }
+
+    fn is_approximate(&self) -> bool { true }
+
+    fn dbg<'a>(&self, value: &Self::Element, out: &mut std::fmt::Formatter<'a>) -> std::fmt::Result {
+        write!(out, "{} +/- {}", value.mid, value.rad)
+    }
+}
+
+impl_eq_based_self_iso!{ RealBall }
+
+impl<I: ?Sized + IntegerRing> CanHomFrom<I> for RealBall {
+
+    type Homomorphism = ();
+
+    fn has_canonical_hom(&self, _: &I) -> Option<Self::Homomorphism> {
+        Some(())
+    }
+
+    fn map_in(&self, from: &I, el: I::Element, _: &Self::Homomorphism) -> Self::Element {
+        let mid = from.to_float_approx(&el);
+        let rad = if from.abs_highest_set_bit(&el).unwrap_or(0) < f64::MANTISSA_DIGITS as usize {
+            0.
+        } else {
+            // `to_float_approx()` may use only the most significant bits
+            mul_up(mid.abs(), 2. * f64::EPSILON)
+        };
+        RealBallEl { mid, rad }
+    }
+}
+
+impl DivisibilityRing for RealBall {
+
+    fn checked_left_div(&self, lhs: &Self::Element, rhs: &Self::Element) -> Option<Self::Element> {
+        if self.contains_zero(rhs) {
+            return None;
+        }
+        // `|(a + x)/(b + y) - a/b| <= (|a| r_b + |b| r_a) / (|b| (|b| - r_b))`
+        let numerator = add_up(mul_up(lhs.mid.abs(), rhs.rad), mul_up(rhs.mid.abs(), lhs.rad));
+        let denominator = (rhs.mid.abs() * (rhs.mid.abs() - rhs.rad).next_down()).next_down();
+        let mid = lhs.mid / rhs.mid;
+        Some(RealBallEl { mid, rad: add_up(div_up(numerator, denominator), rounding_error(mid)) })
+    }
+}
+
+impl PrincipalIdealRing for RealBall {
+
+    fn ideal_gen(&self, _lhs: &Self::Element, _rhs: &Self::Element) -> (Self::Element, Self::Element, Self::Element) {
+        panic!("Since RealBall is only approximate, this cannot be implemented properly")
+    }
+}
+
+impl EuclideanRing for RealBall {
+
+    fn euclidean_div_rem(&self, _lhs: Self::Element, _rhs: &Self::Element) -> (Self::Element, Self::Element) {
+        panic!("Since RealBall is only approximate, this cannot be implemented properly")
+    }
+
+    fn euclidean_deg(&self, _: &Self::Element) -> Option<usize> {
+        panic!("Since RealBall is only approximate, this cannot be implemented properly")
+    }
+}
+
+impl Field for RealBall {
+
+    fn div(&self, lhs: &Self::Element, rhs: &Self::Element) -> Self::Element {
+        self.checked_left_div(lhs, rhs).expect("Divisor ball contains zero")
+    }
+}
+
+///
+/// The ring of complex balls, i.e. disks `{ z in C | |z - m| <= r }`, which are used to
+/// approximate complex numbers in a rigorous way.
+///
+/// More concretely, the real and imaginary part of the midpoint `m` and the radius `r`
+/// are stored as `f64`, and all operations round the radius outwards, such that the result
+/// of an operation is guaranteed to contain the result of the operation when applied to any
+/// elements of the input balls. The only exception are the transcendental functions
+/// like [`ComplexBall::root_of_unity()`], where we have to assume that `sin()` and `cos()`
+/// of the standard library are accurate to within a few ulps.
+///
+/// In particular, computing a complex-valued FFT with this ring yields balls that are
+/// guaranteed to contain the exact result.
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::homomorphism::*;
+/// # use feanor_math::rings::ball::*;
+/// let C = ComplexBall::RING;
+/// let zeta = C.root_of_unity(1, 7);
+/// let one = C.pow(zeta, 7);
+/// assert!(C.contains(&one, 1., 0.));
+/// assert!(C.certified_precision(&one) > 40.);
+/// ```
+///
+#[derive(Clone, Copy, PartialEq)]
+pub struct ComplexBall;
+
+#[derive(Clone, Copy, Debug)]
+pub struct ComplexBallEl {
+    re: f64,
+    im: f64,
+    rad: f64
+}
+
+impl ComplexBall {
+
+    pub const RING: RingValue<ComplexBall> = RingValue::from(ComplexBall);
+
+    pub const I: ComplexBallEl = ComplexBallEl { re: 0., im: 1., rad: 0. };
+
+    ///
+    /// Returns the ball with midpoint `re + im * i` and given radius.
+    ///
+    pub fn ball(&self, re: f64, im: f64, rad: f64) -> ComplexBallEl {
+        assert!(re.is_finite() && im.is_finite());
+        assert!(rad >= 0.);
+        ComplexBallEl { re, im, rad }
+    }
+
+    pub fn from_f64(&self, x: f64) -> ComplexBallEl {
+        self.ball(x, 0., 0.)
+    }
+
+    ///
+    /// Returns real and imaginary part of the midpoint of the ball.
+    ///
+    pub fn midpoint(&self, x: &ComplexBallEl) -> (f64, f64) {
+        (x.re, x.im)
+    }
+
+    pub fn radius(&self, x: &ComplexBallEl) -> f64 {
+        x.rad
+    }
+
+    pub fn contains(&self, x: &ComplexBallEl, re: f64, im: f64) -> bool {
+        complex_abs_down(x.re - re, x.im - im) <= x.rad
+    }
+
+    pub fn contains_zero(&self, x: &ComplexBallEl) -> bool {
+        self.contains(x, 0., 0.)
+    }
+
+    ///
+    /// Returns whether the balls have a nonempty intersection, i.e. whether they might
+    /// represent the same complex number.
+    ///
+    pub fn overlaps(&self, lhs: &ComplexBallEl, rhs: &ComplexBallEl) -> bool {
+        complex_abs_down(lhs.re - rhs.re, lhs.im - rhs.im) <= add_up(lhs.rad, rhs.rad)
+    }
+
+    ///
+    /// Returns the number of bits of the represented complex number that are certainly correct,
+    /// i.e. the value `-log2(r / |m|)` for the midpoint `m` and radius `r`.
+    ///
+    /// If the ball contains zero, we cannot certify any bits, thus this returns `0`. On the
+    /// other hand, if the ball is exact (i.e. has radius zero), this returns `infinity`.
+    ///
+    pub fn certified_precision(&self, x: &ComplexBallEl) -> f64 {
+        if x.rad == 0. {
+            f64::INFINITY
+        } else if self.contains_zero(x) {
+            0.
+        } else {
+            f64::max(0., -(x.rad / complex_abs_down(x.re, x.im)).log2())
+        }
+    }
+
+    pub fn conjugate(&self, x: ComplexBallEl) -> ComplexBallEl {
+        ComplexBallEl { re: x.re, im: -x.im, rad: x.rad }
+    }
+
+    pub fn abs(&self, x: &ComplexBallEl) -> RealBallEl {
+        let mid = x.re.hypot(x.im);
+        RealBallEl { mid, rad: add_up(x.rad, 4. * rounding_error(mid)) }
+    }
+
+    ///
+    /// Computes a ball containing the `n`-th root of unity `exp(2 pi i * i / n)`.
+    ///
+    pub fn root_of_unity(&self, i: i64, n: i64) -> ComplexBallEl {
+        assert!(n > 0);
+        assert!(n <= 1 << f64::MANTISSA_DIGITS);
+        let R = RealBall::RING;
+        // both `i mod n` and `n` are exactly representable, so the only errors come from
+        // the division, the approximation of `2 pi` and the final multiplication
+        let fraction = R.div(&R.from_f64(i.rem_euclid(n) as f64), &R.from_f64(n as f64));
+        let angle = R.mul(fraction, R.ball(2. * std::f64::consts::PI, TWO_PI_ERROR));
+        // `sin()` and `cos()` are 1-Lipschitz, so the error in the angle carries over
+        let component_error = add_up(angle.rad, SIN_COS_ERROR);
+        ComplexBallEl { re: angle.mid.cos(), im: angle.mid.sin(), rad: add_up(component_error, component_error) }
+    }
+}
+
+impl RingValue<ComplexBall> {
+
+    pub fn ball(&self, re: f64, im: f64, rad: f64) -> ComplexBallEl { self.get_ring().ball(re, im, rad) }
+
+    pub fn from_f64(&self, x: f64) -> ComplexBallEl { self.get_ring().from_f64(x) }
+
+    pub fn midpoint(&self, x: &ComplexBallEl) -> (f64, f64) { self.get_ring().midpoint(x) }
+
+    pub fn radius(&self, x: &ComplexBallEl) -> f64 { self.get_ring().radius(x) }
+
+    pub fn contains(&self, x: &ComplexBallEl, re: f64, im: f64) -> bool { self.get_ring().contains(x, re, im) }
+
+    pub fn contains_zero(&self, x: &ComplexBallEl) -> bool { self.get_ring().contains_zero(x) }
+
+    pub fn overlaps(&self, lhs: &ComplexBallEl, rhs: &ComplexBallEl) -> bool { self.get_ring().overlaps(lhs, rhs) }
+
+    pub fn certified_precision(&self, x: &ComplexBallEl) -> f64 { self.get_ring().certified_precision(x) }
+
+    pub fn conjugate(&self, x: ComplexBallEl) -> ComplexBallEl { self.get_ring().conjugate(x) }
+
+    pub fn abs(&self, x: &ComplexBallEl) -> RealBallEl { self.get_ring().abs(x) }
+
+    pub fn root_of_unity(&self, i: i64, n: i64) -> ComplexBallEl { self.get_ring().root_of_unity(i, n) }
+}
+
+impl RingBase for ComplexBall {
+
+    type Element = ComplexBallEl;
+
+    fn clone_el(&self, val: &Self::Element) -> Self::Element {
+        *val
+    }
+
+    fn add_assign(&self, lhs: &mut Self::Element, rhs: Self::Element) {
+        lhs.re += rhs.re;
+        lhs.im += rhs.im;
+        lhs.rad = add_up(add_up(lhs.rad, rhs.rad), add_up(rounding_error(lhs.re), rounding_error(lhs.im)));
+    }
+
+    fn negate_inplace(&self, lhs: &mut Self::Element) {
+        lhs.re = -lhs.re;
+        lhs.im = -lhs.im;
+    }
+
+    fn mul_assign(&self, lhs: &mut Self::Element, rhs: Self::Element) {
+        // `|(a + x)(b + y) - ab| <= |a| r_b + |b| r_a + r_a r_b`
+        let lhs_abs = complex_abs_up(lhs.re, lhs.im);
+        let rhs_abs = complex_abs_up(rhs.re, rhs.im);
+        let rad = add_up(add_up(mul_up(lhs_abs, rhs.rad), mul_up(rhs_abs, lhs.rad)), mul_up(lhs.rad, rhs.rad));
+        // each part of the midpoint is computed using two multiplications and one addition
+        let rounding = mul_up(mul_up(lhs_abs, rhs_abs), 4. * f64::EPSILON);
+        let new_im = lhs.re * rhs.im + lhs.im * rhs.re;
+        lhs.re = lhs.re * rhs.re - lhs.im * rhs.im;
+        lhs.im = new_im;
+        lhs.rad = add_up(add_up(rad, rounding), add_up(rounding_error(lhs.re), rounding_error(lhs.im)));
+    }
+
+    fn from_int(&self, value: i32) -> Self::Element {
+        ComplexBallEl { re: value as f64, im: 0., rad: 0. }
+    }
+
+    fn eq_el(&self, _: &Self::Element, _: &Self::Element) -> bool {
+        panic!("Cannot provide equality on approximate rings")
+    }
+
+    fn is_commutative(&self) -> bool { true }
+
+    fn is_noetherian(&self) -> bool { true }
+
+    fn is_approximate(&self) -> bool { true }
+
+    fn dbg<'a>(&self, value: &Self::Element, out: &mut std::fmt::Formatter<'a>) -> std::fmt::Result {
+        write!(out, "{} + {}i +/- {}", value.re, value.im, value.rad)
+    }
+}
+
+impl_eq_based_self_iso!{ ComplexBall }
+
+impl<I: ?Sized + IntegerRing> CanHomFrom<I> for ComplexBall {
+
+    type Homomorphism = ();
+
+    fn has_canonical_hom(&self, _: &I) -> Option<Self::Homomorphism> {
+        Some(())
+    }
+
+    fn map_in(&self, from: &I, el: I::Element, _: &Self::Homomorphism) -> Self::Element {
+        let RealBallEl { mid, rad } = RealBall.map_in(from, el, &());
+        ComplexBallEl { re: mid, im: 0., rad }
+    }
+}
+
+impl CanHomFrom<RealBall> for ComplexBall {
+
+    type Homomorphism = ();
+
+    fn has_canonical_hom(&self, _: &RealBall) -> Option<Self::Homomorphism> {
+        Some(())
+    }
+
+    fn map_in(&self, _: &RealBall, el: RealBallEl, _: &Self::Homomorphism) -> Self::Element {
+        ComplexBallEl { re: el.mid, im: 0., rad: el.rad }
+    }
+}
+
+impl DivisibilityRing for ComplexBall {
+
+    fn checked_left_div(&self, lhs: &Self::Element, rhs: &Self::Element) -> Option<Self::Element> {
+        if self.contains_zero(rhs) {
+            return None;
+        }
+        // `|1/(b + y) - 1/b| = |y| / (|b| |b + y|) <= r_b / (|b| (|b| - r_b))`
+        let rhs_abs_down = complex_abs_down(rhs.re, rhs.im);
+        let inv_rad = div_up(rhs.rad, (rhs_abs_down * (rhs_abs_down - rhs.rad).next_down()).next_down());
+        let abs_sqr = rhs.re * rhs.re + rhs.im * rhs.im;
+        let (inv_re, inv_im) = (rhs.re / abs_sqr, -rhs.im / abs_sqr);
+        // computing each part of the inverse requires four operations
+        let rounding = mul_up(complex_abs_up(inv_re, inv_im), 8. * f64::EPSILON);
+        let rhs_inv = ComplexBallEl { re: inv_re, im: inv_im, rad: add_up(inv_rad, rounding) };
+        return Some(self.mul_ref(lhs, &rhs_inv));
+    }
+}
+
+impl PrincipalIdealRing for ComplexBall {
+
+    fn ideal_gen(&self, _lhs: &Self::Element, _rhs: &Self::Element) -> (Self::Element, Self::Element, Self::Element) {
+        panic!("Since ComplexBall is only approximate, this cannot be implemented properly")
+    }
+}
+
+impl EuclideanRing for ComplexBall {
+
+    fn euclidean_div_rem(&self, _lhs: Self::Element, _rhs: &Self::Element) -> (Self::Element, Self::Element) {
+        panic!("Since ComplexBall is only approximate, this cannot be implemented properly")
+    }
+
+    fn euclidean_deg(&self, _: &Self::Element) -> Option<usize> {
+        panic!("Since ComplexBall is only approximate, this cannot be implemented properly")
+    }
+}
+
+impl Field for ComplexBall {
+
+    fn div(&self, lhs: &Self::Element, rhs: &Self::Element) -> Self::Element {
+        self.checked_left_div(lhs, rhs).expect("Divisor ball contains zero")
+    }
+}
+
+///
+/// The error bounds refer to the midpoints of the balls, which are computed as in 
+/// [`crate::rings::float_complex::Complex64`]. Hence, an [`crate::algorithms::fft::complex_fft::ErrorEstimate`]
+/// gives an a-priori bound on how far the midpoints of the result of an FFT are from the exact
+/// result, while the radii of the resulting balls give a rigorous a-posteriori bound.
+///
+impl ApproxComplexRing for ComplexBall {
+
+    fn relative_error(&self) -> f64 {
+        f64::EPSILON
+    }
+
+    fn root_of_unity_error(&self) -> f64 {
+        // the angle is in `[0, 2 pi)`, so by the analysis in `root_of_unity()` its error is at
+        // most `2 pi * EPSILON + TWO_PI_ERROR + 2 pi * EPSILON` (up to subnormal terms)
+        2. * (16. * f64::EPSILON + SIN_COS_ERROR)
+    }
+}
+
+#[cfg(test)]
+use crate::divisibility::DivisibilityRingStore;
+#[cfg(test)]
+use crate::algorithms::fft::*;
+#[cfg(test)]
+use crate::algorithms::fft::cooley_tuckey::FFTTableCooleyTuckey;
+#[cfg(test)]
+use crate::default_memory_provider;
+#[cfg(test)]
+use crate::mempool::*;
+#[cfg(test)]
+use crate::algorithms::fft::complex_fft::ErrorEstimate;
+#[cfg(test)]
+use crate::rings::float_mp::ComplexMP;
+#[cfg(test)]
+use crate::ordered::OrderedRingStore;
+
+#[test]
+fn test_real_ball_arithmetic() {
+    let R = RealBall::RING;
+    let third = R.div(&R.one(), &R.int_hom().map(3));
+    assert!(!R.contains(&third, 1. / 3. + 1e-15));
+    let x = R.sub(R.int_hom().mul_map(third, 3), R.one());
+    assert!(R.contains_zero(&x));
+    assert!(R.radius(&x) < 1e-15);
+
+    let mut current = R.from_f64(0.1);
+    for _ in 0..100 {
+        current = R.mul(R.add(current, R.from_f64(0.1)), R.from_f64(1.01));
+    }
+    assert!(R.certified_precision(&current) > 40.);
+    assert_eq!(Some(Ordering::Less), R.cmp_certified(&R.from_f64(1.), &current));
+    assert_eq!(None, R.cmp_certified(&R.ball(1., 0.5), &R.ball(2., 0.5)));
+
+    let sqrt_2 = R.sqrt(R.int_hom().map(2));
+    assert!(R.contains(&sqrt_2, 2f64.sqrt()));
+    assert!(R.contains_zero(&R.sub(R.mul_ref(&sqrt_2, &sqrt_2), R.int_hom().map(2))));
+    assert!(R.contains(&R.sqrt(R.ball(1., 2.)), 0.));
+    assert!(R.contains(&R.sqrt(R.ball(1., 2.)), 3f64.sqrt()));
+}
+
+#[test]
+fn test_complex_ball_arithmetic() {
+    let C = ComplexBall::RING;
+    let i = ComplexBall::I;
+    assert!(C.contains(&C.mul(i, i), -1., 0.));
+    let z = C.ball(3., -4., 1e-10);
+    let z_inv = C.div(&C.one(), &z);
+    assert!(C.contains(&z_inv, 3. / 25., 4. / 25.));
+    assert!(C.contains(&C.mul(z_inv, z), 1., 0.));
+    assert!(C.certified_precision(&z_inv) > 25.);
+    assert!(C.certified_precision(&z_inv) < 40.);
+    assert!(C.overlaps(&C.ball(0., 0., 1.), &C.ball(1., 1., 0.5)));
+    assert!(!C.overlaps(&C.ball(0., 0., 1.), &C.ball(1., 1., 0.4)));
+    assert!(C.checked_left_div(&C.one(), &C.ball(0.5, 0., 1.)).is_none());
+}
+
+#[test]
+fn test_certified_fft() {
+    let C = ComplexBall::RING;
+    let log2_n = 6;
+    let n = 1 << log2_n;
+    let fft = FFTTableCooleyTuckey::for_complex_ball(C, log2_n);
+    let mut values = default_memory_provider!().get_new_init(n as usize, |i| C.int_hom().map(i as i32 % 3));
+    fft.fft(&mut values, &default_memory_provider!(), &C.identity());
+    // the a-posteriori error bound given by the radii should not be worse than the a-priori estimate
+    let expected_error = fft.expected_absolute_error(2., 0.);
+    for i in 0..(n as usize) {
+        assert!(C.radius(&values[i]) <= expected_error);
+    }
+    fft.inv_fft(&mut values, &default_memory_provider!(), &C.identity());
+    for i in 0..(n as usize) {
+        assert!(C.contains(&values[i], (i % 3) as f64, 0.));
+        assert!(C.certified_precision(&values[i]) > 30. || i % 3 == 0);
+    }
+}
+
+#[test]
+fn test_root_of_unity() {
+    let C = ComplexBall::RING;
+    let CC = ComplexMP::new(200);
+    let RR = CC.real_part_ring();
+    for n in [1, 2, 3, 7, 12, 64, 1000, 1 << 20] {
+        for i in [0, 1, 2, 5, n / 3, n / 2, n - 1, -1, 3 * n + 1] {
+            let zeta = C.root_of_unity(i, n);
+            assert!(C.radius(&zeta) <= C.get_ring().root_of_unity_error());
+            let (re, im) = C.midpoint(&zeta);
+            let error = CC.abs(&CC.sub(CC.root_of_unity(i, n), CC.from_parts(RR.from_f64(re), RR.from_f64(im))));
+            assert!(RR.is_leq(&error, &RR.from_f64(C.radius(&zeta))));
+        }
+    }
+}
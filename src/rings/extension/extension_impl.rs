@@ -269,6 +269,11 @@ impl<R, V, M> FreeAlgebra for FreeAlgebraImplBase<R, V, M>
     }
 }
 
+///
+/// Canonical homomorphisms between free algebras of the same rank, defined by the same polynomial (up to a
+/// canonical homomorphism between the base rings). Homomorphisms from a lower level of a tower of free algebras
+/// into a higher one are not provided, see [`super::TowerInclusion`] instead.
+///
 impl<R1, V1, M1, R2, V2, M2> CanHomFrom<FreeAlgebraImplBase<R1, V1, M1>> for FreeAlgebraImplBase<R2, V2, M2>
     where R1: RingStore, V1: VectorView<El<R1>>, M1: MemoryProvider<El<R1>>,
         R2: RingStore, V2: VectorView<El<R2>>, M2: MemoryProvider<El<R2>>,
@@ -0,0 +1,573 @@
+use crate::algorithms;
+use crate::algorithms::resultant::{ResultantHint, resultant_over_field};
+use crate::default_memory_provider;
+use crate::delegate::DelegateRing;
+use crate::divisibility::DivisibilityRing;
+use crate::field::{Field, FieldStore};
+use crate::integer::*;
+use crate::mempool::DefaultMemoryProvider;
+use crate::pid::*;
+use crate::primitive_int::*;
+use crate::ring::*;
+use crate::homomorphism::*;
+use crate::rings::extension::extension_impl::*;
+use crate::rings::field::AsField;
+use crate::rings::finite::*;
+use crate::rings::poly::{PolyRing, PolyRingStore};
+use crate::rings::poly::dense_poly::DensePolyRing;
+use crate::rings::zn::{ZnRingStore, zn_64};
+use crate::vector::VectorView;
+use crate::vector::vec_fn::VectorFn;
+use crate::mempool::MemoryProvider;
+
+use super::*;
+
+use oorandom;
+
+type PrimeField = AsField<zn_64::Zn>;
+type GaloisFieldImpl = FreeAlgebraImplBase<PrimeField, Vec<El<PrimeField>>, DefaultMemoryProvider>;
+
+///
+/// The finite field `GF(p^k)` with `p^k` elements, represented as `Fp[X]/(f(X))` for
+/// a primitive polynomial `f` of degree `k`. Elements are stored w.r.t. the power basis
+/// `1, θ, ..., θ^(k - 1)`, where `θ` is the image of `X`, as by [`FreeAlgebraImpl`].
+///
+/// Since `f` is chosen to be primitive, the canonical generator `θ` generates the multiplicative
+/// group of the field. The polynomial `f` is found by a deterministic search, so constructing
+/// the same field twice will give the same representation (but not necessarily the one given
+/// by the Conway polynomial). The characteristic `p` must be a prime that fits into [`zn_64::Zn`].
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::homomorphism::*;
+/// # use feanor_math::rings::extension::*;
+/// # use feanor_math::rings::extension::galois_field::*;
+/// # use feanor_math::algorithms::discrete_log::finite_field_log;
+/// # use feanor_math::assert_el_eq;
+/// let F = GaloisField::new(3, 4);
+/// let Fp = F.base_ring();
+/// let x = F.canonical_gen();
+/// assert_el_eq!(&F, &F.pow(F.clone_el(&x), 3), &F.frobenius(&x, 1));
+/// assert_el_eq!(Fp, &Fp.int_hom().map(1), &F.trace(&F.one()));
+/// assert_eq!(Some(37), finite_field_log(F.pow(F.clone_el(&x), 37), x, &F));
+/// ```
+///
+pub struct GaloisFieldBase {
+    base: RingValue<GaloisFieldImpl>,
+    characteristic: u64
+}
+
+///
+/// The finite field `GF(p^k)`. For details, see [`GaloisFieldBase`].
+///
+pub type GaloisField = RingValue<GaloisFieldBase>;
+
+impl GaloisField {
+
+    pub fn new(p: u64, k: usize) -> Self {
+        RingValue::from(GaloisFieldBase::new(p, k))
+    }
+
+    pub fn characteristic(&self) -> u64 { self.get_ring().characteristic() }
+
+    pub fn degree(&self) -> usize { self.get_ring().degree() }
+
+    pub fn frobenius(&self, el: &El<Self>, power: usize) -> El<Self> { self.get_ring().frobenius(el, power) }
+
+    pub fn trace(&self, el: &El<Self>) -> El<PrimeField> { self.get_ring().trace(el) }
+
+    pub fn norm(&self, el: &El<Self>) -> El<PrimeField> { self.get_ring().norm(el) }
+}
+
+impl GaloisFieldBase {
+
+    pub fn new(p: u64, k: usize) -> Self {
+        assert!(k >= 1);
+        let Fp = zn_64::Zn::new(p).as_field().ok().expect("the characteristic of a Galois field must be prime");
+        let FpX = DensePolyRing::new(Fp, "X");
+        let mut rng = oorandom::Rand64::new(((p as u128) << 64) | k as u128);
+        let f = algorithms::cantor_zassenhaus::random_primitive_polynomial(&FpX, k, || rng.rand_u64());
+        let x_pow_rank = (0..k).map(|i| Fp.negate(Fp.clone_el(FpX.coefficient_at(&f, i)))).collect::<Vec<_>>();
+        GaloisFieldBase {
+            base: FreeAlgebraImpl::new(Fp, x_pow_rank, default_memory_provider!()),
+            characteristic: p
+        }
+    }
+
+    pub fn characteristic(&self) -> u64 {
+        self.characteristic
+    }
+
+    pub fn degree(&self) -> usize {
+        self.rank()
+    }
+
+    fn coefficients(&self, el: &<Self as RingBase>::Element) -> Vec<El<PrimeField>> {
+        let coeffs = self.wrt_canonical_basis(el);
+        (0..self.rank()).map(|i| coeffs.at(i)).collect()
+    }
+
+    ///
+    /// Returns the defining polynomial `f` of this field, as element of the given polynomial ring.
+    ///
+    fn defining_poly<P>(&self, poly_ring: P) -> El<P>
+        where P: PolyRingStore,
+            P::Type: PolyRing,
+            <P::Type as RingExtension>::BaseRing: RingStore<Type = <PrimeField as RingStore>::Type>
+    {
+        let Fp = self.base_ring();
+        let x_pow_rank = self.coefficients(&RingRef::new(self).pow(self.canonical_gen(), self.rank()));
+        poly_ring.from_terms(x_pow_rank.into_iter().enumerate().map(|(i, c)| (Fp.negate(c), i)).chain(Some((Fp.one(), self.rank()))))
+    }
+
+    ///
+    /// Computes the image of `el` under the `power`-th power of the Frobenius automorphism,
+    /// i.e. `el^(p^power)`.
+    ///
+    pub fn frobenius(&self, el: &<Self as RingBase>::Element, power: usize) -> <Self as RingBase>::Element {
+        let mut result = self.clone_el(el);
+        for _ in 0..(power % self.rank()) {
+            result = RingRef::new(self).pow(result, self.characteristic as usize);
+        }
+        return result;
+    }
+
+    ///
+    /// Computes the trace of `el` over the prime field, i.e. the sum of all its Galois conjugates.
+    ///
+    pub fn trace(&self, el: &<Self as RingBase>::Element) -> El<PrimeField> {
+        let mut current = self.clone_el(el);
+        let mut result = self.clone_el(el);
+        for _ in 1..self.rank() {
+            current = self.frobenius(&current, 1);
+            self.add_assign_ref(&mut result, &current);
+        }
+        return self.wrt_canonical_basis(&result).at(0);
+    }
+
+    ///
+    /// Computes the norm of `el` over the prime field, i.e. the product of all its Galois conjugates.
+    ///
+    pub fn norm(&self, el: &<Self as RingBase>::Element) -> El<PrimeField> {
+        let mut current = self.clone_el(el);
+        let mut result = self.clone_el(el);
+        for _ in 1..self.rank() {
+            current = self.frobenius(&current, 1);
+            self.mul_assign_ref(&mut result, &current);
+        }
+        return self.wrt_canonical_basis(&result).at(0);
+    }
+}
+
+impl PartialEq for GaloisFieldBase {
+
+    fn eq(&self, other: &Self) -> bool {
+        self.base.get_ring() == other.base.get_ring()
+    }
+}
+
+impl DelegateRing for GaloisFieldBase {
+
+    type Element = <GaloisFieldImpl as RingBase>::Element;
+    type Base = GaloisFieldImpl;
+
+    fn get_delegate(&self) -> &Self::Base {
+        self.base.get_ring()
+    }
+
+    fn delegate(&self, el: Self::Element) -> <Self::Base as RingBase>::Element {
+        el
+    }
+
+    fn delegate_mut<'a>(&self, el: &'a mut Self::Element) -> &'a mut <Self::Base as RingBase>::Element {
+        el
+    }
+
+    fn delegate_ref<'a>(&self, el: &'a Self::Element) -> &'a <Self::Base as RingBase>::Element {
+        el
+    }
+
+    fn rev_delegate(&self, el: <Self::Base as RingBase>::Element) -> Self::Element {
+        el
+    }
+}
+
+impl RingExtension for GaloisFieldBase {
+
+    type BaseRing = PrimeField;
+
+    fn base_ring<'a>(&'a self) -> &'a Self::BaseRing {
+        self.base.base_ring()
+    }
+
+    fn from(&self, x: El<Self::BaseRing>) -> Self::Element {
+        self.base.get_ring().from(x)
+    }
+
+    fn mul_assign_base(&self, lhs: &mut Self::Element, rhs: &El<Self::BaseRing>) {
+        self.base.get_ring().mul_assign_base(lhs, rhs)
+    }
+}
+
+impl FreeAlgebra for GaloisFieldBase {
+
+    type VectorRepresentation<'a> = <GaloisFieldImpl as FreeAlgebra>::VectorRepresentation<'a>
+        where Self: 'a;
+
+    fn canonical_gen(&self) -> Self::Element {
+        self.base.get_ring().canonical_gen()
+    }
+
+    fn wrt_canonical_basis<'a>(&'a self, el: &'a Self::Element) -> Self::VectorRepresentation<'a> {
+        self.base.get_ring().wrt_canonical_basis(el)
+    }
+
+    fn rank(&self) -> usize {
+        self.base.get_ring().rank()
+    }
+}
+
+pub struct GaloisFieldElementsIter<'a> {
+    ring: &'a GaloisFieldBase,
+    current: Option<Vec<El<PrimeField>>>
+}
+
+impl<'a> Iterator for GaloisFieldElementsIter<'a> {
+
+    type Item = <GaloisFieldBase as RingBase>::Element;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Fp = self.ring.base_ring();
+        let current = self.current.as_mut()?;
+        let result = self.ring.from_canonical_basis(current.iter().map(|c| Fp.clone_el(c)));
+        let mut i = 0;
+        loop {
+            if i == current.len() {
+                self.current = None;
+                break;
+            }
+            Fp.add_assign(&mut current[i], Fp.one());
+            if !Fp.is_zero(&current[i]) {
+                break;
+            }
+            i += 1;
+        }
+        return Some(result);
+    }
+}
+
+impl FiniteRing for GaloisFieldBase {
+
+    type ElementsIter<'a> = GaloisFieldElementsIter<'a>;
+
+    fn elements<'a>(&'a self) -> Self::ElementsIter<'a> {
+        GaloisFieldElementsIter {
+            ring: self,
+            current: Some((0..self.rank()).map(|_| self.base_ring().zero()).collect())
+        }
+    }
+
+    fn random_element<G: FnMut() -> u64>(&self, mut rng: G) -> <Self as RingBase>::Element {
+        self.from_canonical_basis((0..self.rank()).map(|_| self.base_ring().random_element(&mut rng)))
+    }
+
+    fn size<I: IntegerRingStore>(&self, ZZ: &I) -> El<I>
+        where I::Type: IntegerRing
+    {
+        ZZ.pow(int_cast(self.characteristic as i64, ZZ, &StaticRing::<i64>::RING), self.rank())
+    }
+}
+
+impl HashableElRing for GaloisFieldBase {
+
+    fn hash<H: std::hash::Hasher>(&self, el: &Self::Element, h: &mut H) {
+        for c in self.coefficients(el) {
+            self.base_ring().hash(&c, h);
+        }
+    }
+}
+
+impl CanHomFrom<Self> for GaloisFieldBase {
+
+    type Homomorphism = ();
+
+    fn has_canonical_hom(&self, from: &Self) -> Option<()> {
+        if self == from {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn map_in(&self, _from: &Self, el: Self::Element, _: &()) -> Self::Element {
+        el
+    }
+}
+
+impl CanonicalIso<Self> for GaloisFieldBase {
+
+    type Isomorphism = ();
+
+    fn has_canonical_iso(&self, from: &Self) -> Option<()> {
+        if self == from {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn map_out(&self, _from: &Self, el: Self::Element, _: &()) -> Self::Element {
+        el
+    }
+}
+
+impl<J: ?Sized + IntegerRing> CanHomFrom<J> for GaloisFieldBase
+    where <PrimeField as RingStore>::Type: CanHomFrom<J>
+{
+    type Homomorphism = <<PrimeField as RingStore>::Type as CanHomFrom<J>>::Homomorphism;
+
+    fn has_canonical_hom(&self, from: &J) -> Option<Self::Homomorphism> {
+        self.base_ring().get_ring().has_canonical_hom(from)
+    }
+
+    fn map_in(&self, from: &J, el: J::Element, hom: &Self::Homomorphism) -> Self::Element {
+        self.from(self.base_ring().get_ring().map_in(from, el, hom))
+    }
+}
+
+impl DivisibilityRing for GaloisFieldBase {
+
+    fn checked_left_div(&self, lhs: &Self::Element, rhs: &Self::Element) -> Option<Self::Element> {
+        if self.is_zero(rhs) {
+            if self.is_zero(lhs) {
+                return Some(self.zero());
+            } else {
+                return None;
+            }
+        }
+        // compute the inverse of `rhs` using the extended euclidean algorithm in `Fp[X]`
+        let Fp = self.base_ring();
+        let FpX = DensePolyRing::new(Fp, "X");
+        let rhs_poly = FpX.from_terms(self.coefficients(rhs).into_iter().enumerate().map(|(i, c)| (c, i)));
+        let (s, _, d) = algorithms::eea::eea(rhs_poly, self.defining_poly(&FpX), &FpX);
+        assert_eq!(Some(0), FpX.degree(&d));
+        let d_inv = Fp.div(&Fp.one(), FpX.coefficient_at(&d, 0));
+        let rhs_inv = self.from_canonical_basis((0..self.rank()).map(|i| Fp.mul_ref(FpX.coefficient_at(&s, i), &d_inv)));
+        return Some(self.mul_ref_fst(lhs, rhs_inv));
+    }
+}
+
+impl PrincipalIdealRing for GaloisFieldBase {
+
+    fn ideal_gen(&self, lhs: &Self::Element, rhs: &Self::Element) -> (Self::Element, Self::Element, Self::Element) {
+        if !self.is_zero(lhs) {
+            (self.checked_left_div(&self.one(), lhs).unwrap(), self.zero(), self.one())
+        } else if !self.is_zero(rhs) {
+            (self.zero(), self.checked_left_div(&self.one(), rhs).unwrap(), self.one())
+        } else {
+            (self.zero(), self.zero(), self.zero())
+        }
+    }
+}
+
+impl EuclideanRing for GaloisFieldBase {
+
+    fn euclidean_div_rem(&self, lhs: Self::Element, rhs: &Self::Element) -> (Self::Element, Self::Element) {
+        assert!(!self.is_zero(rhs));
+        (self.checked_left_div(&lhs, rhs).unwrap(), self.zero())
+    }
+
+    fn euclidean_deg(&self, val: &Self::Element) -> Option<usize> {
+        if self.is_zero(val) {
+            Some(0)
+        } else {
+            Some(1)
+        }
+    }
+}
+
+impl Field for GaloisFieldBase {}
+
+impl ResultantHint for GaloisFieldBase {
+    fn compute_resultant<P>(&self, poly_ring: P, f: &El<P>, g: &El<P>) -> Self::Element
+        where P: PolyRingStore,
+            P::Type: PolyRing,
+            <P::Type as RingExtension>::BaseRing: RingStore<Type = Self>
+    {
+        resultant_over_field(poly_ring, f, g)
+    }
+}
+
+impl<R, V, M> CanHomFrom<GaloisFieldBase> for FreeAlgebraImplBase<R, V, M>
+    where R: RingStore, R::Type: CanHomFrom<GaloisFieldBase>, V: VectorView<El<R>>, M: MemoryProvider<El<R>>
+{
+    type Homomorphism = <R::Type as CanHomFrom<GaloisFieldBase>>::Homomorphism;
+
+    fn has_canonical_hom(&self, from: &GaloisFieldBase) -> Option<Self::Homomorphism> {
+        self.base_ring().get_ring().has_canonical_hom(from)
+    }
+
+    fn map_in(&self, from: &GaloisFieldBase, el: <GaloisFieldBase as RingBase>::Element, hom: &Self::Homomorphism) -> Self::Element {
+        self.from(self.base_ring().get_ring().map_in(from, el, hom))
+    }
+}
+
+///
+/// Finds an embedding `GF(p^a) -> GF(p^b)`, which exists if and only if both fields have the same
+/// characteristic and `a | b`. Since `GF(p^b)` is Galois over `GF(p)`, there are exactly `a` such
+/// embeddings, and this function returns one of them, by mapping the canonical generator of `from`
+/// to a root of its minimal polynomial in `to`. 
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::homomorphism::*;
+/// # use feanor_math::rings::extension::*;
+/// # use feanor_math::rings::extension::galois_field::*;
+/// # use feanor_math::assert_el_eq;
+/// let F4 = GaloisField::new(2, 2);
+/// let F16 = GaloisField::new(2, 4);
+/// let hom = find_embedding(&F4, &F16).unwrap();
+/// let x = F4.canonical_gen();
+/// assert_el_eq!(&F16, &F16.pow(hom.map_ref(&x), 3), &hom.map(F4.pow(x, 3)));
+/// assert!(find_embedding(&F16, &F4).is_none());
+/// ```
+///
+pub fn find_embedding<R, S>(from: R, to: S) -> Option<FreeAlgebraHom<R, S>>
+    where R: RingStore<Type = GaloisFieldBase>, S: RingStore<Type = GaloisFieldBase>
+{
+    if from.get_ring().characteristic() != to.get_ring().characteristic() || to.get_ring().degree() % from.get_ring().degree() != 0 {
+        return None;
+    }
+    let image_of_generator = {
+        let FX = DensePolyRing::new(&to, "X");
+        let base_hom = to.base_ring().can_hom(from.base_ring()).unwrap();
+        let n = from.rank();
+        let x_pow_rank = from.get_ring().coefficients(&from.pow(from.canonical_gen(), n));
+        let f = FX.from_terms(x_pow_rank.into_iter().enumerate()
+            .map(|(i, c)| (to.inclusion().map(to.base_ring().negate(base_hom.map(c))), i))
+            .chain(Some((to.one(), n)))
+        );
+        // `f` is irreducible of degree dividing `deg(to)`, so it splits into linear factors
+        algorithms::finite_field_roots::find_root(&FX, &f).unwrap()
+    };
+    return Some(FreeAlgebraHom::new(from, to, image_of_generator).unwrap());
+}
+
+#[cfg(test)]
+use crate::algorithms::discrete_log::finite_field_log;
+
+#[test]
+fn test_field_axioms() {
+    let F = GaloisField::new(3, 3);
+    assert_eq!(27, F.size(&StaticRing::<i64>::RING));
+    assert_eq!(27, F.elements().count());
+    crate::ring::generic_tests::test_ring_axioms(&F, F.elements());
+    crate::divisibility::generic_tests::test_divisibility_axioms(&F, F.elements());
+    for x in F.elements().filter(|x| !F.is_zero(x)) {
+        assert_el_eq!(&F, &F.one(), &F.mul_ref_fst(&x, F.div(&F.one(), &x)));
+    }
+}
+
+#[test]
+fn test_frobenius_trace_norm() {
+    for (p, k) in [(3, 4), (2, 8), (5, 1), (65537, 2)] {
+        let F = GaloisField::new(p, k);
+        let Fp = F.base_ring();
+        let x = F.canonical_gen();
+        let y = F.add(F.pow(F.clone_el(&x), 3), F.int_hom().map(2));
+        assert_el_eq!(&F, &y, &F.frobenius(&y, k));
+        assert_el_eq!(&F, &F.pow(F.clone_el(&y), p as usize), &F.frobenius(&y, 1));
+        // `Tr` is `Fp`-linear and `N` is multiplicative
+        assert_el_eq!(Fp, &Fp.add(F.trace(&x), F.trace(&y)), &F.trace(&F.add_ref(&x, &y)));
+        assert_el_eq!(Fp, &Fp.mul(F.norm(&x), F.norm(&y)), &F.norm(&F.mul_ref(&x, &y)));
+        assert_el_eq!(Fp, &Fp.int_hom().map(k as i32), &F.trace(&F.one()));
+        // the norm of `θ` is `(-1)^(k + 1) c_0`, where `θ^k = c_0 + c_1 θ + ...`
+        let f_at_zero = F.wrt_canonical_basis(&F.pow(F.clone_el(&x), k)).at(0);
+        assert_el_eq!(Fp, &Fp.mul(Fp.int_hom().map(if k % 2 == 0 { -1 } else { 1 }), f_at_zero), &F.norm(&x));
+    }
+}
+
+#[test]
+fn test_primitive_generator() {
+    let F = GaloisField::new(2, 8);
+    let x = F.canonical_gen();
+    let mut current = F.one();
+    for _ in 1..255 {
+        F.mul_assign_ref(&mut current, &x);
+        assert!(!F.is_one(&current));
+    }
+    F.mul_assign_ref(&mut current, &x);
+    assert!(F.is_one(&current));
+}
+
+#[test]
+fn test_finite_field_log() {
+    let F = GaloisField::new(3, 4);
+    let x = F.canonical_gen();
+    for e in [0, 1, 17, 79] {
+        assert_eq!(Some(e), finite_field_log(F.pow(F.clone_el(&x), e as usize), F.clone_el(&x), &F));
+    }
+    let y = F.add(F.clone_el(&x), F.one());
+    let log = finite_field_log(F.clone_el(&y), F.clone_el(&x), &F).unwrap();
+    assert_el_eq!(&F, &y, &F.pow(x, log as usize));
+}
+
+#[test]
+fn test_find_embedding() {
+    for (p, a, b) in [(3, 2, 4), (2, 2, 4), (2, 3, 6), (5, 1, 3), (7, 2, 2)] {
+        let from = GaloisField::new(p, a);
+        let to = GaloisField::new(p, b);
+        let hom = find_embedding(&from, &to).unwrap();
+        let x = from.canonical_gen();
+        let elements = [from.zero(), from.one(), from.clone_el(&x), from.add(from.pow(from.clone_el(&x), 2), from.int_hom().map(2)), from.neg_one()];
+        for a in &elements {
+            for b in &elements {
+                assert_el_eq!(&to, &to.add(hom.map_ref(a), hom.map_ref(b)), &hom.map(from.add_ref(a, b)));
+                assert_el_eq!(&to, &to.mul(hom.map_ref(a), hom.map_ref(b)), &hom.map(from.mul_ref(a, b)));
+            }
+        }
+    }
+    assert!(find_embedding(&GaloisField::new(2, 2), &GaloisField::new(2, 3)).is_none());
+    assert!(find_embedding(&GaloisField::new(2, 2), &GaloisField::new(3, 4)).is_none());
+}
+
+#[test]
+fn test_tower_can_hom() {
+    let K = GaloisField::new(3, 2);
+    let i = K.canonical_gen();
+    // `L = K[Y]/(Y^2 - θ)`, which is a field since `θ` generates `K*` and thus is not a square
+    let L = FreeAlgebraImpl::new(&K, [K.clone_el(&i), K.zero()], default_memory_provider!());
+    let hom = L.can_hom(&K).unwrap();
+    assert_el_eq!(&L, &L.pow(L.canonical_gen(), 2), &hom.map_ref(&i));
+    assert_el_eq!(&L, &L.inclusion().map(K.clone_el(&i)), &hom.map(i));
+
+    // canonical homomorphisms between free algebras have to preserve the canonical generator
+    let M = FreeAlgebraImpl::new(&K, [K.pow(K.canonical_gen(), 2), K.zero(), K.zero(), K.zero()], default_memory_provider!());
+    assert!(L.can_hom(&M).is_none());
+    assert!(M.can_hom(&L).is_none());
+}
+
+#[test]
+fn test_tower_inclusion() {
+    let Fp = zn_64::Zn::new(3);
+    let K = FreeAlgebraImpl::new(Fp, [Fp.neg_one(), Fp.zero()], default_memory_provider!());
+    let i = K.canonical_gen();
+    // `L = K[Y]/(Y^2 - (1 + i))`, which is a field since `1 + i` generates `K*`
+    let L = FreeAlgebraImpl::new(&K, [K.add(K.one(), K.clone_el(&i)), K.zero()], default_memory_provider!());
+    let hom = TowerInclusion::new(&K, &L).unwrap();
+    assert_el_eq!(&L, &L.pow(L.canonical_gen(), 2), &hom.map(K.add(K.one(), K.clone_el(&i))));
+    assert_el_eq!(&L, &L.neg_one(), &L.pow(hom.map_ref(&i), 2));
+    assert_el_eq!(&L, &L.inclusion().map(K.clone_el(&i)), &hom.map(i));
+
+    // `K2` is a different, but canonically isomorphic copy of `K`
+    let K2 = FreeAlgebraImpl::new(Fp, [Fp.neg_one(), Fp.zero()], default_memory_provider!());
+    let hom = TowerInclusion::new(&K2, &L).unwrap();
+    assert_el_eq!(&L, &L.pow(L.canonical_gen(), 4), &L.pow(hom.map(K2.add(K2.one(), K2.canonical_gen())), 2));
+    assert!(TowerInclusion::new(&FreeAlgebraImpl::new(Fp, [Fp.one(), Fp.zero()], default_memory_provider!()), &L).is_none());
+
+    // the prime field is included via `K`
+    let hom = TowerInclusion::new(&Fp, &K).unwrap();
+    assert_el_eq!(&K, &K.int_hom().map(2), &hom.map(Fp.int_hom().map(2)));
+}
@@ -0,0 +1,396 @@
+use crate::algorithms;
+use crate::algorithms::resultant::{ResultantHint, resultant_over_field};
+use crate::divisibility::DivisibilityRing;
+use crate::field::Field;
+use crate::integer::*;
+use crate::pid::*;
+use crate::ring::*;
+use crate::homomorphism::*;
+use crate::rings::finite::*;
+use crate::rings::poly::gf2_poly::*;
+use crate::rings::zn::zn_static;
+use crate::vector::vec_fn::VectorFn;
+
+use super::*;
+
+///
+/// The finite field `GF(2^n)` for `n <= 127`, with elements stored bit-packed in a `u128`.
+///
+/// The field is represented as `F2[X]/(f(X))`, where `f` is an irreducible trinomial
+/// `X^n + X^a + 1` or, if no such trinomial exists, an irreducible pentanomial
+/// `X^n + X^a + X^b + X^c + 1`. Among those, we choose the lexicographically smallest
+/// one, i.e. the one with smallest `a`, then smallest `b` and so on. In particular, the
+/// representation of `GF(2^8)` is the same as the one used by AES.
+///
+/// Multiplication uses carry-less multiplication of the packed elements, followed by a
+/// reduction modulo `f`, which is fast since `f` is sparse.
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::field::*;
+/// # use feanor_math::rings::extension::*;
+/// # use feanor_math::rings::extension::gf2n::*;
+/// let F = GF2n::new(8);
+/// assert_eq!(&[0, 1, 3, 4][..], F.modulus_exponents());
+/// // the AES S-box maps `0x53` to `0xED`, which uses the inverse `0x53^-1 = 0xCA`
+/// assert_eq!(0xCA, F.div(&F.one(), &0x53));
+/// ```
+///
+#[derive(Clone, Copy)]
+pub struct GF2nBase {
+    base_ring: zn_static::Fp<2>,
+    degree: usize,
+    // the exponents `e < n` of the nonzero terms of the modulus `f`, in ascending order;
+    // there are at most 4 such terms
+    modulus_exponents: [usize; 4],
+    modulus_len: usize
+}
+
+///
+/// The finite field `GF(2^n)`. For details, see [`GF2nBase`].
+///
+pub type GF2n = RingValue<GF2nBase>;
+
+impl GF2n {
+
+    pub fn new(degree: usize) -> Self {
+        RingValue::from(GF2nBase::new(degree))
+    }
+
+    pub fn modulus_exponents(&self) -> &[usize] { self.get_ring().modulus_exponents() }
+}
+
+impl GF2nBase {
+
+    pub fn new(degree: usize) -> Self {
+        assert!(degree >= 1 && degree <= 127);
+        let poly_ring = GF2PolyRing::new("X");
+        let is_irreducible = |exponents: &[usize]| {
+            let f = poly_ring.from_terms(exponents.iter().chain(Some(&degree)).map(|e| (1, *e)));
+            algorithms::cantor_zassenhaus::is_irreducible(&poly_ring, &f)
+        };
+        let create = |exponents: &[usize]| {
+            let mut modulus_exponents = [0; 4];
+            modulus_exponents[..exponents.len()].copy_from_slice(exponents);
+            GF2nBase {
+                base_ring: zn_static::Fp::<2>::RING,
+                degree: degree,
+                modulus_exponents: modulus_exponents,
+                modulus_len: exponents.len()
+            }
+        };
+        if degree == 1 {
+            return create(&[0]);
+        }
+        for a in 1..degree {
+            if is_irreducible(&[0, a]) {
+                return create(&[0, a]);
+            }
+        }
+        for a in 3..degree {
+            for b in 2..a {
+                for c in 1..b {
+                    if is_irreducible(&[0, c, b, a]) {
+                        return create(&[0, c, b, a]);
+                    }
+                }
+            }
+        }
+        unreachable!("there is an irreducible trinomial or pentanomial of degree {}", degree)
+    }
+
+    ///
+    /// Returns the exponents `e < n` of the nonzero terms of the modulus `f`, in ascending order.
+    ///
+    pub fn modulus_exponents(&self) -> &[usize] {
+        &self.modulus_exponents[..self.modulus_len]
+    }
+
+    fn mask(&self) -> u128 {
+        (1 << self.degree) - 1
+    }
+
+    ///
+    /// Reduces the polynomial `lo + X^128 hi` modulo `f`, assuming that its degree is `< 2n - 1`.
+    ///
+    fn reduce(&self, mut lo: u128, mut hi: u128) -> u128 {
+        loop {
+            let t = (lo >> self.degree) | (hi << (128 - self.degree));
+            if t == 0 {
+                return lo;
+            }
+            lo &= self.mask();
+            hi = 0;
+            for e in self.modulus_exponents() {
+                lo ^= t << e;
+                if *e != 0 {
+                    hi ^= t >> (128 - e);
+                }
+            }
+        }
+    }
+}
+
+///
+/// Computes the product of the two polynomials over `F2` whose coefficients are given by the
+/// bits of `lhs` and `rhs`, and returns it as pair of its lower and higher 128 bits.
+///
+fn carryless_mul_u128(lhs: u128, rhs: u128) -> (u128, u128) {
+    let (lhs0, lhs1) = (lhs as u64, (lhs >> 64) as u64);
+    let (rhs0, rhs1) = (rhs as u64, (rhs >> 64) as u64);
+    let low = carryless_mul(lhs0, rhs0);
+    let mid = carryless_mul(lhs0, rhs1) ^ carryless_mul(lhs1, rhs0);
+    let high = carryless_mul(lhs1, rhs1);
+    (low ^ (mid << 64), high ^ (mid >> 64))
+}
+
+impl PartialEq for GF2nBase {
+
+    fn eq(&self, other: &Self) -> bool {
+        self.degree == other.degree && self.modulus_exponents() == other.modulus_exponents()
+    }
+}
+
+impl RingBase for GF2nBase {
+
+    type Element = u128;
+
+    fn clone_el(&self, val: &Self::Element) -> Self::Element {
+        *val
+    }
+
+    fn add_assign(&self, lhs: &mut Self::Element, rhs: Self::Element) {
+        *lhs ^= rhs;
+    }
+
+    fn negate_inplace(&self, _: &mut Self::Element) {}
+
+    fn mul_assign(&self, lhs: &mut Self::Element, rhs: Self::Element) {
+        let (lo, hi) = carryless_mul_u128(*lhs, rhs);
+        *lhs = self.reduce(lo, hi);
+    }
+
+    fn from_int(&self, value: i32) -> Self::Element {
+        (value & 1) as u128
+    }
+
+    fn eq_el(&self, lhs: &Self::Element, rhs: &Self::Element) -> bool {
+        *lhs == *rhs
+    }
+
+    fn is_commutative(&self) -> bool { true }
+
+    fn is_noetherian(&self) -> bool { true }
+
+    fn dbg<'a>(&self, value: &Self::Element, out: &mut std::fmt::Formatter<'a>) -> std::fmt::Result {
+        let poly_ring = GF2PolyRing::new("θ");
+        poly_ring.get_ring().dbg(&poly_ring.get_ring().from_words(vec![*value as u64, (*value >> 64) as u64]), out)
+    }
+}
+
+impl_eq_based_self_iso!{ GF2nBase }
+
+impl<I: ?Sized + IntegerRing> CanHomFrom<I> for GF2nBase {
+
+    type Homomorphism = ();
+
+    fn has_canonical_hom(&self, _: &I) -> Option<()> {
+        Some(())
+    }
+
+    fn map_in(&self, from: &I, el: I::Element, _: &()) -> Self::Element {
+        from.abs_is_bit_set(&el, 0) as u128
+    }
+}
+
+impl RingExtension for GF2nBase {
+
+    type BaseRing = zn_static::Fp<2>;
+
+    fn base_ring<'a>(&'a self) -> &'a Self::BaseRing {
+        &self.base_ring
+    }
+
+    fn from(&self, x: El<Self::BaseRing>) -> Self::Element {
+        x as u128
+    }
+}
+
+pub struct GF2nCoefficients {
+    value: u128,
+    len: usize
+}
+
+impl VectorFn<u64> for GF2nCoefficients {
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn at(&self, i: usize) -> u64 {
+        assert!(i < self.len);
+        ((self.value >> i) & 1) as u64
+    }
+}
+
+impl FreeAlgebra for GF2nBase {
+
+    type VectorRepresentation<'a> = GF2nCoefficients;
+
+    fn canonical_gen(&self) -> Self::Element {
+        self.reduce(2, 0)
+    }
+
+    fn rank(&self) -> usize {
+        self.degree
+    }
+
+    fn wrt_canonical_basis<'a>(&'a self, el: &'a Self::Element) -> Self::VectorRepresentation<'a> {
+        GF2nCoefficients { value: *el, len: self.degree }
+    }
+
+    fn from_canonical_basis<V>(&self, vec: V) -> Self::Element
+        where V: ExactSizeIterator + DoubleEndedIterator + Iterator<Item = El<Self::BaseRing>>
+    {
+        assert_eq!(vec.len(), self.rank());
+        vec.enumerate().fold(0, |current, (i, c)| current | ((c as u128) << i))
+    }
+}
+
+impl FiniteRing for GF2nBase {
+
+    type ElementsIter<'a> = std::ops::Range<u128>;
+
+    fn elements<'a>(&'a self) -> Self::ElementsIter<'a> {
+        0..(1 << self.degree)
+    }
+
+    fn random_element<G: FnMut() -> u64>(&self, mut rng: G) -> <Self as RingBase>::Element {
+        (((rng() as u128) << 64) | rng() as u128) & self.mask()
+    }
+
+    fn size<I: IntegerRingStore>(&self, ZZ: &I) -> El<I>
+        where I::Type: IntegerRing
+    {
+        ZZ.pow(ZZ.int_hom().map(2), self.degree)
+    }
+}
+
+impl HashableElRing for GF2nBase {
+
+    fn hash<H: std::hash::Hasher>(&self, el: &Self::Element, h: &mut H) {
+        h.write_u128(*el)
+    }
+}
+
+impl DivisibilityRing for GF2nBase {
+
+    fn checked_left_div(&self, lhs: &Self::Element, rhs: &Self::Element) -> Option<Self::Element> {
+        if self.is_zero(rhs) {
+            if self.is_zero(lhs) {
+                return Some(self.zero());
+            } else {
+                return None;
+            }
+        }
+        // we have `rhs^-1 = rhs^(2^n - 2) = rhs^2 rhs^4 ... rhs^(2^(n - 1))`
+        let mut current = *rhs;
+        let mut result = *lhs;
+        for _ in 1..self.degree {
+            self.square(&mut current);
+            self.mul_assign(&mut result, current);
+        }
+        return Some(result);
+    }
+}
+
+impl PrincipalIdealRing for GF2nBase {
+
+    fn ideal_gen(&self, lhs: &Self::Element, rhs: &Self::Element) -> (Self::Element, Self::Element, Self::Element) {
+        if !self.is_zero(lhs) {
+            (self.checked_left_div(&self.one(), lhs).unwrap(), self.zero(), self.one())
+        } else if !self.is_zero(rhs) {
+            (self.zero(), self.checked_left_div(&self.one(), rhs).unwrap(), self.one())
+        } else {
+            (self.zero(), self.zero(), self.zero())
+        }
+    }
+}
+
+impl EuclideanRing for GF2nBase {
+
+    fn euclidean_div_rem(&self, lhs: Self::Element, rhs: &Self::Element) -> (Self::Element, Self::Element) {
+        assert!(!self.is_zero(rhs));
+        (self.checked_left_div(&lhs, rhs).unwrap(), self.zero())
+    }
+
+    fn euclidean_deg(&self, val: &Self::Element) -> Option<usize> {
+        if self.is_zero(val) {
+            Some(0)
+        } else {
+            Some(1)
+        }
+    }
+}
+
+impl Field for GF2nBase {}
+
+impl ResultantHint for GF2nBase {
+    fn compute_resultant<P>(&self, poly_ring: P, f: &El<P>, g: &El<P>) -> Self::Element
+        where P: PolyRingStore,
+            P::Type: PolyRing,
+            <P::Type as RingExtension>::BaseRing: RingStore<Type = Self>
+    {
+        resultant_over_field(poly_ring, f, g)
+    }
+}
+
+#[cfg(test)]
+use crate::primitive_int::StaticRing;
+#[cfg(test)]
+use crate::field::FieldStore;
+#[cfg(test)]
+use crate::rings::extension::extension_impl::FreeAlgebraImpl;
+#[cfg(test)]
+use crate::default_memory_provider;
+
+#[test]
+fn test_field_axioms() {
+    let F = GF2n::new(5);
+    crate::ring::generic_tests::test_ring_axioms(&F, F.elements());
+    crate::divisibility::generic_tests::test_divisibility_axioms(&F, F.elements());
+    for x in F.elements().filter(|x| !F.is_zero(x)) {
+        assert_el_eq!(&F, &F.one(), &F.mul(x, F.div(&F.one(), &x)));
+    }
+    let F = GF2n::new(127);
+    let elements = [0, 1, 2, u128::MAX >> 1, 1 << 126, 0x0123456789ABCDEF0123456789ABCDEF];
+    crate::ring::generic_tests::test_ring_axioms(&F, elements.into_iter());
+    crate::divisibility::generic_tests::test_divisibility_axioms(&F, elements.into_iter());
+}
+
+#[test]
+fn test_modulus() {
+    assert_eq!(&[0][..], GF2n::new(1).modulus_exponents());
+    assert_eq!(&[0, 1][..], GF2n::new(2).modulus_exponents());
+    assert_eq!(&[0, 1, 3, 4][..], GF2n::new(8).modulus_exponents());
+    assert_eq!(&[0, 1][..], GF2n::new(127).modulus_exponents());
+    for n in 1..128 {
+        assert!(GF2n::new(n).modulus_exponents().len() <= 4);
+    }
+}
+
+#[test]
+fn test_against_free_algebra() {
+    let F = GF2n::new(8);
+    let x_pow_rank = (0..8).map(|i| if F.modulus_exponents().contains(&i) { 1 } else { 0 }).collect::<Vec<u64>>();
+    let G = FreeAlgebraImpl::new(zn_static::Fp::<2>::RING, x_pow_rank, default_memory_provider!());
+    let to_G = |x: &u128| G.from_canonical_basis(F.wrt_canonical_basis(x).to_vec().into_iter());
+    for x in F.elements().step_by(7) {
+        for y in F.elements().step_by(11) {
+            assert_el_eq!(&G, &G.mul(to_G(&x), to_G(&y)), &to_G(&F.mul(x, y)));
+        }
+    }
+    assert_el_eq!(&G, &G.canonical_gen(), &to_G(&F.canonical_gen()));
+    assert_eq!(256, F.size(&StaticRing::<i64>::RING));
+}
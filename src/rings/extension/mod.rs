@@ -4,6 +4,9 @@ use crate::homomorphism::*;
 use super::poly::{PolyRingStore, PolyRing};
 
 pub mod extension_impl;
+pub mod number_field;
+pub mod galois_field;
+pub mod gf2n;
 
 ///
 /// A ring `R` that is an extension of a base ring `S`, generated by a single element
@@ -75,6 +78,181 @@ pub fn poly_repr<P: PolyRingStore, R: FreeAlgebraStore>(from: R, to: P, el: &El<
 
 }
 
+///
+/// A homomorphism `R -> S` from a free algebra `R` over a base ring `A` into an extension `S`
+/// of a ring `B`, which is uniquely determined by the canonical homomorphism `A -> B` and the
+/// image of the canonical generator of `R` in `S`.
+///
+/// Note that such homomorphisms usually do not preserve the canonical generator, hence they are
+/// not canonical homomorphisms. An example are embeddings between finite fields, see e.g.
+/// [`galois_field::find_embedding()`].
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::homomorphism::*;
+/// # use feanor_math::primitive_int::*;
+/// # use feanor_math::rings::extension::*;
+/// # use feanor_math::rings::extension::extension_impl::*;
+/// # use feanor_math::{default_memory_provider, assert_el_eq};
+/// let ZZ = StaticRing::<i64>::RING;
+/// // `Z[i]` and `Z[ζ_8]`, with the embedding `i -> ζ_8^2`
+/// let from = FreeAlgebraImpl::new(ZZ, [-1, 0], default_memory_provider!());
+/// let to = FreeAlgebraImpl::new(ZZ, [-1, 0, 0, 0], default_memory_provider!());
+/// let hom = FreeAlgebraHom::new(&from, &to, to.pow(to.canonical_gen(), 2)).unwrap();
+/// assert_el_eq!(&to, &to.neg_one(), &hom.map(from.pow(from.canonical_gen(), 2)));
+/// // there is no embedding with `i -> ζ_8`
+/// assert!(FreeAlgebraHom::new(&from, &to, to.canonical_gen()).is_none());
+/// ```
+///
+pub struct FreeAlgebraHom<R, S>
+    where R: RingStore, R::Type: FreeAlgebra,
+        S: RingStore, S::Type: RingExtension,
+        <<S::Type as RingExtension>::BaseRing as RingStore>::Type: CanHomFrom<<<R::Type as RingExtension>::BaseRing as RingStore>::Type>
+{
+    from: R,
+    to: S,
+    image_of_generator: El<S>,
+    base_hom: <<<S::Type as RingExtension>::BaseRing as RingStore>::Type as CanHomFrom<<<R::Type as RingExtension>::BaseRing as RingStore>::Type>>::Homomorphism
+}
+
+impl<R, S> FreeAlgebraHom<R, S>
+    where R: RingStore, R::Type: FreeAlgebra,
+        S: RingStore, S::Type: RingExtension,
+        <<S::Type as RingExtension>::BaseRing as RingStore>::Type: CanHomFrom<<<R::Type as RingExtension>::BaseRing as RingStore>::Type>
+{
+    ///
+    /// Creates the homomorphism `R -> S` that maps the canonical generator of `R` to the
+    /// given element. Returns `None` if there is no canonical homomorphism between the base rings
+    /// of `R` and `S`, or the given element does not satisfy the defining relation of `R`.
+    ///
+    pub fn new(from: R, to: S, image_of_generator: El<S>) -> Option<Self> {
+        let base_hom = to.base_ring().get_ring().has_canonical_hom(from.base_ring().get_ring())?;
+        let result = FreeAlgebraHom { from, to, image_of_generator, base_hom };
+        let x_pow_rank = result.from.pow(result.from.canonical_gen(), result.from.rank());
+        if result.to.eq_el(&result.to.pow(result.to.clone_el(&result.image_of_generator), result.from.rank()), &result.map(x_pow_rank)) {
+            return Some(result);
+        } else {
+            return None;
+        }
+    }
+
+    pub fn image_of_generator(&self) -> &El<S> {
+        &self.image_of_generator
+    }
+}
+
+impl<R, S> Homomorphism<R::Type, S::Type> for FreeAlgebraHom<R, S>
+    where R: RingStore, R::Type: FreeAlgebra,
+        S: RingStore, S::Type: RingExtension,
+        <<S::Type as RingExtension>::BaseRing as RingStore>::Type: CanHomFrom<<<R::Type as RingExtension>::BaseRing as RingStore>::Type>
+{
+    type DomainStore = R;
+    type CodomainStore = S;
+
+    fn domain(&self) -> &R {
+        &self.from
+    }
+
+    fn codomain(&self) -> &S {
+        &self.to
+    }
+
+    fn map(&self, x: El<R>) -> El<S> {
+        self.map_ref(&x)
+    }
+
+    fn map_ref(&self, x: &El<R>) -> El<S> {
+        let coefficients = self.from.wrt_canonical_basis(x);
+        let mut result = self.to.zero();
+        for i in (0..self.from.rank()).rev() {
+            self.to.mul_assign_ref(&mut result, &self.image_of_generator);
+            self.to.add_assign(&mut result, self.to.get_ring().from(self.to.base_ring().get_ring().map_in(self.from.base_ring().get_ring(), coefficients.at(i), &self.base_hom)));
+        }
+        return result;
+    }
+}
+
+///
+/// The inclusion `R -> S` of a ring `R` into a ring extension `S`, whose base ring `B` has a canonical
+/// homomorphism `R -> B`. In particular, this includes the case that `R` is (isomorphic to) `B`, and
+/// thus can be used to embed the lower levels of a tower of extensions into the higher ones, even if
+/// all levels are [`extension_impl::FreeAlgebraImpl`]s.
+/// 
+/// Note that this cannot be provided by [`CanHomFrom`], since a [`extension_impl::FreeAlgebraImpl`] only
+/// has canonical homomorphisms from other [`extension_impl::FreeAlgebraImpl`]s of the same rank. A generic
+/// [`CanHomFrom`] between [`extension_impl::FreeAlgebraImpl`]s whose base rings form a tower would overlap
+/// with that implementation, which is not possible with the current form of specialization. Hence, canonical
+/// homomorphisms between towers of [`extension_impl::FreeAlgebraImpl`]s are currently not supported, and
+/// computations with towers (e.g. composita) have to use this inclusion or a [`FreeAlgebraHom`] explicitly.
+/// The only exception are towers over a [`galois_field::GaloisField`], which can be included via [`CanHomFrom`].
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::homomorphism::*;
+/// # use feanor_math::rings::extension::*;
+/// # use feanor_math::rings::extension::extension_impl::*;
+/// # use feanor_math::rings::zn::zn_64::*;
+/// # use feanor_math::{default_memory_provider, assert_el_eq};
+/// let Fp = Zn::new(3);
+/// // `K = F3[i]` and `L = K[Y]/(Y^2 - i)`
+/// let K = FreeAlgebraImpl::new(Fp, [Fp.neg_one(), Fp.zero()], default_memory_provider!());
+/// let L = FreeAlgebraImpl::new(&K, [K.canonical_gen(), K.zero()], default_memory_provider!());
+/// let hom = TowerInclusion::new(&K, &L).unwrap();
+/// assert_el_eq!(&L, &L.pow(L.canonical_gen(), 2), &hom.map(K.canonical_gen()));
+/// ```
+///
+pub struct TowerInclusion<R, S>
+    where R: RingStore,
+        S: RingStore, S::Type: RingExtension,
+        <<S::Type as RingExtension>::BaseRing as RingStore>::Type: CanHomFrom<R::Type>
+{
+    from: R,
+    to: S,
+    base_hom: <<<S::Type as RingExtension>::BaseRing as RingStore>::Type as CanHomFrom<R::Type>>::Homomorphism
+}
+
+impl<R, S> TowerInclusion<R, S>
+    where R: RingStore,
+        S: RingStore, S::Type: RingExtension,
+        <<S::Type as RingExtension>::BaseRing as RingStore>::Type: CanHomFrom<R::Type>
+{
+    ///
+    /// Creates the inclusion `R -> S`, or returns `None` if there is no canonical homomorphism
+    /// from `R` to the base ring of `S`.
+    ///
+    pub fn new(from: R, to: S) -> Option<Self> {
+        let base_hom = to.base_ring().get_ring().has_canonical_hom(from.get_ring())?;
+        Some(TowerInclusion { from, to, base_hom })
+    }
+}
+
+impl<R, S> Homomorphism<R::Type, S::Type> for TowerInclusion<R, S>
+    where R: RingStore,
+        S: RingStore, S::Type: RingExtension,
+        <<S::Type as RingExtension>::BaseRing as RingStore>::Type: CanHomFrom<R::Type>
+{
+    type DomainStore = R;
+    type CodomainStore = S;
+
+    fn domain(&self) -> &R {
+        &self.from
+    }
+
+    fn codomain(&self) -> &S {
+        &self.to
+    }
+
+    fn map(&self, x: El<R>) -> El<S> {
+        self.to.get_ring().from(self.to.base_ring().get_ring().map_in(self.from.get_ring(), x, &self.base_hom))
+    }
+
+    fn map_ref(&self, x: &El<R>) -> El<S> {
+        self.to.get_ring().from(self.to.base_ring().get_ring().map_in_ref(self.from.get_ring(), x, &self.base_hom))
+    }
+}
+
 #[cfg(any(test, feature = "generic_tests"))]
 pub fn generic_test_free_algebra_axioms<R: FreeAlgebraStore>(ring: R)
     where R::Type: FreeAlgebra
@@ -0,0 +1,761 @@
+use crate::algorithms;
+use crate::algorithms::smith::{DenseMatrix, determinant, invert, kernel};
+use crate::default_memory_provider;
+use crate::delegate::DelegateRing;
+use crate::divisibility::DivisibilityRing;
+use crate::field::{Field, FieldStore};
+use crate::integer::*;
+use crate::matrix::Matrix;
+use crate::mempool::DefaultMemoryProvider;
+use crate::ordered::OrderedRingStore;
+use crate::pid::*;
+use crate::ring::*;
+use crate::homomorphism::*;
+use crate::rings::extension::extension_impl::*;
+use crate::rings::field::AsField;
+use crate::rings::float_complex::{Complex64, Complex64El};
+use crate::rings::poly::{PolyRing, PolyRingStore};
+use crate::rings::poly::dense_poly::DensePolyRing;
+use crate::rings::rational::*;
+use crate::rings::zn::{ZnRingStore, zn_barett};
+use crate::vector::vec_fn::VectorFn;
+
+use super::*;
+
+type NumberFieldImpl<I> = FreeAlgebraImplBase<RationalField<I>, Vec<RationalFieldEl<I>>, DefaultMemoryProvider>;
+
+///
+/// An algebraic number field `K = Q[X]/(f(X))`, where `f` is a monic irreducible polynomial with integer
+/// coefficients. Arithmetic is performed w.r.t. the power basis `1, θ, ..., θ^(n - 1)`, where `θ` is the
+/// image of `X` in `K`, as by [`FreeAlgebraImpl`]. Additionally, this provides number-theoretic functionality,
+/// like norms and traces of elements, embeddings into `C` and the computation of an integral basis of the
+/// ring of integers of `K`.
+///
+/// As for [`FreeAlgebraImpl`], the defining polynomial is given by the coefficients `c_i` in
+/// `θ^n = c_0 + c_1 θ + ... + c_(n - 1) θ^(n - 1)`. Irreducibility of the defining polynomial is not
+/// checked; using a reducible polynomial will give wrong results or panics.
+///
+/// Since computations in number fields quickly lead to large coefficients, it is usually best to use
+/// an arbitrary-precision integer ring like [`BigIntRing`].
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::homomorphism::*;
+/// # use feanor_math::field::*;
+/// # use feanor_math::primitive_int::*;
+/// # use feanor_math::rings::extension::*;
+/// # use feanor_math::rings::extension::number_field::*;
+/// # use feanor_math::rings::rational::*;
+/// # use feanor_math::assert_el_eq;
+/// // the field `Q(sqrt(5))`
+/// let K = NumberField::new(StaticRing::<i64>::RING, vec![5, 0]);
+/// let QQ = K.base_ring();
+/// let sqrt5 = K.canonical_gen();
+/// assert_el_eq!(QQ, &QQ.int_hom().map(-5), &K.norm(&sqrt5));
+/// let golden_ratio = K.div(&K.add_ref_fst(&K.one(), sqrt5), &K.int_hom().map(2));
+/// assert_el_eq!(QQ, &QQ.int_hom().map(-1), &K.norm(&golden_ratio));
+/// assert_eq!(5, K.field_discriminant());
+/// ```
+///
+pub struct NumberFieldBase<I: IntegerRingStore>
+    where I::Type: IntegerRing
+{
+    base: RingValue<NumberFieldImpl<I>>,
+    // the traces `Tr(θ^i)` for `i < n`
+    power_traces: Vec<El<I>>
+}
+
+///
+/// An algebraic number field. For details, see [`NumberFieldBase`].
+///
+pub type NumberField<I> = RingValue<NumberFieldBase<I>>;
+
+impl<I: IntegerRingStore> NumberField<I>
+    where I::Type: IntegerRing
+{
+    pub fn new(integer_ring: I, x_pow_rank: Vec<El<I>>) -> Self {
+        RingValue::from(NumberFieldBase::new(integer_ring, x_pow_rank))
+    }
+
+    pub fn trace(&self, el: &El<Self>) -> El<RationalField<I>> { self.get_ring().trace(el) }
+
+    pub fn norm(&self, el: &El<Self>) -> El<RationalField<I>> { self.get_ring().norm(el) }
+
+    pub fn charpoly<P>(&self, el: &El<Self>, poly_ring: P) -> El<P>
+        where P: PolyRingStore,
+            P::Type: PolyRing,
+            <<P::Type as RingExtension>::BaseRing as RingStore>::Type: CanHomFrom<RationalFieldBase<I>>
+    {
+        self.get_ring().charpoly(el, poly_ring)
+    }
+
+    pub fn minpoly<P>(&self, el: &El<Self>, poly_ring: P) -> El<P>
+        where P: PolyRingStore,
+            P::Type: PolyRing,
+            <<P::Type as RingExtension>::BaseRing as RingStore>::Type: CanHomFrom<RationalFieldBase<I>>
+    {
+        self.get_ring().minpoly(el, poly_ring)
+    }
+
+    pub fn discriminant(&self) -> El<I> { self.get_ring().discriminant() }
+
+    pub fn complex_embeddings(&self) -> Vec<Complex64El> { self.get_ring().complex_embeddings() }
+
+    pub fn embed(&self, el: &El<Self>, root: Complex64El) -> Complex64El { self.get_ring().embed(el, root) }
+
+    pub fn signature(&self) -> (usize, usize) { self.get_ring().signature() }
+
+    pub fn integral_basis(&self) -> Vec<El<Self>> { self.get_ring().integral_basis() }
+
+    pub fn field_discriminant(&self) -> El<I> { self.get_ring().field_discriminant() }
+}
+
+impl<I: IntegerRingStore> NumberFieldBase<I>
+    where I::Type: IntegerRing
+{
+    pub fn new(integer_ring: I, x_pow_rank: Vec<El<I>>) -> Self {
+        let n = x_pow_rank.len();
+        assert!(n >= 1);
+        // Newton's identities for the power sums `s_k` of the roots of `X^n - c_(n - 1) X^(n - 1) - ... - c_0`
+        let mut power_traces: Vec<El<I>> = Vec::with_capacity(n);
+        power_traces.push(integer_ring.int_hom().map(n as i32));
+        for k in 1..n {
+            let mut s_k = integer_ring.int_hom().mul_ref_map(&x_pow_rank[n - k], &(k as i32));
+            for i in 1..k {
+                integer_ring.add_assign(&mut s_k, integer_ring.mul_ref(&x_pow_rank[n - i], &power_traces[k - i]));
+            }
+            power_traces.push(s_k);
+        }
+        let QQ = RationalField::new(integer_ring);
+        let x_pow_rank = x_pow_rank.into_iter().map(|c| QQ.inclusion().map(c)).collect::<Vec<_>>();
+        NumberFieldBase {
+            base: FreeAlgebraImpl::new(QQ, x_pow_rank, default_memory_provider!()),
+            power_traces: power_traces
+        }
+    }
+
+    fn integer_ring(&self) -> &I {
+        self.base_ring().base_ring()
+    }
+
+    fn coefficients(&self, el: &<Self as RingBase>::Element) -> Vec<El<RationalField<I>>> {
+        let vec = self.wrt_canonical_basis(el);
+        (0..self.rank()).map(|i| vec.at(i)).collect()
+    }
+
+    fn as_integer(&self, x: &El<RationalField<I>>) -> El<I> {
+        assert!(self.integer_ring().is_one(self.base_ring().den(x)));
+        self.integer_ring().clone_el(self.base_ring().num(x))
+    }
+
+    ///
+    /// Returns the defining polynomial `f` of this number field as element of
+    /// the given polynomial ring.
+    ///
+    fn defining_poly<P>(&self, poly_ring: P) -> El<P>
+        where P: PolyRingStore,
+            P::Type: PolyRing,
+            <P::Type as RingExtension>::BaseRing: RingStore<Type = RationalFieldBase<I>>
+    {
+        let QQ = self.base_ring();
+        let x_pow_rank = self.coefficients(&RingRef::new(self).pow(self.canonical_gen(), self.rank()));
+        poly_ring.from_terms(x_pow_rank.into_iter().enumerate().map(|(i, c)| (QQ.negate(c), i)).chain(Some((QQ.one(), self.rank()))))
+    }
+
+    ///
+    /// Computes the trace of the `Q`-linear map `K -> K, x -> el * x`.
+    ///
+    pub fn trace(&self, el: &<Self as RingBase>::Element) -> El<RationalField<I>> {
+        let QQ = self.base_ring();
+        QQ.sum(self.coefficients(el).into_iter().zip(self.power_traces.iter()).map(|(c, s)| QQ.mul(c, QQ.inclusion().map_ref(s))))
+    }
+
+    ///
+    /// Returns the coefficients of the characteristic polynomial of `el`, in
+    /// ascending order of degree.
+    ///
+    fn charpoly_coefficients(&self, el: &<Self as RingBase>::Element) -> Vec<El<RationalField<I>>> {
+        let QQ = self.base_ring();
+        let n = self.rank();
+        let mut power = self.one();
+        let power_sums = (1..=n).map(|_| {
+            self.mul_assign_ref(&mut power, el);
+            self.trace(&power)
+        }).collect::<Vec<_>>();
+        // Newton's identities give the elementary symmetric polynomials `e_k` of the conjugates of `el`
+        let mut elementary_symmetric: Vec<El<RationalField<I>>> = vec![QQ.one()];
+        for k in 1..=n {
+            let mut e_k = QQ.zero();
+            for i in 1..=k {
+                let summand = QQ.mul_ref(&elementary_symmetric[k - i], &power_sums[i - 1]);
+                if i % 2 == 1 {
+                    QQ.add_assign(&mut e_k, summand);
+                } else {
+                    QQ.sub_assign(&mut e_k, summand);
+                }
+            }
+            elementary_symmetric.push(QQ.div(&e_k, &QQ.int_hom().map(k as i32)));
+        }
+        let mut result = elementary_symmetric.into_iter().enumerate().map(|(k, e_k)| if k % 2 == 0 { e_k } else { QQ.negate(e_k) }).collect::<Vec<_>>();
+        result.reverse();
+        return result;
+    }
+
+    ///
+    /// Computes the norm of `el`, i.e. the determinant of the `Q`-linear map `K -> K, x -> el * x`.
+    ///
+    pub fn norm(&self, el: &<Self as RingBase>::Element) -> El<RationalField<I>> {
+        let QQ = self.base_ring();
+        let constant_coeff = self.charpoly_coefficients(el).swap_remove(0);
+        if self.rank() % 2 == 0 {
+            constant_coeff
+        } else {
+            QQ.negate(constant_coeff)
+        }
+    }
+
+    ///
+    /// Computes the characteristic polynomial of the `Q`-linear map `K -> K, x -> el * x`.
+    ///
+    pub fn charpoly<P>(&self, el: &<Self as RingBase>::Element, poly_ring: P) -> El<P>
+        where P: PolyRingStore,
+            P::Type: PolyRing,
+            <<P::Type as RingExtension>::BaseRing as RingStore>::Type: CanHomFrom<RationalFieldBase<I>>
+    {
+        let hom = poly_ring.base_ring().can_hom(self.base_ring()).unwrap();
+        poly_ring.from_terms(self.charpoly_coefficients(el).into_iter().enumerate().map(|(i, c)| (hom.map(c), i)))
+    }
+
+    ///
+    /// Computes the minimal polynomial of `el` over `Q`, i.e. the monic polynomial `f` of smallest
+    /// degree with `f(el) = 0`.
+    ///
+    pub fn minpoly<P>(&self, el: &<Self as RingBase>::Element, poly_ring: P) -> El<P>
+        where P: PolyRingStore,
+            P::Type: PolyRing,
+            <<P::Type as RingExtension>::BaseRing as RingStore>::Type: CanHomFrom<RationalFieldBase<I>>
+    {
+        let QQ = self.base_ring();
+        let QQX = DensePolyRing::new(QQ, "X");
+        // the characteristic polynomial is a power of the minimal polynomial, thus the latter is its square-free part
+        let charpoly = self.charpoly(el, &QQX);
+        let derivative = QQX.from_terms(QQX.terms(&charpoly).filter(|(_, i)| *i > 0).map(|(c, i)| (QQ.int_hom().mul_ref_map(c, &(i as i32)), i - 1)));
+        let gcd = algorithms::eea::gcd(QQX.clone_el(&charpoly), derivative, &QQX);
+        let mut minpoly = QQX.euclidean_div(charpoly, &gcd);
+        let lc_inv = QQ.div(&QQ.one(), QQX.lc(&minpoly).unwrap());
+        QQX.inclusion().mul_assign_map_ref(&mut minpoly, &lc_inv);
+
+        let hom = poly_ring.base_ring().can_hom(QQ).unwrap();
+        poly_ring.from_terms(QQX.terms(&minpoly).map(|(c, i)| (hom.map_ref(c), i)))
+    }
+
+    ///
+    /// Returns the discriminant of the defining polynomial, which is also the discriminant
+    /// of the order `Z[θ]`.
+    ///
+    pub fn discriminant(&self) -> El<I> {
+        let n = self.rank();
+        let theta = self.canonical_gen();
+        let traces = (0..(2 * n - 1)).map(|k| self.trace(&RingRef::new(self).pow(self.clone_el(&theta), k))).collect::<Vec<_>>();
+        let mut trace_matrix = DenseMatrix::from_fn(n, n, |i, j| self.base_ring().clone_el(&traces[i + j]));
+        self.as_integer(&determinant(&mut trace_matrix, self.base_ring()))
+    }
+
+    ///
+    /// Returns the images `σ(θ)` for all embeddings `σ: K -> C`, which are exactly the complex roots of
+    /// the defining polynomial. They are ordered such that the real roots come first (in ascending order),
+    /// followed by pairs of complex conjugate roots.
+    ///
+    pub fn complex_embeddings(&self) -> Vec<Complex64El> {
+        let CC = Complex64::RING;
+        let QQ = self.base_ring();
+        let ZZ = self.integer_ring();
+        let to_f64 = |x: &El<RationalField<I>>| ZZ.to_float_approx(QQ.num(x)) / ZZ.to_float_approx(QQ.den(x));
+        let x_pow_rank = self.coefficients(&RingRef::new(self).pow(self.canonical_gen(), self.rank()));
+        let poly = x_pow_rank.iter().map(|c| CC.from_f64(-to_f64(c))).chain(Some(CC.one())).collect::<Vec<_>>();
+        let roots = algorithms::complex_roots::aberth_roots(&poly).into_iter().map(|z| algorithms::complex_roots::newton_polish(&poly, z)).collect::<Vec<_>>();
+
+        let mut real_roots = Vec::new();
+        let mut complex_roots = Vec::new();
+        for root in roots {
+            if CC.im(root).abs() <= 1e-8 * f64::max(1., CC.abs(root)) {
+                real_roots.push(CC.re(root));
+            } else if CC.im(root) > 0. {
+                complex_roots.push(root);
+            }
+        }
+        assert_eq!(self.rank(), real_roots.len() + 2 * complex_roots.len(), "Failed to separate complex roots; the defining polynomial is probably not square-free");
+        real_roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        complex_roots.sort_by(|a, b| CC.re(*a).partial_cmp(&CC.re(*b)).unwrap());
+        return real_roots.into_iter().map(|x| CC.from_f64(x))
+            .chain(complex_roots.into_iter().flat_map(|z| [z, CC.conjugate(z)]))
+            .collect();
+    }
+
+    ///
+    /// Computes `σ(el)`, where `σ: K -> C` is the embedding with `σ(θ) = root`. Usually, `root` is
+    /// one of the values returned by [`NumberFieldBase::complex_embeddings()`].
+    ///
+    pub fn embed(&self, el: &<Self as RingBase>::Element, root: Complex64El) -> Complex64El {
+        let CC = Complex64::RING;
+        let QQ = self.base_ring();
+        let ZZ = self.integer_ring();
+        let mut result = CC.zero();
+        for c in self.coefficients(el).into_iter().rev() {
+            CC.mul_assign(&mut result, root);
+            CC.add_assign(&mut result, CC.from_f64(ZZ.to_float_approx(QQ.num(&c)) / ZZ.to_float_approx(QQ.den(&c))));
+        }
+        return result;
+    }
+
+    ///
+    /// Returns the signature `(r1, r2)` of this number field, i.e. the number `r1` of real embeddings
+    /// and the number `r2` of pairs of complex conjugate embeddings.
+    ///
+    pub fn signature(&self) -> (usize, usize) {
+        let CC = Complex64::RING;
+        let r1 = self.complex_embeddings().into_iter().filter(|z| CC.im(*z) == 0.).count();
+        (r1, (self.rank() - r1) / 2)
+    }
+
+    ///
+    /// Computes a `Z`-basis of the ring of integers of this number field, using the Round 2 algorithm
+    /// of Zassenhaus (see e.g. Algorithm 6.1.8 in Cohen's "A Course in Computational Algebraic Number Theory").
+    ///
+    /// This requires the factorization of the discriminant of the defining polynomial, so might be slow
+    /// if the discriminant is large.
+    ///
+    pub fn integral_basis(&self) -> Vec<<Self as RingBase>::Element> {
+        let ZZbig = BigIntRing::RING;
+        let ZZ = self.integer_ring();
+        let theta = self.canonical_gen();
+        let mut basis = (0..self.rank()).map(|i| RingRef::new(self).pow(self.clone_el(&theta), i)).collect::<Vec<_>>();
+        let discriminant = int_cast(self.discriminant(), &ZZbig, ZZ);
+        for (p, e) in algorithms::int_factor::factor(&ZZbig, discriminant) {
+            // only primes whose square divides the discriminant can divide the index `[O_K : Z[θ]]`
+            if e >= 2 {
+                basis = self.p_maximal_order(basis, &p);
+            }
+        }
+        return basis;
+    }
+
+    ///
+    /// Computes the discriminant of this number field, i.e. the discriminant of its ring of integers.
+    ///
+    pub fn field_discriminant(&self) -> El<I> {
+        let basis = self.integral_basis();
+        let n = self.rank();
+        let mut trace_matrix = DenseMatrix::from_fn(n, n, |i, j| self.trace(&self.mul_ref(&basis[i], &basis[j])));
+        self.as_integer(&determinant(&mut trace_matrix, self.base_ring()))
+    }
+
+    ///
+    /// Returns the integer matrix `(m_ijk)` such that `ω_i ω_j = sum_k m_ijk ω_k`, where
+    /// `ω_i` is the given basis of an order.
+    ///
+    fn multiplication_table(&self, basis: &[<Self as RingBase>::Element]) -> Vec<Vec<Vec<El<I>>>> {
+        let QQ = self.base_ring();
+        let n = self.rank();
+        let basis_coefficients = basis.iter().map(|b| self.coefficients(b)).collect::<Vec<_>>();
+        let basis_matrix_inv = invert(&mut DenseMatrix::from_fn(n, n, |i, j| QQ.clone_el(&basis_coefficients[i][j])), QQ).expect("Basis is not linearly independent");
+        (0..n).map(|i| (0..n).map(|j| {
+            let product = self.coefficients(&self.mul_ref(&basis[i], &basis[j]));
+            (0..n).map(|k| self.as_integer(&QQ.sum((0..n).map(|l| QQ.mul_ref(&product[l], basis_matrix_inv.at(l, k)))))).collect()
+        }).collect()).collect()
+    }
+
+    ///
+    /// Given a basis of an order `O`, computes a basis of an order that contains `O` and is maximal at `p`,
+    /// i.e. whose index in the ring of integers is coprime to `p`.
+    ///
+    fn p_maximal_order(&self, mut basis: Vec<<Self as RingBase>::Element>, p: &El<BigIntRing>) -> Vec<<Self as RingBase>::Element> {
+        let ZZbig = BigIntRing::RING;
+        let ZZ = self.integer_ring();
+        let QQ = self.base_ring();
+        let n = self.rank();
+        let Fp = zn_barett::Zn::new(ZZbig, ZZbig.clone_el(p)).as_field().ok().unwrap();
+        let reduce = |x: &El<I>| Fp.coerce(&ZZbig, int_cast(ZZ.clone_el(x), &ZZbig, ZZ));
+        let lift = |x: &El<AsField<zn_barett::Zn<BigIntRing>>>| int_cast(Fp.smallest_positive_lift(Fp.clone_el(x)), ZZ, &ZZbig);
+        let p_int = int_cast(ZZbig.clone_el(p), ZZ, &ZZbig);
+        let mut q = ZZbig.clone_el(p);
+        while ZZbig.is_lt(&q, &ZZbig.int_hom().map(n as i32)) {
+            ZZbig.mul_assign_ref(&mut q, p);
+        }
+
+        loop {
+            let table = self.multiplication_table(&basis);
+            let table_mod_p = table.iter().map(|row| row.iter().map(|entry| entry.iter().map(reduce).collect::<Vec<_>>()).collect::<Vec<_>>()).collect::<Vec<_>>();
+            let mul_mod_p = |lhs: &Vec<_>, rhs: &Vec<_>| (0..n).map(|k| Fp.sum((0..n).flat_map(|i| (0..n).map(move |j| (i, j))).map(|(i, j)| Fp.mul(Fp.mul_ref(&lhs[i], &rhs[j]), Fp.clone_el(&table_mod_p[i][j][k]))))).collect::<Vec<_>>();
+            let unit_vector = |i: usize| (0..n).map(|j| if i == j { Fp.one() } else { Fp.zero() }).collect::<Vec<_>>();
+
+            // the `p`-radical `I` of `O` is the kernel of `x -> x^q` on `O/pO`, where `q = p^j >= n`
+            let frobenius_columns = (0..n).map(|i| algorithms::sqr_mul::generic_abs_square_and_multiply(
+                unit_vector(i),
+                &q,
+                &ZZbig,
+                |a| mul_mod_p(&a, &a),
+                |a, b| mul_mod_p(a, &b),
+                unit_vector(0)
+            )).collect::<Vec<_>>();
+            let mut frobenius_matrix = DenseMatrix::from_fn(n, n, |i, j| Fp.clone_el(&frobenius_columns[j][i]));
+            let radical_basis = lattice_basis_mod_p(ZZ, &p_int, n, kernel(&mut frobenius_matrix, &Fp).into_iter().map(|(i, v)| (i, v.iter().map(lift).collect())).collect());
+            let radical_basis_inv = invert(&mut DenseMatrix::from_fn(n, n, |i, j| QQ.inclusion().map_ref(&radical_basis[i][j])), QQ).unwrap();
+
+            // the multiplier ring `{ x in K | xI ⊆ I }` is `U/p` where `U/pO` is the kernel of `O/pO -> End(I/pI)`
+            let multiplier_columns = (0..n).map(|i| radical_basis.iter().flat_map(|b| {
+                let product = (0..n).map(|k| ZZ.sum((0..n).map(|j| ZZ.mul_ref(&b[j], &table[i][j][k])))).collect::<Vec<_>>();
+                (0..n).map(|k| reduce(&self.as_integer(&QQ.sum((0..n).map(|l| QQ.mul(QQ.inclusion().map_ref(&product[l]), QQ.clone_el(radical_basis_inv.at(l, k)))))))).collect::<Vec<_>>()
+            }).collect::<Vec<_>>()).collect::<Vec<_>>();
+            let mut multiplier_matrix = DenseMatrix::from_fn(n * n, n, |i, j| Fp.clone_el(&multiplier_columns[j][i]));
+            let multiplier_kernel = kernel(&mut multiplier_matrix, &Fp);
+            if multiplier_kernel.is_empty() {
+                return basis;
+            }
+            let p_inv = QQ.from_fraction(ZZ.one(), ZZ.clone_el(&p_int));
+            let new_elements = multiplier_kernel.into_iter().map(|(i, v)| {
+                let mut new_element = self.sum(v.iter().zip(basis.iter()).map(|(c, b)| RingRef::new(self).inclusion().mul_ref_map(b, &QQ.inclusion().map(lift(c)))));
+                self.mul_assign_base(&mut new_element, &p_inv);
+                (i, new_element)
+            }).collect::<Vec<_>>();
+            for (i, new_element) in new_elements {
+                basis[i] = new_element;
+            }
+        }
+    }
+}
+
+///
+/// Computes a `Z`-basis of the lattice `L + pZ^n`, where `L` is generated by the given vectors. Each vector
+/// `v` must come with an index `i` such that `v_i = 1` and `v_j = 0` for all indices `j` of the other vectors,
+/// as returned by [`algorithms::smith::kernel()`].
+///
+fn lattice_basis_mod_p<I>(ZZ: &I, p: &El<I>, n: usize, generators: Vec<(usize, Vec<El<I>>)>) -> Vec<Vec<El<I>>>
+    where I: IntegerRingStore,
+        I::Type: IntegerRing
+{
+    let mut result = (0..n).map(|i| (0..n).map(|j| if i == j { ZZ.clone_el(p) } else { ZZ.zero() }).collect::<Vec<_>>()).collect::<Vec<_>>();
+    for (i, v) in generators {
+        result[i] = v;
+    }
+    return result;
+}
+
+impl<I: IntegerRingStore> PartialEq for NumberFieldBase<I>
+    where I::Type: IntegerRing
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.base.get_ring() == other.base.get_ring()
+    }
+}
+
+impl<I: IntegerRingStore> DelegateRing for NumberFieldBase<I>
+    where I::Type: IntegerRing
+{
+    type Element = <NumberFieldImpl<I> as RingBase>::Element;
+    type Base = NumberFieldImpl<I>;
+
+    fn get_delegate(&self) -> &Self::Base {
+        self.base.get_ring()
+    }
+
+    fn delegate(&self, el: Self::Element) -> <Self::Base as RingBase>::Element {
+        el
+    }
+
+    fn delegate_mut<'a>(&self, el: &'a mut Self::Element) -> &'a mut <Self::Base as RingBase>::Element {
+        el
+    }
+
+    fn delegate_ref<'a>(&self, el: &'a Self::Element) -> &'a <Self::Base as RingBase>::Element {
+        el
+    }
+
+    fn rev_delegate(&self, el: <Self::Base as RingBase>::Element) -> Self::Element {
+        el
+    }
+}
+
+impl<I: IntegerRingStore> RingExtension for NumberFieldBase<I>
+    where I::Type: IntegerRing
+{
+    type BaseRing = RationalField<I>;
+
+    fn base_ring<'a>(&'a self) -> &'a Self::BaseRing {
+        self.base.base_ring()
+    }
+
+    fn from(&self, x: El<Self::BaseRing>) -> Self::Element {
+        self.base.get_ring().from(x)
+    }
+
+    fn mul_assign_base(&self, lhs: &mut Self::Element, rhs: &El<Self::BaseRing>) {
+        self.base.get_ring().mul_assign_base(lhs, rhs)
+    }
+}
+
+impl<I: IntegerRingStore> FreeAlgebra for NumberFieldBase<I>
+    where I::Type: IntegerRing
+{
+    type VectorRepresentation<'a> = <NumberFieldImpl<I> as FreeAlgebra>::VectorRepresentation<'a>
+        where Self: 'a;
+
+    fn canonical_gen(&self) -> Self::Element {
+        self.base.get_ring().canonical_gen()
+    }
+
+    fn wrt_canonical_basis<'a>(&'a self, el: &'a Self::Element) -> Self::VectorRepresentation<'a> {
+        self.base.get_ring().wrt_canonical_basis(el)
+    }
+
+    fn rank(&self) -> usize {
+        self.base.get_ring().rank()
+    }
+}
+
+impl<I: IntegerRingStore> CanHomFrom<Self> for NumberFieldBase<I>
+    where I::Type: IntegerRing
+{
+    type Homomorphism = ();
+
+    fn has_canonical_hom(&self, from: &Self) -> Option<()> {
+        if self == from {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn map_in(&self, _from: &Self, el: Self::Element, _: &()) -> Self::Element {
+        el
+    }
+}
+
+impl<I: IntegerRingStore> CanonicalIso<Self> for NumberFieldBase<I>
+    where I::Type: IntegerRing
+{
+    type Isomorphism = ();
+
+    fn has_canonical_iso(&self, from: &Self) -> Option<()> {
+        if self == from {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn map_out(&self, _from: &Self, el: Self::Element, _: &()) -> Self::Element {
+        el
+    }
+}
+
+impl<I: IntegerRingStore, J: ?Sized + IntegerRing> CanHomFrom<J> for NumberFieldBase<I>
+    where I::Type: IntegerRing
+{
+    type Homomorphism = ();
+
+    fn has_canonical_hom(&self, _: &J) -> Option<()> {
+        Some(())
+    }
+
+    fn map_in(&self, from: &J, el: J::Element, _: &()) -> Self::Element {
+        self.from(self.base_ring().get_ring().map_in(from, el, &()))
+    }
+}
+
+impl<I: IntegerRingStore> DivisibilityRing for NumberFieldBase<I>
+    where I::Type: IntegerRing
+{
+    fn checked_left_div(&self, lhs: &Self::Element, rhs: &Self::Element) -> Option<Self::Element> {
+        if self.is_zero(rhs) {
+            if self.is_zero(lhs) {
+                return Some(self.zero());
+            } else {
+                return None;
+            }
+        }
+        // compute the inverse of `rhs` using the extended euclidean algorithm in `Q[X]`
+        let QQ = self.base_ring();
+        let QQX = DensePolyRing::new(QQ, "X");
+        let rhs_poly = QQX.from_terms(self.coefficients(rhs).into_iter().enumerate().map(|(i, c)| (c, i)));
+        let (s, _, d) = algorithms::eea::eea(rhs_poly, self.defining_poly(&QQX), &QQX);
+        assert_eq!(Some(0), QQX.degree(&d), "The defining polynomial of the number field is not irreducible");
+        let d_inv = QQ.div(&QQ.one(), QQX.coefficient_at(&d, 0));
+        let rhs_inv = self.from_canonical_basis((0..self.rank()).map(|i| QQ.mul_ref(QQX.coefficient_at(&s, i), &d_inv)));
+        return Some(self.mul_ref_fst(lhs, rhs_inv));
+    }
+}
+
+impl<I: IntegerRingStore> PrincipalIdealRing for NumberFieldBase<I>
+    where I::Type: IntegerRing
+{
+    fn ideal_gen(&self, lhs: &Self::Element, rhs: &Self::Element) -> (Self::Element, Self::Element, Self::Element) {
+        if !self.is_zero(lhs) {
+            (self.checked_left_div(&self.one(), lhs).unwrap(), self.zero(), self.one())
+        } else if !self.is_zero(rhs) {
+            (self.zero(), self.checked_left_div(&self.one(), rhs).unwrap(), self.one())
+        } else {
+            (self.zero(), self.zero(), self.zero())
+        }
+    }
+}
+
+impl<I: IntegerRingStore> EuclideanRing for NumberFieldBase<I>
+    where I::Type: IntegerRing
+{
+    fn euclidean_div_rem(&self, lhs: Self::Element, rhs: &Self::Element) -> (Self::Element, Self::Element) {
+        assert!(!self.is_zero(rhs));
+        (self.checked_left_div(&lhs, rhs).unwrap(), self.zero())
+    }
+
+    fn euclidean_deg(&self, val: &Self::Element) -> Option<usize> {
+        if self.is_zero(val) {
+            Some(0)
+        } else {
+            Some(1)
+        }
+    }
+}
+
+impl<I: IntegerRingStore> Field for NumberFieldBase<I>
+    where I::Type: IntegerRing
+{}
+
+#[cfg(test)]
+use crate::primitive_int::StaticRing;
+#[cfg(test)]
+use crate::rings::rust_bigint::RustBigintRing;
+
+#[test]
+fn test_field_axioms() {
+    let K = NumberField::new(StaticRing::<i64>::RING, vec![-2, 1, 0]);
+    let elements = [(0, 0, 0), (1, 0, 0), (0, 1, 0), (1, -1, 2), (-3, 0, 1), (2, 5, -7)].into_iter()
+        .map(|(a, b, c)| K.from_canonical_basis([a, b, c].into_iter().map(|x| K.base_ring().int_hom().map(x))))
+        .collect::<Vec<_>>();
+    crate::ring::generic_tests::test_ring_axioms(&K, elements.iter().map(|x| K.clone_el(x)));
+    crate::divisibility::generic_tests::test_divisibility_axioms(&K, elements.iter().map(|x| K.clone_el(x)));
+    for x in elements.iter().filter(|x| !K.is_zero(x)) {
+        assert_el_eq!(&K, &K.one(), &K.mul_ref_fst(x, K.div(&K.one(), x)));
+    }
+}
+
+#[test]
+fn test_norm_trace_charpoly() {
+    let ZZ = StaticRing::<i64>::RING;
+    // `θ^3 = 2`
+    let K = NumberField::new(ZZ, vec![2, 0, 0]);
+    let QQ = K.base_ring();
+    let theta = K.canonical_gen();
+    assert_el_eq!(QQ, &QQ.int_hom().map(2), &K.norm(&theta));
+    assert_el_eq!(QQ, &QQ.zero(), &K.trace(&theta));
+    assert_el_eq!(QQ, &QQ.int_hom().map(3), &K.trace(&K.one()));
+    // `θ + 1` has minimal polynomial `(X - 1)^3 - 2`
+    let x = K.add_ref_snd(K.one(), &theta);
+    assert_el_eq!(QQ, &QQ.int_hom().map(3), &K.norm(&x));
+    assert_el_eq!(QQ, &QQ.int_hom().map(3), &K.trace(&x));
+
+    let QQX = DensePolyRing::new(QQ, "X");
+    let expected = QQX.from_terms([(-3, 0), (3, 1), (-3, 2), (1, 3)].into_iter().map(|(c, i)| (QQ.int_hom().map(c), i)));
+    assert_el_eq!(&QQX, &expected, &K.charpoly(&x, &QQX));
+    assert_el_eq!(&QQX, &expected, &K.minpoly(&x, &QQX));
+
+    // rational elements have the minimal polynomial `X - a`
+    let a = K.inclusion().map(QQ.from_fraction(1, 2));
+    let expected = QQX.from_terms([(QQ.from_fraction(-1, 8), 0), (QQ.from_fraction(3, 4), 1), (QQ.from_fraction(-3, 2), 2), (QQ.one(), 3)].into_iter());
+    assert_el_eq!(&QQX, &expected, &K.charpoly(&a, &QQX));
+    let expected = QQX.from_terms([(QQ.from_fraction(-1, 2), 0), (QQ.one(), 1)].into_iter());
+    assert_el_eq!(&QQX, &expected, &K.minpoly(&a, &QQX));
+
+    // `θ^2` in `Q(sqrt[4]{5})` has minimal polynomial `X^2 - 5`
+    let L = NumberField::new(ZZ, vec![5, 0, 0, 0]);
+    let theta_sqr = L.pow(L.canonical_gen(), 2);
+    let expected = QQX.from_terms([(QQ.int_hom().map(-5), 0), (QQ.one(), 2)].into_iter());
+    assert_el_eq!(&QQX, &expected, &L.minpoly(&theta_sqr, &QQX));
+    assert_el_eq!(&QQX, &QQX.pow(expected, 2), &L.charpoly(&theta_sqr, &QQX));
+}
+
+#[test]
+fn test_discriminant() {
+    let ZZ = StaticRing::<i64>::RING;
+    // disc(X^2 + 1) = -4
+    assert_eq!(-4, NumberField::new(ZZ, vec![-1, 0]).discriminant());
+    // disc(X^3 - 2) = -108
+    assert_eq!(-108, NumberField::new(ZZ, vec![2, 0, 0]).discriminant());
+    // disc(X^3 + X + 1) = -31
+    assert_eq!(-31, NumberField::new(ZZ, vec![-1, -1, 0]).discriminant());
+}
+
+#[test]
+fn test_complex_embeddings() {
+    let CC = Complex64::RING;
+    // `X^3 - 2` has one real and two complex roots
+    let K = NumberField::new(StaticRing::<i64>::RING, vec![2, 0, 0]);
+    assert_eq!((1, 1), K.signature());
+    let embeddings = K.complex_embeddings();
+    assert_eq!(3, embeddings.len());
+    assert!(CC.is_approx_eq(CC.from_f64(2f64.powf(1. / 3.)), embeddings[0], 10));
+    assert!(CC.is_approx_eq(CC.mul(embeddings[0], CC.root_of_unity(1, 3)), embeddings[1], 10));
+    assert!(CC.is_approx_eq(CC.conjugate(embeddings[1]), embeddings[2], 10));
+
+    // embeddings are ring homomorphisms
+    let x = K.from_canonical_basis([1, -2, 3].into_iter().map(|c| K.base_ring().int_hom().map(c)));
+    let y = K.from_canonical_basis([0, 5, -1].into_iter().map(|c| K.base_ring().int_hom().map(c)));
+    for root in embeddings {
+        let expected = CC.mul(K.embed(&x, root), K.embed(&y, root));
+        assert!(CC.is_approx_eq(expected, K.embed(&K.mul_ref(&x, &y), root), 1000));
+    }
+
+    // the 5-th cyclotomic field is totally complex
+    let L = NumberField::new(StaticRing::<i64>::RING, vec![-1, -1, -1, -1]);
+    assert_eq!((0, 2), L.signature());
+    // `X^2 - 3` is totally real
+    let L = NumberField::new(StaticRing::<i64>::RING, vec![3, 0]);
+    assert_eq!((2, 0), L.signature());
+}
+
+#[test]
+fn test_integral_basis() {
+    let ZZ = StaticRing::<i64>::RING;
+    // `Q(sqrt(5))` has ring of integers `Z[(1 + sqrt(5))/2]`
+    let K = NumberField::new(ZZ, vec![5, 0]);
+    assert_eq!(20, K.discriminant());
+    assert_eq!(5, K.field_discriminant());
+    // `Q(sqrt(-1))` has ring of integers `Z[i]`
+    let K = NumberField::new(ZZ, vec![-1, 0]);
+    assert_eq!(-4, K.field_discriminant());
+    // `Q(sqrt[3]{2})` has ring of integers `Z[θ]`
+    let K = NumberField::new(ZZ, vec![2, 0, 0]);
+    assert_eq!(-108, K.field_discriminant());
+    // `X^3 - X^2 - 2X - 8` is Dedekind's example of a field where the ring of integers is not monogenic,
+    // it has discriminant `-503` and index `2`
+    let K = NumberField::new(ZZ, vec![8, 2, 1]);
+    assert_eq!(-2012, K.discriminant());
+    assert_eq!(-503, K.field_discriminant());
+}
+
+#[test]
+fn test_integral_basis_elements_are_integral() {
+    let ZZ = RustBigintRing::RING;
+    // `Q(sqrt[4]{12})`, i.e. `θ^4 = 12`
+    let K = NumberField::new(ZZ, vec![ZZ.int_hom().map(12), ZZ.zero(), ZZ.zero(), ZZ.zero()]);
+    let QQ = K.base_ring();
+    let QQX = DensePolyRing::new(QQ, "X");
+    let basis = K.integral_basis();
+    for b in &basis {
+        let charpoly = K.charpoly(b, &QQX);
+        assert!(QQX.terms(&charpoly).all(|(c, _)| ZZ.is_one(QQ.den(c))));
+    }
+    // `θ^2/2 = sqrt(3)` is integral
+    let sqrt3 = K.div(&K.pow(K.canonical_gen(), 2), &K.int_hom().map(2));
+    let basis_matrix = basis.iter().map(|b| K.get_ring().coefficients(b)).collect::<Vec<_>>();
+    let inv = invert(&mut DenseMatrix::from_fn(4, 4, |i, j| QQ.clone_el(&basis_matrix[i][j])), QQ).unwrap();
+    let coords = K.get_ring().coefficients(&sqrt3);
+    for k in 0..4 {
+        let c = QQ.sum((0..4).map(|l| QQ.mul_ref(&coords[l], inv.at(l, k))));
+        assert!(ZZ.is_one(QQ.den(&c)));
+    }
+    // `Z[θ]` has index `16` in the ring of integers
+    assert_el_eq!(&ZZ, &ZZ.int_hom().map(-442368), &K.discriminant());
+    assert_el_eq!(&ZZ, &ZZ.int_hom().map(-1728), &K.field_discriminant());
+}
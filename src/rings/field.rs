@@ -5,6 +5,8 @@ use crate::field::Field;
 use crate::integer::IntegerRing;
 use crate::ring::*;
 use crate::homomorphism::*;
+use crate::algorithms::resultant::{ResultantHint, resultant_over_field};
+use crate::rings::poly::{PolyRing, PolyRingStore};
 
 #[derive(Clone, Copy)]
 pub struct AsFieldBase<R: DivisibilityRingStore> 
@@ -203,6 +205,18 @@ impl<R: DivisibilityRingStore> Field for AsFieldBase<R>
     }
 }
 
+impl<R: DivisibilityRingStore> ResultantHint for AsFieldBase<R>
+    where R::Type: PrincipalIdealRing
+{
+    fn compute_resultant<P>(&self, poly_ring: P, f: &El<P>, g: &El<P>) -> Self::Element
+        where P: PolyRingStore,
+            P::Type: PolyRing,
+            <P::Type as RingExtension>::BaseRing: RingStore<Type = Self>
+    {
+        resultant_over_field(poly_ring, f, g)
+    }
+}
+
 #[cfg(test)]
 use crate::rings::zn::zn_barett::Zn;
 #[cfg(test)]
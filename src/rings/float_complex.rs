@@ -7,6 +7,7 @@ use crate::integer::{IntegerRingStore, IntegerRing};
 use crate::ring::*;
 use crate::homomorphism::*;
 use crate::divisibility::DivisibilityRing;
+use crate::algorithms::fft::complex_fft::*;
 
 #[derive(Clone, Copy, PartialEq)]
 pub struct Complex64;
@@ -63,6 +64,18 @@ impl Complex64 {
         Complex64El(x, 0.)
     }
 
+    pub fn from_parts(&self, re: f64, im: f64) -> Complex64El {
+        Complex64El(re, im)
+    }
+
+    pub fn re(&self, Complex64El(re, _): Complex64El) -> f64 {
+        re
+    }
+
+    pub fn im(&self, Complex64El(_, im): Complex64El) -> f64 {
+        im
+    }
+
     pub fn root_of_unity(&self, i: i64, n: i64) -> Complex64El {
         self.exp(self.mul(self.from_f64((i as f64 / n as f64) * (2. * PI)), Self::I))
     }
@@ -88,6 +101,12 @@ impl RingValue<Complex64> {
 
     pub fn from_f64(&self, x: f64) -> Complex64El { self.get_ring().from_f64(x) }
 
+    pub fn from_parts(&self, re: f64, im: f64) -> Complex64El { self.get_ring().from_parts(re, im) }
+
+    pub fn re(&self, val: Complex64El) -> f64 { self.get_ring().re(val) }
+
+    pub fn im(&self, val: Complex64El) -> f64 { self.get_ring().im(val) }
+
     pub fn root_of_unity(&self, i: i64, n: i64) -> Complex64El { self.get_ring().root_of_unity(i, n) }
 }
 
@@ -171,6 +190,17 @@ impl EuclideanRing for Complex64 {
 
 impl Field for Complex64 {}
 
+impl ApproxComplexRing for Complex64 {
+
+    fn relative_error(&self) -> f64 {
+        f64::EPSILON
+    }
+
+    fn root_of_unity_error(&self) -> f64 {
+        root_of_unity_error()
+    }
+}
+
 #[test]
 fn test_pow() {
     let CC = Complex64::RING;
@@ -0,0 +1,1153 @@
+use std::cmp::{max, Ordering};
+
+use crate::algorithms::fft::complex_fft::ApproxComplexRing;
+use crate::divisibility::DivisibilityRing;
+use crate::field::Field;
+use crate::homomorphism::*;
+use crate::integer::*;
+use crate::ordered::*;
+use crate::primitive_int::StaticRing;
+use crate::pid::{EuclideanRing, EuclideanRingStore, PrincipalIdealRing};
+use crate::ring::*;
+use crate::rings::rust_bigint::*;
+
+const ZZ: RustBigintRing = RustBigintRing::RING;
+
+///
+/// An arbitrary-precision binary floating point number, representing the value
+/// `mantissa * 2^exponent`.
+///
+/// The number itself does not store its precision, this is determined by the ring
+/// it belongs to, i.e. [`RealMPBase`] resp. [`ComplexMPBase`]. In particular, the
+/// result of each operation is rounded to the precision of the ring that performs it.
+///
+#[derive(Clone, Debug)]
+pub struct FloatMP {
+    mantissa: RustBigint,
+    exponent: i64
+}
+
+impl FloatMP {
+
+    pub fn mantissa(&self) -> &RustBigint {
+        &self.mantissa
+    }
+
+    pub fn exponent(&self) -> i64 {
+        self.exponent
+    }
+}
+
+fn zero() -> FloatMP {
+    FloatMP { mantissa: ZZ.zero(), exponent: 0 }
+}
+
+fn is_zero(x: &FloatMP) -> bool {
+    ZZ.is_zero(&x.mantissa)
+}
+
+fn is_neg(x: &FloatMP) -> bool {
+    ZZ.is_neg(&x.mantissa)
+}
+
+fn negate(x: FloatMP) -> FloatMP {
+    FloatMP { mantissa: ZZ.negate(x.mantissa), exponent: x.exponent }
+}
+
+fn from_integer(x: RustBigint) -> FloatMP {
+    FloatMP { mantissa: x, exponent: 0 }
+}
+
+///
+/// Returns `k` such that `2^k <= |x| < 2^(k + 1)`, or `None` if `x` is zero.
+///
+fn log2_abs(x: &FloatMP) -> Option<i64> {
+    ZZ.abs_highest_set_bit(&x.mantissa).map(|b| b as i64 + x.exponent)
+}
+
+///
+/// Computes `x * 2^power`, which is exact.
+///
+fn mul_pow_2(x: &FloatMP, power: i64) -> FloatMP {
+    FloatMP { mantissa: ZZ.clone_el(&x.mantissa), exponent: x.exponent + power }
+}
+
+///
+/// Rounds `mantissa * 2^exponent` to the closest number with at most `precision` significant bits
+/// (possibly `precision + 1` in the case of a carry).
+///
+fn round(mantissa: RustBigint, exponent: i64, precision: usize) -> FloatMP {
+    match ZZ.abs_highest_set_bit(&mantissa) {
+        None => zero(),
+        Some(b) if b < precision => FloatMP { mantissa, exponent },
+        Some(b) => {
+            let shift = b + 1 - precision;
+            let negative = ZZ.is_neg(&mantissa);
+            let mut result = ZZ.abs(mantissa);
+            ZZ.euclidean_div_pow_2(&mut result, shift - 1);
+            ZZ.add_assign(&mut result, ZZ.one());
+            ZZ.euclidean_div_pow_2(&mut result, 1);
+            if negative {
+                ZZ.negate_inplace(&mut result);
+            }
+            FloatMP { mantissa: result, exponent: exponent + shift as i64 }
+        }
+    }
+}
+
+fn add(lhs: &FloatMP, rhs: &FloatMP, precision: usize) -> FloatMP {
+    let (lhs_log2, rhs_log2) = match (log2_abs(lhs), log2_abs(rhs)) {
+        (None, _) => return round(ZZ.clone_el(&rhs.mantissa), rhs.exponent, precision),
+        (_, None) => return round(ZZ.clone_el(&lhs.mantissa), lhs.exponent, precision),
+        (Some(l), Some(r)) => (l, r)
+    };
+    // if one summand is too small, it won't have any influence on the result
+    if lhs_log2 > rhs_log2 + precision as i64 + 2 {
+        return round(ZZ.clone_el(&lhs.mantissa), lhs.exponent, precision);
+    } else if rhs_log2 > lhs_log2 + precision as i64 + 2 {
+        return round(ZZ.clone_el(&rhs.mantissa), rhs.exponent, precision);
+    }
+    let (larger_exp, smaller_exp) = if lhs.exponent >= rhs.exponent { (lhs, rhs) } else { (rhs, lhs) };
+    let mut result = ZZ.clone_el(&larger_exp.mantissa);
+    ZZ.mul_pow_2(&mut result, (larger_exp.exponent - smaller_exp.exponent) as usize);
+    ZZ.add_assign_ref(&mut result, &smaller_exp.mantissa);
+    return round(result, smaller_exp.exponent, precision);
+}
+
+fn sub(lhs: &FloatMP, rhs: &FloatMP, precision: usize) -> FloatMP {
+    add(lhs, &negate(rhs.clone()), precision)
+}
+
+fn mul_exact(lhs: &FloatMP, rhs: &FloatMP) -> FloatMP {
+    FloatMP { mantissa: ZZ.mul_ref(&lhs.mantissa, &rhs.mantissa), exponent: lhs.exponent + rhs.exponent }
+}
+
+fn mul(lhs: &FloatMP, rhs: &FloatMP, precision: usize) -> FloatMP {
+    round(ZZ.mul_ref(&lhs.mantissa, &rhs.mantissa), lhs.exponent + rhs.exponent, precision)
+}
+
+fn div(lhs: &FloatMP, rhs: &FloatMP, precision: usize) -> FloatMP {
+    let rhs_bits = ZZ.abs_highest_set_bit(&rhs.mantissa).expect("Division by zero");
+    let lhs_bits = match ZZ.abs_highest_set_bit(&lhs.mantissa) {
+        None => return zero(),
+        Some(b) => b
+    };
+    let shift = max(0, (rhs_bits + precision + 2) as i64 - lhs_bits as i64);
+    let mut numerator = ZZ.clone_el(&lhs.mantissa);
+    ZZ.mul_pow_2(&mut numerator, shift as usize);
+    return round(ZZ.euclidean_div(numerator, &rhs.mantissa), lhs.exponent - shift - rhs.exponent, precision);
+}
+
+fn cmp(lhs: &FloatMP, rhs: &FloatMP) -> Ordering {
+    // computing the difference with a small precision preserves its sign
+    let difference = sub(lhs, rhs, 2);
+    if is_zero(&difference) {
+        Ordering::Equal
+    } else if is_neg(&difference) {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    }
+}
+
+///
+/// Computes `floor(sqrt(n))` for a positive integer `n` using Newton's method.
+///
+fn isqrt(n: &RustBigint) -> RustBigint {
+    let mut current = ZZ.power_of_two(ZZ.abs_highest_set_bit(n).unwrap() / 2 + 1);
+    loop {
+        let mut next = ZZ.add_ref_fst(&current, ZZ.euclidean_div(ZZ.clone_el(n), &current));
+        ZZ.euclidean_div_pow_2(&mut next, 1);
+        if ZZ.is_geq(&next, &current) {
+            return current;
+        }
+        current = next;
+    }
+}
+
+fn sqrt(x: &FloatMP, precision: usize) -> FloatMP {
+    assert!(!is_neg(x), "Cannot compute the square root of a negative number");
+    let bits = match ZZ.abs_highest_set_bit(&x.mantissa) {
+        None => return zero(),
+        Some(b) => b
+    };
+    let mut shift = max(0, (2 * precision + 2) as i64 - bits as i64);
+    if (x.exponent - shift) % 2 != 0 {
+        shift += 1;
+    }
+    let mut mantissa = ZZ.clone_el(&x.mantissa);
+    ZZ.mul_pow_2(&mut mantissa, shift as usize);
+    return round(isqrt(&mantissa), (x.exponent - shift) / 2, precision);
+}
+
+///
+/// Returns the integer closest to `x * 2^fixed_point`, rounded towards zero.
+///
+fn to_fixed(x: &FloatMP, fixed_point: usize) -> RustBigint {
+    let mut result = ZZ.clone_el(&x.mantissa);
+    let shift = x.exponent + fixed_point as i64;
+    if shift >= 0 {
+        ZZ.mul_pow_2(&mut result, shift as usize);
+    } else {
+        ZZ.euclidean_div_pow_2(&mut result, (-shift) as usize);
+    }
+    return result;
+}
+
+fn from_fixed(x: RustBigint, fixed_point: usize, precision: usize) -> FloatMP {
+    round(x, -(fixed_point as i64), precision)
+}
+
+fn fixed_mul(lhs: &RustBigint, rhs: &RustBigint, fixed_point: usize) -> RustBigint {
+    let mut result = ZZ.mul_ref(lhs, rhs);
+    ZZ.euclidean_div_pow_2(&mut result, fixed_point);
+    return result;
+}
+
+///
+/// Computes `atan(1/k)` resp. `atanh(1/k)` as fixed point number, using the Taylor series.
+///
+fn atan_inv_fixed(k: i64, fixed_point: usize, hyperbolic: bool) -> RustBigint {
+    let k = ZZ.int_hom().map(k as i32);
+    let k_sqr = ZZ.pow(ZZ.clone_el(&k), 2);
+    let mut current_power = ZZ.euclidean_div(ZZ.power_of_two(fixed_point), &k);
+    let mut result = ZZ.zero();
+    let mut i = 0;
+    while !ZZ.is_zero(&current_power) {
+        let summand = ZZ.euclidean_div(ZZ.clone_el(&current_power), &ZZ.int_hom().map(2 * i + 1));
+        if hyperbolic || i % 2 == 0 {
+            ZZ.add_assign(&mut result, summand);
+        } else {
+            ZZ.sub_assign(&mut result, summand);
+        }
+        current_power = ZZ.euclidean_div(current_power, &k_sqr);
+        i += 1;
+    }
+    return result;
+}
+
+fn pi_fixed(fixed_point: usize) -> RustBigint {
+    // Machin's formula `pi = 16 atan(1/5) - 4 atan(1/239)`
+    ZZ.sub(
+        ZZ.int_hom().mul_map(atan_inv_fixed(5, fixed_point, false), 16),
+        ZZ.int_hom().mul_map(atan_inv_fixed(239, fixed_point, false), 4)
+    )
+}
+
+fn ln2_fixed(fixed_point: usize) -> RustBigint {
+    // `ln(2) = 2 atanh(1/3)`
+    ZZ.int_hom().mul_map(atan_inv_fixed(3, fixed_point, true), 2)
+}
+
+fn pi(precision: usize) -> FloatMP {
+    let fixed_point = precision + 16;
+    from_fixed(pi_fixed(fixed_point), fixed_point, precision)
+}
+
+///
+/// Chooses `s` such that `|x / 2^s| < 2^-8`.
+///
+fn exp_reduction_steps(x: &FloatMP) -> usize {
+    max(0, log2_abs(x).unwrap() + 9) as usize
+}
+
+///
+/// Computes `exp(x)` for `|x| < 1`.
+///
+fn exp_reduced(x: &FloatMP, precision: usize) -> FloatMP {
+    if is_zero(x) {
+        return from_integer(ZZ.one());
+    }
+    // we use `exp(x) = exp(x / 2^s)^(2^s)` and a Taylor series for the small value `x / 2^s`
+    let reduction_steps = exp_reduction_steps(x);
+    let working_precision = precision + reduction_steps + 16;
+    let reduced = to_fixed(&mul_pow_2(x, -(reduction_steps as i64)), working_precision);
+    let mut result = ZZ.power_of_two(working_precision);
+    let mut current = ZZ.power_of_two(working_precision);
+    let mut i = 1;
+    while !ZZ.is_zero(&current) {
+        current = ZZ.euclidean_div(fixed_mul(&current, &reduced, working_precision), &ZZ.int_hom().map(i));
+        ZZ.add_assign_ref(&mut result, &current);
+        i += 1;
+    }
+    let mut result = from_fixed(result, working_precision, working_precision);
+    for _ in 0..reduction_steps {
+        result = mul(&result, &result, working_precision);
+    }
+    return round(result.mantissa, result.exponent, precision);
+}
+
+fn exp(x: &FloatMP, precision: usize) -> FloatMP {
+    let x_log2 = match log2_abs(x) {
+        None => return from_integer(ZZ.one()),
+        Some(l) if l < 0 => return exp_reduced(x, precision),
+        Some(l) => l
+    };
+    // for `|x| >= 2^61`, the exponent of the result does not fit into an `i64` anymore
+    if x_log2 >= 61 {
+        assert!(is_neg(x), "Overflow when computing exp()");
+        return zero();
+    }
+    // write `x = k ln(2) + r` with `|r| < ln(2)`, then `exp(x) = 2^k exp(r)`; since `k` has
+    // up to `log2|x| + 1` bits, we need this many additional bits of `ln(2)`
+    let working_precision = precision + x_log2 as usize + 16;
+    let ln2 = from_fixed(ln2_fixed(working_precision), working_precision, working_precision);
+    let k = to_fixed(&div(x, &ln2, working_precision), 0);
+    let r = sub(x, &mul_exact(&from_integer(ZZ.clone_el(&k)), &ln2), working_precision);
+    return mul_pow_2(&exp_reduced(&r, precision), int_cast(k, StaticRing::<i64>::RING, ZZ));
+}
+
+///
+/// Computes `(cos(x), sin(x))`.
+///
+fn cos_sin(x: &FloatMP, precision: usize) -> (FloatMP, FloatMP) {
+    if is_zero(x) {
+        return (from_integer(ZZ.one()), zero());
+    }
+    // first reduce modulo `2 pi`
+    let mut x = x.clone();
+    let x_log2 = log2_abs(&x).unwrap();
+    if x_log2 >= 3 {
+        let reduction_precision = precision + x_log2 as usize + 16;
+        let two_pi = mul_pow_2(&pi(reduction_precision), 1);
+        let quotient = to_fixed(&div(&x, &two_pi, reduction_precision), 0);
+        x = sub(&x, &mul_exact(&from_integer(quotient), &two_pi), reduction_precision);
+        if is_zero(&x) {
+            return (from_integer(ZZ.one()), zero());
+        }
+    }
+    // now use `exp(ix) = exp(ix / 2^s)^(2^s)` and a Taylor series for `exp(ix / 2^s)`
+    let reduction_steps = exp_reduction_steps(&x);
+    let working_precision = precision + reduction_steps + max(0, -log2_abs(&x).unwrap()) as usize + 16;
+    let reduced = to_fixed(&mul_pow_2(&x, -(reduction_steps as i64)), working_precision);
+    let (mut result_re, mut result_im) = (ZZ.power_of_two(working_precision), ZZ.zero());
+    let (mut current_re, mut current_im) = (ZZ.power_of_two(working_precision), ZZ.zero());
+    let mut i = 1;
+    while !ZZ.is_zero(&current_re) || !ZZ.is_zero(&current_im) {
+        let divisor = ZZ.int_hom().map(i);
+        (current_re, current_im) = (
+            ZZ.euclidean_div(ZZ.negate(fixed_mul(&current_im, &reduced, working_precision)), &divisor),
+            ZZ.euclidean_div(fixed_mul(&current_re, &reduced, working_precision), &divisor)
+        );
+        ZZ.add_assign_ref(&mut result_re, &current_re);
+        ZZ.add_assign_ref(&mut result_im, &current_im);
+        i += 1;
+    }
+    let mut re = from_fixed(result_re, working_precision, working_precision);
+    let mut im = from_fixed(result_im, working_precision, working_precision);
+    for _ in 0..reduction_steps {
+        (re, im) = (
+            add(&mul_exact(&re, &re), &negate(mul_exact(&im, &im)), working_precision),
+            mul_pow_2(&mul(&re, &im, working_precision), 1)
+        );
+    }
+    return (round(re.mantissa, re.exponent, precision), round(im.mantissa, im.exponent, precision));
+}
+
+fn ln(x: &FloatMP, precision: usize) -> FloatMP {
+    assert!(!is_zero(x) && !is_neg(x), "Can only compute the logarithm of a positive number");
+    // write `x = y * 2^k` with `3/4 <= y < 3/2`
+    let mut k = log2_abs(x).unwrap();
+    let mut y = mul_pow_2(x, -k);
+    if cmp(&y, &FloatMP { mantissa: ZZ.int_hom().map(3), exponent: -1 }) != Ordering::Less {
+        y = mul_pow_2(&y, -1);
+        k += 1;
+    }
+    // `y - 1` can be computed exactly
+    let exact_precision = ZZ.abs_highest_set_bit(&y.mantissa).unwrap() + 2;
+    let y_minus_one = sub(&y, &from_integer(ZZ.one()), exact_precision);
+    let k_bits = StaticRing::<i64>::RING.abs_log2_ceil(&(k.abs() + 1)).unwrap();
+    let relative_size = log2_abs(&y_minus_one).map(|l| max(0, -l) as usize).unwrap_or(0);
+    let working_precision = precision + relative_size + k_bits + 16;
+
+    // use `ln(y) = 2 atanh((y - 1)/(y + 1))`
+    let t = to_fixed(&div(&y_minus_one, &add(&y, &from_integer(ZZ.one()), working_precision), working_precision), working_precision);
+    let t_sqr = fixed_mul(&t, &t, working_precision);
+    let mut current_power = t;
+    let mut result = ZZ.zero();
+    let mut i = 0;
+    while !ZZ.is_zero(&current_power) {
+        ZZ.add_assign(&mut result, ZZ.euclidean_div(ZZ.clone_el(&current_power), &ZZ.int_hom().map(2 * i + 1)));
+        current_power = fixed_mul(&current_power, &t_sqr, working_precision);
+        i += 1;
+    }
+    ZZ.mul_pow_2(&mut result, 1);
+    ZZ.add_assign(&mut result, ZZ.mul(ln2_fixed(working_precision), int_cast(k, ZZ, StaticRing::<i64>::RING)));
+    return from_fixed(result, working_precision, precision);
+}
+
+
+fn abs(x: &FloatMP) -> FloatMP {
+    FloatMP { mantissa: ZZ.abs(ZZ.clone_el(&x.mantissa)), exponent: x.exponent }
+}
+
+fn atan(x: &FloatMP, precision: usize) -> FloatMP {
+    let x_log2 = match log2_abs(x) {
+        None => return zero(),
+        Some(l) => l
+    };
+    let one = from_integer(ZZ.one());
+    if cmp(&abs(x), &one) == Ordering::Greater {
+        // use `atan(x) = sign(x) pi/2 - atan(1/|x|)`
+        let working_precision = precision + 16;
+        let half_pi = mul_pow_2(&pi(working_precision), -1);
+        let result = sub(&half_pi, &atan(&div(&one, &abs(x), working_precision), working_precision), precision);
+        return if is_neg(x) { negate(result) } else { result };
+    }
+    // use `atan(x) = 2 atan(x / (1 + sqrt(1 + x^2)))` to make `x` small, then use the Taylor series
+    let reduction_steps = 8;
+    let working_precision = precision + max(0, -x_log2) as usize + 16;
+    let mut reduced = x.clone();
+    for _ in 0..reduction_steps {
+        let denominator = add(&one, &sqrt(&add(&one, &mul(&reduced, &reduced, working_precision), working_precision), working_precision), working_precision);
+        reduced = div(&reduced, &denominator, working_precision);
+    }
+    let t = to_fixed(&reduced, working_precision);
+    let t_sqr = fixed_mul(&t, &t, working_precision);
+    let mut current_power = t;
+    let mut result = ZZ.zero();
+    let mut i = 0;
+    while !ZZ.is_zero(&current_power) {
+        let summand = ZZ.euclidean_div(ZZ.clone_el(&current_power), &ZZ.int_hom().map(2 * i + 1));
+        if i % 2 == 0 {
+            ZZ.add_assign(&mut result, summand);
+        } else {
+            ZZ.sub_assign(&mut result, summand);
+        }
+        current_power = fixed_mul(&current_power, &t_sqr, working_precision);
+        i += 1;
+    }
+    return from_fixed(result, working_precision - reduction_steps, precision);
+}
+
+///
+/// Computes the angle of the point `(x, y)`, as a value in `(-pi, pi]`.
+///
+fn atan2(y: &FloatMP, x: &FloatMP, precision: usize) -> FloatMP {
+    let working_precision = precision + 16;
+    if is_zero(x) {
+        if is_zero(y) {
+            return zero();
+        }
+        let half_pi = mul_pow_2(&pi(precision), -1);
+        return if is_neg(y) { negate(half_pi) } else { half_pi };
+    }
+    let result = atan(&div(y, x, working_precision), working_precision);
+    if !is_neg(x) {
+        return round(result.mantissa, result.exponent, precision);
+    } else if !is_neg(y) {
+        return add(&result, &pi(working_precision), precision);
+    } else {
+        return sub(&result, &pi(working_precision), precision);
+    }
+}
+
+fn from_f64(x: f64) -> FloatMP {
+    assert!(x.is_finite());
+    let bits = x.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7FF) as i64;
+    let fraction = (bits & ((1 << 52) - 1)) as i64;
+    let (mantissa, exponent) = if biased_exponent == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased_exponent - 1075)
+    };
+    let mantissa = if x < 0. { -mantissa } else { mantissa };
+    return FloatMP { mantissa: int_cast(mantissa, ZZ, StaticRing::<i64>::RING), exponent };
+}
+
+fn to_f64(x: &FloatMP) -> f64 {
+    let x = round(ZZ.clone_el(&x.mantissa), x.exponent, 62);
+    let mantissa = int_cast(x.mantissa, StaticRing::<i64>::RING, ZZ) as f64;
+    // multiply in two steps, to avoid an intermediate underflow/overflow
+    let first_exponent = (x.exponent / 2).clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+    let second_exponent = (x.exponent - x.exponent / 2).clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+    return mantissa * 2f64.powi(first_exponent) * 2f64.powi(second_exponent);
+}
+
+///
+/// Writes `x` in scientific notation, with the given number of decimal digits.
+///
+fn format_decimal(x: &FloatMP, digits: usize, out: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let x_log2 = match log2_abs(x) {
+        None => return write!(out, "0"),
+        Some(l) => l
+    };
+    // an estimate of `floor(log10|x|)`, might be off by one
+    let decimal_exponent = (x_log2 as f64 * std::f64::consts::LOG10_2).floor() as i64;
+    let scale = digits as i64 - 1 - decimal_exponent;
+    let mut numerator = ZZ.abs(ZZ.clone_el(&x.mantissa));
+    let mut denominator = ZZ.one();
+    if scale >= 0 {
+        ZZ.mul_assign(&mut numerator, ZZ.pow(ZZ.int_hom().map(10), scale as usize));
+    } else {
+        ZZ.mul_assign(&mut denominator, ZZ.pow(ZZ.int_hom().map(10), (-scale) as usize));
+    }
+    if x.exponent >= 0 {
+        ZZ.mul_pow_2(&mut numerator, x.exponent as usize);
+    } else {
+        ZZ.mul_pow_2(&mut denominator, (-x.exponent) as usize);
+    }
+    let digit_string = format!("{}", ZZ.format(&ZZ.rounded_div(numerator, &denominator)));
+    let decimal_exponent = decimal_exponent + digit_string.len() as i64 - digits as i64;
+    let sign = if is_neg(x) { "-" } else { "" };
+    if digit_string.len() == 1 {
+        write!(out, "{}{}e{}", sign, digit_string, decimal_exponent)
+    } else {
+        write!(out, "{}{}.{}e{}", sign, &digit_string[..1], &digit_string[1..], decimal_exponent)
+    }
+}
+
+///
+/// The field of real numbers `R`, approximated by binary floating point numbers with
+/// an arbitrary (but fixed) number of significant bits.
+///
+/// All operations are rounded to the given precision, hence this ring is approximate
+/// (see [`RingBase::is_approximate()`]) and does not support equality checks.
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::homomorphism::*;
+/// # use feanor_math::rings::float_mp::*;
+/// let R = RealMP::new(128);
+/// let two = R.int_hom().map(2);
+/// let sqrt_two = R.sqrt(R.clone_el(&two));
+/// assert!(R.is_approx_eq(R.mul_ref(&sqrt_two, &sqrt_two), two, 4));
+/// assert!(!R.is_approx_eq(R.from_f64(2f64.sqrt()), sqrt_two, 1 << 20));
+/// ```
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RealMPBase {
+    precision: usize
+}
+
+///
+/// The field of real numbers `R`, approximated by binary floating point numbers with
+/// an arbitrary (but fixed) number of significant bits. For details, see [`RealMPBase`].
+///
+pub type RealMP = RingValue<RealMPBase>;
+
+impl RealMP {
+
+    pub fn new(precision: usize) -> Self {
+        RingValue::from(RealMPBase::new(precision))
+    }
+}
+
+impl RealMPBase {
+
+    pub fn new(precision: usize) -> Self {
+        assert!(precision >= 2);
+        RealMPBase { precision }
+    }
+
+    ///
+    /// Returns the number of significant bits of the elements in this ring.
+    ///
+    pub fn precision(&self) -> usize {
+        self.precision
+    }
+
+    pub fn from_f64(&self, x: f64) -> FloatMP {
+        let result = from_f64(x);
+        round(result.mantissa, result.exponent, self.precision)
+    }
+
+    pub fn to_f64(&self, x: &FloatMP) -> f64 {
+        to_f64(x)
+    }
+
+    pub fn pi(&self) -> FloatMP {
+        pi(self.precision)
+    }
+
+    pub fn sqrt(&self, x: FloatMP) -> FloatMP {
+        sqrt(&x, self.precision)
+    }
+
+    pub fn exp(&self, x: FloatMP) -> FloatMP {
+        exp(&x, self.precision)
+    }
+
+    pub fn ln(&self, x: FloatMP) -> FloatMP {
+        ln(&x, self.precision)
+    }
+
+    pub fn atan(&self, x: FloatMP) -> FloatMP {
+        atan(&x, self.precision)
+    }
+
+    pub fn is_absolute_approx_eq(&self, lhs: FloatMP, rhs: FloatMP, absolute_threshold: &FloatMP) -> bool {
+        cmp(&abs(&self.sub(lhs, rhs)), absolute_threshold) == Ordering::Less
+    }
+
+    pub fn is_relative_approx_eq(&self, lhs: FloatMP, rhs: FloatMP, relative_limit: &FloatMP) -> bool {
+        let absolute_threshold = mul(&abs(&lhs), relative_limit, self.precision);
+        self.is_absolute_approx_eq(lhs, rhs, &absolute_threshold)
+    }
+
+    ///
+    /// Checks whether `lhs` and `rhs` are equal, up to an error of `precision` times the
+    /// precision of this ring.
+    ///
+    pub fn is_approx_eq(&self, lhs: FloatMP, rhs: FloatMP, precision: u64) -> bool {
+        let scaled_precision = scaled_precision(precision, self.precision);
+        if self.is_absolute_approx_eq(self.clone_el(&lhs), self.zero(), &scaled_precision) {
+            self.is_absolute_approx_eq(rhs, self.zero(), &scaled_precision)
+        } else {
+            self.is_relative_approx_eq(lhs, rhs, &scaled_precision)
+        }
+    }
+}
+
+///
+/// Returns `factor * 2^-precision`.
+///
+fn scaled_precision(factor: u64, precision: usize) -> FloatMP {
+    FloatMP { mantissa: int_cast(factor as i64, ZZ, StaticRing::<i64>::RING), exponent: -(precision as i64) }
+}
+
+impl RingValue<RealMPBase> {
+
+    pub fn precision(&self) -> usize { self.get_ring().precision() }
+
+    pub fn from_f64(&self, x: f64) -> FloatMP { self.get_ring().from_f64(x) }
+
+    pub fn to_f64(&self, x: &FloatMP) -> f64 { self.get_ring().to_f64(x) }
+
+    pub fn pi(&self) -> FloatMP { self.get_ring().pi() }
+
+    pub fn sqrt(&self, x: FloatMP) -> FloatMP { self.get_ring().sqrt(x) }
+
+    pub fn exp(&self, x: FloatMP) -> FloatMP { self.get_ring().exp(x) }
+
+    pub fn ln(&self, x: FloatMP) -> FloatMP { self.get_ring().ln(x) }
+
+    pub fn atan(&self, x: FloatMP) -> FloatMP { self.get_ring().atan(x) }
+
+    pub fn is_absolute_approx_eq(&self, lhs: FloatMP, rhs: FloatMP, absolute_threshold: &FloatMP) -> bool { self.get_ring().is_absolute_approx_eq(lhs, rhs, absolute_threshold) }
+
+    pub fn is_relative_approx_eq(&self, lhs: FloatMP, rhs: FloatMP, relative_limit: &FloatMP) -> bool { self.get_ring().is_relative_approx_eq(lhs, rhs, relative_limit) }
+
+    pub fn is_approx_eq(&self, lhs: FloatMP, rhs: FloatMP, precision: u64) -> bool { self.get_ring().is_approx_eq(lhs, rhs, precision) }
+}
+
+impl RingBase for RealMPBase {
+
+    type Element = FloatMP;
+
+    fn clone_el(&self, val: &Self::Element) -> Self::Element {
+        val.clone()
+    }
+
+    fn add_assign_ref(&self, lhs: &mut Self::Element, rhs: &Self::Element) {
+        *lhs = add(lhs, rhs, self.precision);
+    }
+
+    fn add_assign(&self, lhs: &mut Self::Element, rhs: Self::Element) {
+        self.add_assign_ref(lhs, &rhs);
+    }
+
+    fn negate_inplace(&self, lhs: &mut Self::Element) {
+        ZZ.negate_inplace(&mut lhs.mantissa);
+    }
+
+    fn mul_assign_ref(&self, lhs: &mut Self::Element, rhs: &Self::Element) {
+        *lhs = mul(lhs, rhs, self.precision);
+    }
+
+    fn mul_assign(&self, lhs: &mut Self::Element, rhs: Self::Element) {
+        self.mul_assign_ref(lhs, &rhs);
+    }
+
+    fn from_int(&self, value: i32) -> Self::Element {
+        round(ZZ.int_hom().map(value), 0, self.precision)
+    }
+
+    fn eq_el(&self, _: &Self::Element, _: &Self::Element) -> bool {
+        panic!("Cannot provide equality on approximate rings")
+    }
+
+    fn is_commutative(&self) -> bool { true }
+
+    fn is_noetherian(&self) -> bool { true }
+
+    fn is_approximate(&self) -> bool { true }
+
+    fn dbg<'a>(&self, value: &Self::Element, out: &mut std::fmt::Formatter<'a>) -> std::fmt::Result {
+        format_decimal(value, decimal_digits(self.precision), out)
+    }
+}
+
+///
+/// The number of decimal digits that are displayed for a number with the given precision.
+///
+fn decimal_digits(precision: usize) -> usize {
+    max(1, (precision as f64 * std::f64::consts::LOG10_2).floor() as usize)
+}
+
+impl_eq_based_self_iso!{ RealMPBase }
+
+impl<I: ?Sized + IntegerRing> CanHomFrom<I> for RealMPBase {
+
+    type Homomorphism = ();
+
+    fn has_canonical_hom(&self, _: &I) -> Option<Self::Homomorphism> {
+        Some(())
+    }
+
+    fn map_in(&self, from: &I, el: I::Element, _: &Self::Homomorphism) -> Self::Element {
+        round(int_cast(el, ZZ, RingRef::new(from)), 0, self.precision)
+    }
+}
+
+impl OrderedRing for RealMPBase {
+
+    fn cmp(&self, lhs: &Self::Element, rhs: &Self::Element) -> Ordering {
+        cmp(lhs, rhs)
+    }
+}
+
+impl DivisibilityRing for RealMPBase {
+
+    fn checked_left_div(&self, lhs: &Self::Element, rhs: &Self::Element) -> Option<Self::Element> {
+        if is_zero(rhs) {
+            None
+        } else {
+            Some(div(lhs, rhs, self.precision))
+        }
+    }
+}
+
+impl PrincipalIdealRing for RealMPBase {
+
+    fn ideal_gen(&self, _lhs: &Self::Element, _rhs: &Self::Element) -> (Self::Element, Self::Element, Self::Element) {
+        panic!("Since RealMP is only approximate, this cannot be implemented properly")
+    }
+}
+
+impl EuclideanRing for RealMPBase {
+
+    fn euclidean_div_rem(&self, _lhs: Self::Element, _rhs: &Self::Element) -> (Self::Element, Self::Element) {
+        panic!("Since RealMP is only approximate, this cannot be implemented properly")
+    }
+
+    fn euclidean_deg(&self, _: &Self::Element) -> Option<usize> {
+        panic!("Since RealMP is only approximate, this cannot be implemented properly")
+    }
+}
+
+impl Field for RealMPBase {
+
+    fn div(&self, lhs: &Self::Element, rhs: &Self::Element) -> Self::Element {
+        self.checked_left_div(lhs, rhs).expect("Division by zero")
+    }
+}
+
+///
+/// An element of [`ComplexMPBase`], i.e. a complex number whose real and imaginary
+/// part are given as [`FloatMP`]s.
+///
+#[derive(Clone, Debug)]
+pub struct ComplexMPEl(FloatMP, FloatMP);
+
+///
+/// The field of complex numbers `C`, approximated by pairs of binary floating point numbers 
+/// with an arbitrary (but fixed) number of significant bits.
+///
+/// All operations are rounded to the given precision, hence this ring is approximate
+/// (see [`RingBase::is_approximate()`]) and does not support equality checks. In particular,
+/// it can be used to compute complex-valued FFTs with a much smaller error than
+/// [`crate::rings::float_complex::Complex64`], see [`crate::algorithms::fft::cooley_tuckey::FFTTableCooleyTuckey::for_complex_mp()`].
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::rings::float_mp::*;
+/// let C = ComplexMP::new(128);
+/// let i = C.imaginary_unit();
+/// let zeta = C.root_of_unity(1, 8);
+/// assert!(C.is_approx_eq(C.pow(C.clone_el(&zeta), 2), i, 4));
+/// assert!(C.is_approx_eq(C.pow(zeta, 8), C.one(), 16));
+/// ```
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ComplexMPBase {
+    real: RealMPBase
+}
+
+///
+/// The field of complex numbers `C`, approximated by pairs of binary floating point numbers 
+/// with an arbitrary (but fixed) number of significant bits. For details, see [`ComplexMPBase`].
+///
+pub type ComplexMP = RingValue<ComplexMPBase>;
+
+impl ComplexMP {
+
+    pub fn new(precision: usize) -> Self {
+        RingValue::from(ComplexMPBase::new(precision))
+    }
+}
+
+impl ComplexMPBase {
+
+    pub fn new(precision: usize) -> Self {
+        ComplexMPBase { real: RealMPBase::new(precision) }
+    }
+
+    ///
+    /// Returns the number of significant bits of the real and imaginary part of the
+    /// elements in this ring.
+    ///
+    pub fn precision(&self) -> usize {
+        self.real.precision()
+    }
+
+    ///
+    /// Returns the ring of real numbers with the same precision as this ring.
+    ///
+    pub fn real_part_ring(&self) -> RealMP {
+        RingValue::from(self.real)
+    }
+
+    pub fn imaginary_unit(&self) -> ComplexMPEl {
+        ComplexMPEl(zero(), from_integer(ZZ.one()))
+    }
+
+    pub fn from_parts(&self, re: FloatMP, im: FloatMP) -> ComplexMPEl {
+        ComplexMPEl(
+            round(re.mantissa, re.exponent, self.precision()), 
+            round(im.mantissa, im.exponent, self.precision())
+        )
+    }
+
+    pub fn re(&self, ComplexMPEl(re, _): &ComplexMPEl) -> FloatMP {
+        re.clone()
+    }
+
+    pub fn im(&self, ComplexMPEl(_, im): &ComplexMPEl) -> FloatMP {
+        im.clone()
+    }
+
+    pub fn from_f64(&self, x: f64) -> ComplexMPEl {
+        ComplexMPEl(self.real.from_f64(x), zero())
+    }
+
+    pub fn abs(&self, ComplexMPEl(re, im): &ComplexMPEl) -> FloatMP {
+        sqrt(&add(&mul_exact(re, re), &mul_exact(im, im), self.precision() + 2), self.precision())
+    }
+
+    pub fn conjugate(&self, ComplexMPEl(re, im): ComplexMPEl) -> ComplexMPEl {
+        ComplexMPEl(re, negate(im))
+    }
+
+    pub fn exp(&self, ComplexMPEl(re, im): ComplexMPEl) -> ComplexMPEl {
+        let working_precision = self.precision() + 4;
+        let abs = exp(&re, working_precision);
+        let (cos, sin) = cos_sin(&im, working_precision);
+        ComplexMPEl(mul(&abs, &cos, self.precision()), mul(&abs, &sin, self.precision()))
+    }
+
+    pub fn ln_main_branch(&self, ComplexMPEl(re, im): ComplexMPEl) -> ComplexMPEl {
+        let working_precision = self.precision() + 4;
+        let abs_sqr = add(&mul_exact(&re, &re), &mul_exact(&im, &im), working_precision);
+        let ln_abs = mul_pow_2(&ln(&abs_sqr, self.precision()), -1);
+        ComplexMPEl(ln_abs, atan2(&im, &re, self.precision()))
+    }
+
+    ///
+    /// Computes the square root of `x` with nonnegative real part.
+    ///
+    pub fn sqrt(&self, ComplexMPEl(re, im): ComplexMPEl) -> ComplexMPEl {
+        if is_zero(&re) && is_zero(&im) {
+            return self.zero();
+        }
+        let working_precision = self.precision() + 4;
+        let abs = self.abs(&ComplexMPEl(re.clone(), im.clone()));
+        if !is_neg(&re) {
+            let result_re = sqrt(&mul_pow_2(&add(&abs, &re, working_precision), -1), working_precision);
+            let result_im = div(&im, &mul_pow_2(&result_re, 1), self.precision());
+            ComplexMPEl(round(result_re.mantissa, result_re.exponent, self.precision()), result_im)
+        } else {
+            let mut result_im = sqrt(&mul_pow_2(&sub(&abs, &re, working_precision), -1), working_precision);
+            if is_neg(&im) {
+                result_im = negate(result_im);
+            }
+            let result_re = div(&im, &mul_pow_2(&result_im, 1), self.precision());
+            ComplexMPEl(result_re, round(result_im.mantissa, result_im.exponent, self.precision()))
+        }
+    }
+
+    ///
+    /// Computes the `n`-th root of unity `exp(2 pi i * i / n)`.
+    ///
+    pub fn root_of_unity(&self, i: i64, n: i64) -> ComplexMPEl {
+        assert!(n > 0);
+        let i = i.rem_euclid(n);
+        let working_precision = self.precision() + 8;
+        let angle = div(&mul_exact(&pi(working_precision), &from_integer(int_cast(2 * i, ZZ, StaticRing::<i64>::RING))), &from_integer(int_cast(n, ZZ, StaticRing::<i64>::RING)), working_precision);
+        let (cos, sin) = cos_sin(&angle, self.precision());
+        ComplexMPEl(cos, sin)
+    }
+
+    pub fn is_absolute_approx_eq(&self, lhs: ComplexMPEl, rhs: ComplexMPEl, absolute_threshold: &FloatMP) -> bool {
+        cmp(&self.abs(&self.sub(lhs, rhs)), absolute_threshold) == Ordering::Less
+    }
+
+    pub fn is_relative_approx_eq(&self, lhs: ComplexMPEl, rhs: ComplexMPEl, relative_limit: &FloatMP) -> bool {
+        let absolute_threshold = mul(&self.abs(&lhs), relative_limit, self.precision());
+        self.is_absolute_approx_eq(lhs, rhs, &absolute_threshold)
+    }
+
+    ///
+    /// Checks whether `lhs` and `rhs` are equal, up to an error of `precision` times the
+    /// precision of this ring.
+    ///
+    pub fn is_approx_eq(&self, lhs: ComplexMPEl, rhs: ComplexMPEl, precision: u64) -> bool {
+        let scaled_precision = scaled_precision(precision, self.precision());
+        if self.is_absolute_approx_eq(self.clone_el(&lhs), self.zero(), &scaled_precision) {
+            self.is_absolute_approx_eq(rhs, self.zero(), &scaled_precision)
+        } else {
+            self.is_relative_approx_eq(lhs, rhs, &scaled_precision)
+        }
+    }
+}
+
+impl RingValue<ComplexMPBase> {
+
+    pub fn precision(&self) -> usize { self.get_ring().precision() }
+
+    pub fn real_part_ring(&self) -> RealMP { self.get_ring().real_part_ring() }
+
+    pub fn imaginary_unit(&self) -> ComplexMPEl { self.get_ring().imaginary_unit() }
+
+    pub fn from_parts(&self, re: FloatMP, im: FloatMP) -> ComplexMPEl { self.get_ring().from_parts(re, im) }
+
+    pub fn re(&self, val: &ComplexMPEl) -> FloatMP { self.get_ring().re(val) }
+
+    pub fn im(&self, val: &ComplexMPEl) -> FloatMP { self.get_ring().im(val) }
+
+    pub fn from_f64(&self, x: f64) -> ComplexMPEl { self.get_ring().from_f64(x) }
+
+    pub fn abs(&self, val: &ComplexMPEl) -> FloatMP { self.get_ring().abs(val) }
+
+    pub fn conjugate(&self, val: ComplexMPEl) -> ComplexMPEl { self.get_ring().conjugate(val) }
+
+    pub fn exp(&self, exp: ComplexMPEl) -> ComplexMPEl { self.get_ring().exp(exp) }
+
+    pub fn ln_main_branch(&self, val: ComplexMPEl) -> ComplexMPEl { self.get_ring().ln_main_branch(val) }
+
+    pub fn sqrt(&self, val: ComplexMPEl) -> ComplexMPEl { self.get_ring().sqrt(val) }
+
+    pub fn root_of_unity(&self, i: i64, n: i64) -> ComplexMPEl { self.get_ring().root_of_unity(i, n) }
+
+    pub fn is_absolute_approx_eq(&self, lhs: ComplexMPEl, rhs: ComplexMPEl, absolute_threshold: &FloatMP) -> bool { self.get_ring().is_absolute_approx_eq(lhs, rhs, absolute_threshold) }
+
+    pub fn is_relative_approx_eq(&self, lhs: ComplexMPEl, rhs: ComplexMPEl, relative_limit: &FloatMP) -> bool { self.get_ring().is_relative_approx_eq(lhs, rhs, relative_limit) }
+
+    pub fn is_approx_eq(&self, lhs: ComplexMPEl, rhs: ComplexMPEl, precision: u64) -> bool { self.get_ring().is_approx_eq(lhs, rhs, precision) }
+}
+
+impl RingBase for ComplexMPBase {
+
+    type Element = ComplexMPEl;
+
+    fn clone_el(&self, val: &Self::Element) -> Self::Element {
+        val.clone()
+    }
+
+    fn add_assign_ref(&self, ComplexMPEl(lhs_re, lhs_im): &mut Self::Element, ComplexMPEl(rhs_re, rhs_im): &Self::Element) {
+        *lhs_re = add(lhs_re, rhs_re, self.precision());
+        *lhs_im = add(lhs_im, rhs_im, self.precision());
+    }
+
+    fn add_assign(&self, lhs: &mut Self::Element, rhs: Self::Element) {
+        self.add_assign_ref(lhs, &rhs);
+    }
+
+    fn negate_inplace(&self, ComplexMPEl(re, im): &mut Self::Element) {
+        ZZ.negate_inplace(&mut re.mantissa);
+        ZZ.negate_inplace(&mut im.mantissa);
+    }
+
+    fn mul_assign_ref(&self, ComplexMPEl(lhs_re, lhs_im): &mut Self::Element, ComplexMPEl(rhs_re, rhs_im): &Self::Element) {
+        let new_im = add(&mul_exact(lhs_re, rhs_im), &mul_exact(lhs_im, rhs_re), self.precision());
+        *lhs_re = sub(&mul_exact(lhs_re, rhs_re), &mul_exact(lhs_im, rhs_im), self.precision());
+        *lhs_im = new_im;
+    }
+
+    fn mul_assign(&self, lhs: &mut Self::Element, rhs: Self::Element) {
+        self.mul_assign_ref(lhs, &rhs);
+    }
+
+    fn from_int(&self, value: i32) -> Self::Element {
+        ComplexMPEl(self.real.from_int(value), zero())
+    }
+
+    fn eq_el(&self, _: &Self::Element, _: &Self::Element) -> bool {
+        panic!("Cannot provide equality on approximate rings")
+    }
+
+    fn is_commutative(&self) -> bool { true }
+
+    fn is_noetherian(&self) -> bool { true }
+
+    fn is_approximate(&self) -> bool { true }
+
+    fn dbg<'a>(&self, ComplexMPEl(re, im): &Self::Element, out: &mut std::fmt::Formatter<'a>) -> std::fmt::Result {
+        format_decimal(re, decimal_digits(self.precision()), out)?;
+        write!(out, " + ")?;
+        format_decimal(im, decimal_digits(self.precision()), out)?;
+        write!(out, "i")
+    }
+}
+
+impl_eq_based_self_iso!{ ComplexMPBase }
+
+impl<I: ?Sized + IntegerRing> CanHomFrom<I> for ComplexMPBase {
+
+    type Homomorphism = ();
+
+    fn has_canonical_hom(&self, _: &I) -> Option<Self::Homomorphism> {
+        Some(())
+    }
+
+    fn map_in(&self, from: &I, el: I::Element, _: &Self::Homomorphism) -> Self::Element {
+        ComplexMPEl(round(int_cast(el, ZZ, RingRef::new(from)), 0, self.precision()), zero())
+    }
+}
+
+impl CanHomFrom<RealMPBase> for ComplexMPBase {
+
+    type Homomorphism = ();
+
+    fn has_canonical_hom(&self, _: &RealMPBase) -> Option<Self::Homomorphism> {
+        Some(())
+    }
+
+    fn map_in(&self, _: &RealMPBase, el: FloatMP, _: &Self::Homomorphism) -> Self::Element {
+        ComplexMPEl(round(el.mantissa, el.exponent, self.precision()), zero())
+    }
+}
+
+impl DivisibilityRing for ComplexMPBase {
+
+    fn checked_left_div(&self, ComplexMPEl(lhs_re, lhs_im): &Self::Element, ComplexMPEl(rhs_re, rhs_im): &Self::Element) -> Option<Self::Element> {
+        if is_zero(rhs_re) && is_zero(rhs_im) {
+            return None;
+        }
+        let working_precision = self.precision() + 4;
+        let abs_sqr = add(&mul_exact(rhs_re, rhs_re), &mul_exact(rhs_im, rhs_im), working_precision);
+        let res_re = add(&mul_exact(lhs_re, rhs_re), &mul_exact(lhs_im, rhs_im), working_precision);
+        let res_im = sub(&mul_exact(lhs_im, rhs_re), &mul_exact(lhs_re, rhs_im), working_precision);
+        return Some(ComplexMPEl(div(&res_re, &abs_sqr, self.precision()), div(&res_im, &abs_sqr, self.precision())));
+    }
+}
+
+impl PrincipalIdealRing for ComplexMPBase {
+
+    fn ideal_gen(&self, _lhs: &Self::Element, _rhs: &Self::Element) -> (Self::Element, Self::Element, Self::Element) {
+        panic!("Since ComplexMP is only approximate, this cannot be implemented properly")
+    }
+}
+
+impl EuclideanRing for ComplexMPBase {
+
+    fn euclidean_div_rem(&self, _lhs: Self::Element, _rhs: &Self::Element) -> (Self::Element, Self::Element) {
+        panic!("Since ComplexMP is only approximate, this cannot be implemented properly")
+    }
+
+    fn euclidean_deg(&self, _: &Self::Element) -> Option<usize> {
+        panic!("Since ComplexMP is only approximate, this cannot be implemented properly")
+    }
+}
+
+impl Field for ComplexMPBase {
+
+    fn div(&self, lhs: &Self::Element, rhs: &Self::Element) -> Self::Element {
+        self.checked_left_div(lhs, rhs).expect("Division by zero")
+    }
+}
+
+impl ApproxComplexRing for ComplexMPBase {
+
+    fn relative_error(&self) -> f64 {
+        f64::max(2f64.powi(1 - self.precision() as i32), f64::MIN_POSITIVE)
+    }
+}
+
+#[cfg(test)]
+use crate::rings::float_complex::Complex64;
+#[cfg(test)]
+use crate::field::FieldStore;
+
+#[test]
+fn test_arithmetic() {
+    let R = RealMP::new(100);
+    let a = R.from_f64(1.5);
+    let b = R.from_f64(-0.25);
+    assert_eq!(1.25, R.to_f64(&R.add_ref(&a, &b)));
+    assert_eq!(-0.375, R.to_f64(&R.mul_ref(&a, &b)));
+    assert_eq!(-6., R.to_f64(&R.div(&a, &b)));
+    assert_eq!(1.75, R.to_f64(&R.sub_ref(&a, &b)));
+    assert!(R.is_approx_eq(R.div(&R.one(), &R.int_hom().map(3)), R.from_f64(1. / 3.), 1 << 50));
+    assert!(!R.is_approx_eq(R.div(&R.one(), &R.int_hom().map(3)), R.from_f64(1. / 3.), 1 << 40));
+    assert!(R.is_lt(&b, &a));
+    assert!(R.is_neg(&b));
+    assert!(R.is_gt(&R.add(R.one(), R.from_f64(2f64.powi(-90))), &R.one()));
+}
+
+#[test]
+fn test_sqrt_exp_ln() {
+    let R = RealMP::new(200);
+    let two = R.int_hom().map(2);
+    let sqrt_two = R.sqrt(R.clone_el(&two));
+    assert!(R.is_approx_eq(R.mul_ref(&sqrt_two, &sqrt_two), R.clone_el(&two), 4));
+    assert!(R.is_approx_eq(R.exp(R.ln(R.clone_el(&two))), R.clone_el(&two), 16));
+    assert!(R.is_approx_eq(R.ln(R.exp(R.from_f64(-13.5))), R.from_f64(-13.5), 16));
+    assert!(R.is_approx_eq(R.exp(R.from_f64(3.)), R.pow(R.exp(R.one()), 3), 16));
+    let x = R.add(R.one(), R.from_f64(2f64.powi(-100)));
+    let ln_x = R.sub(R.from_f64(2f64.powi(-100)), R.from_f64(2f64.powi(-201)));
+    assert!(R.is_approx_eq(R.ln(x), ln_x, 16));
+    assert!(R.is_approx_eq(R.int_hom().mul_map(R.atan(R.one()), 4), R.pi(), 16));
+    assert!((R.to_f64(&R.pi()) - std::f64::consts::PI).abs() < 1e-15);
+}
+
+#[test]
+fn test_exp_large_argument() {
+    let R = RealMP::new(200);
+    let x = R.int_hom().map(100000);
+    let exp_x = R.exp(R.clone_el(&x));
+    assert!(R.is_approx_eq(R.ln(R.clone_el(&exp_x)), R.clone_el(&x), 16));
+    assert!(R.is_approx_eq(R.mul(exp_x, R.exp(R.negate(R.clone_el(&x)))), R.one(), 16));
+    assert!(R.is_approx_eq(R.exp(R.from_f64(10.5)), R.mul(R.pow(R.exp(R.one()), 10), R.exp(R.from_f64(0.5))), 64));
+    assert_eq!(0., R.to_f64(&R.exp(R.from_f64(-2f64.powi(70)))));
+}
+
+#[test]
+#[should_panic(expected = "Overflow")]
+fn test_exp_overflow() {
+    let R = RealMP::new(200);
+    R.exp(R.from_f64(2f64.powi(70)));
+}
+
+#[test]
+fn test_complex_functions() {
+    let C = ComplexMP::new(150);
+    let i = C.imaginary_unit();
+    assert!(C.is_approx_eq(C.mul_ref(&i, &i), C.neg_one(), 1));
+    assert!(C.is_approx_eq(C.exp(C.mul(i.clone(), C.coerce(&C.real_part_ring(), C.real_part_ring().pi()))), C.neg_one(), 16));
+    let z = C.from_parts(C.real_part_ring().from_f64(-3.), C.real_part_ring().from_f64(4.));
+    assert!(C.is_approx_eq(C.pow(C.sqrt(C.clone_el(&z)), 2), C.clone_el(&z), 16));
+    assert!(C.is_approx_eq(C.exp(C.ln_main_branch(C.clone_el(&z))), C.clone_el(&z), 16));
+    let R = C.real_part_ring();
+    let expected = C.from_parts(R.div(&R.int_hom().map(-3), &R.int_hom().map(25)), R.div(&R.int_hom().map(-4), &R.int_hom().map(25)));
+    assert!(C.is_approx_eq(C.div(&C.one(), &z), expected, 16));
+    let zeta = C.root_of_unity(3, 7);
+    assert!(C.is_approx_eq(C.pow(C.clone_el(&zeta), 7), C.one(), 64));
+    assert!(C.is_approx_eq(C.mul_ref(&zeta, &C.root_of_unity(-3, 7)), C.one(), 16));
+    let CC = Complex64::RING;
+    let zeta_f64 = CC.root_of_unity(3, 7);
+    assert!(CC.is_approx_eq(zeta_f64, CC.add(CC.from_f64(C.real_part_ring().to_f64(&C.re(&zeta))), CC.mul(Complex64::I, CC.from_f64(C.real_part_ring().to_f64(&C.im(&zeta))))), 4));
+}
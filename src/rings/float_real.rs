@@ -0,0 +1,295 @@
+use std::cmp::Ordering;
+use std::f64::EPSILON;
+
+use crate::pid::{EuclideanRing, PrincipalIdealRing};
+use crate::field::Field;
+use crate::integer::IntegerRing;
+use crate::ordered::OrderedRing;
+use crate::ring::*;
+use crate::homomorphism::*;
+use crate::divisibility::DivisibilityRing;
+use crate::rings::float_complex::{Complex64, Complex64El};
+
+///
+/// An implementation of the field of real numbers `R`, using 64-bit floating point
+/// numbers.
+///
+/// This ring is approximate (see [`RingBase::is_approximate()`]). However, contrary to
+/// [`Complex64`], equality is implemented as exact equality of the underlying `f64` values.
+/// This makes it possible to use `Real64` as base ring of other rings, e.g. of
+/// [`crate::rings::poly::dense_poly::DensePolyRing`], or to do linear algebra over it,
+/// since these require at least a test for zero. Note however that because of rounding
+/// errors, two values that are equal in theory are usually not equal in practice. Hence,
+/// to compare computed values, use [`Real64::is_approx_eq()`] instead.
+///
+/// In particular, generic code must not assume that [`RingBase::eq_el()`] panics for rings
+/// that are approximate, but only that computations with their elements are inexact.
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::homomorphism::*;
+/// # use feanor_math::rings::poly::*;
+/// # use feanor_math::rings::poly::dense_poly::*;
+/// # use feanor_math::rings::float_real::*;
+/// let RR = Real64::RING;
+/// let poly_ring = DensePolyRing::new(RR, "X");
+/// let f = poly_ring.from_terms([(-2., 0), (1., 2)].into_iter());
+/// let sqrt_2 = RR.sqrt(2.);
+/// assert!(RR.is_absolute_approx_eq(0., poly_ring.evaluate(&f, &sqrt_2, &RR.identity()), 1e-15));
+/// ```
+///
+#[derive(Clone, Copy, PartialEq)]
+pub struct Real64;
+
+impl Real64 {
+
+    pub const RING: RingValue<Real64> = RingValue::from(Real64);
+
+    pub fn sqrt(&self, x: f64) -> f64 {
+        x.sqrt()
+    }
+
+    pub fn exp(&self, x: f64) -> f64 {
+        x.exp()
+    }
+
+    pub fn ln(&self, x: f64) -> f64 {
+        x.ln()
+    }
+
+    pub fn is_absolute_approx_eq(&self, lhs: f64, rhs: f64, absolute_threshold: f64) -> bool {
+        (lhs - rhs).abs() < absolute_threshold
+    }
+
+    pub fn is_relative_approx_eq(&self, lhs: f64, rhs: f64, relative_limit: f64) -> bool {
+        self.is_absolute_approx_eq(lhs, rhs, lhs.abs() * relative_limit)
+    }
+
+    pub fn is_approx_eq(&self, lhs: f64, rhs: f64, precision: u64) -> bool {
+        let scaled_precision = precision as f64 * EPSILON;
+        if self.is_absolute_approx_eq(lhs, 0., scaled_precision) {
+            self.is_absolute_approx_eq(rhs, 0., scaled_precision)
+        } else {
+            self.is_relative_approx_eq(lhs, rhs, scaled_precision)
+        }
+    }
+}
+
+impl RingValue<Real64> {
+
+    pub fn sqrt(&self, x: f64) -> f64 { self.get_ring().sqrt(x) }
+
+    pub fn exp(&self, x: f64) -> f64 { self.get_ring().exp(x) }
+
+    pub fn ln(&self, x: f64) -> f64 { self.get_ring().ln(x) }
+
+    pub fn is_absolute_approx_eq(&self, lhs: f64, rhs: f64, absolute_threshold: f64) -> bool { self.get_ring().is_absolute_approx_eq(lhs, rhs, absolute_threshold) }
+
+    pub fn is_relative_approx_eq(&self, lhs: f64, rhs: f64, relative_limit: f64) -> bool { self.get_ring().is_relative_approx_eq(lhs, rhs, relative_limit) }
+
+    pub fn is_approx_eq(&self, lhs: f64, rhs: f64, precision: u64) -> bool { self.get_ring().is_approx_eq(lhs, rhs, precision) }
+}
+
+impl RingBase for Real64 {
+
+    type Element = f64;
+
+    fn clone_el(&self, val: &Self::Element) -> Self::Element {
+        *val
+    }
+
+    fn add_assign(&self, lhs: &mut Self::Element, rhs: Self::Element) {
+        *lhs += rhs;
+    }
+
+    fn negate_inplace(&self, lhs: &mut Self::Element) {
+        *lhs = -*lhs;
+    }
+
+    fn mul_assign(&self, lhs: &mut Self::Element, rhs: Self::Element) {
+        *lhs *= rhs;
+    }
+
+    fn from_int(&self, value: i32) -> Self::Element {
+        value as f64
+    }
+
+    fn eq_el(&self, lhs: &Self::Element, rhs: &Self::Element) -> bool {
+        // in contrast to `Complex64`, we provide exact equality, since otherwise `Real64` could not
+        // be used as base ring of polynomial rings or matrices, see the documentation of `Real64`
+        *lhs == *rhs
+    }
+
+    fn is_commutative(&self) -> bool { true }
+
+    fn is_noetherian(&self) -> bool { true }
+
+    fn is_approximate(&self) -> bool { true }
+
+    fn dbg<'a>(&self, value: &Self::Element, out: &mut std::fmt::Formatter<'a>) -> std::fmt::Result {
+        write!(out, "{}", value)
+    }
+}
+
+impl_eq_based_self_iso!{ Real64 }
+
+impl<I: ?Sized + IntegerRing> CanHomFrom<I> for Real64 {
+
+    type Homomorphism = ();
+
+    fn has_canonical_hom(&self, _: &I) -> Option<Self::Homomorphism> {
+        Some(())
+    }
+
+    fn map_in(&self, from: &I, el: I::Element, _: &Self::Homomorphism) -> Self::Element {
+        from.to_float_approx(&el)
+    }
+}
+
+impl CanHomFrom<Real64> for Complex64 {
+
+    type Homomorphism = ();
+
+    fn has_canonical_hom(&self, _: &Real64) -> Option<Self::Homomorphism> {
+        Some(())
+    }
+
+    fn map_in(&self, _: &Real64, el: f64, _: &Self::Homomorphism) -> Complex64El {
+        self.from_f64(el)
+    }
+}
+
+impl OrderedRing for Real64 {
+
+    fn cmp(&self, lhs: &Self::Element, rhs: &Self::Element) -> Ordering {
+        lhs.partial_cmp(rhs).expect("Cannot compare NaN")
+    }
+}
+
+impl DivisibilityRing for Real64 {
+
+    fn checked_left_div(&self, lhs: &Self::Element, rhs: &Self::Element) -> Option<Self::Element> {
+        if *rhs == 0. {
+            None
+        } else {
+            Some(*lhs / *rhs)
+        }
+    }
+}
+
+impl PrincipalIdealRing for Real64 {
+
+    fn ideal_gen(&self, lhs: &Self::Element, rhs: &Self::Element) -> (Self::Element, Self::Element, Self::Element) {
+        if *lhs != 0. {
+            (1. / *lhs, 0., 1.)
+        } else if *rhs != 0. {
+            (0., 1. / *rhs, 1.)
+        } else {
+            (0., 0., 0.)
+        }
+    }
+}
+
+impl EuclideanRing for Real64 {
+
+    fn euclidean_div_rem(&self, lhs: Self::Element, rhs: &Self::Element) -> (Self::Element, Self::Element) {
+        assert!(*rhs != 0.);
+        (lhs / *rhs, 0.)
+    }
+
+    fn euclidean_deg(&self, val: &Self::Element) -> Option<usize> {
+        if *val == 0. {
+            Some(0)
+        } else {
+            Some(1)
+        }
+    }
+}
+
+impl Field for Real64 {}
+
+#[cfg(test)]
+use crate::rings::poly::*;
+#[cfg(test)]
+use crate::rings::poly::dense_poly::DensePolyRing;
+#[cfg(test)]
+use crate::ordered::OrderedRingStore;
+#[cfg(test)]
+use crate::field::FieldStore;
+#[cfg(test)]
+use crate::primitive_int::StaticRing;
+#[cfg(test)]
+use crate::algorithms::smith::{DenseMatrix, solve_right};
+#[cfg(test)]
+use crate::matrix::Matrix;
+
+#[test]
+fn test_ordered_field() {
+    let RR = Real64::RING;
+    assert!(RR.is_lt(&-1., &0.5));
+    assert!(RR.is_neg(&RR.sub(RR.one(), RR.int_hom().map(2))));
+    assert_eq!(0.25, RR.div(&1., &4.));
+    assert!(RR.is_approx_eq(RR.div(&1., &3.) * 3., 1., 1));
+    assert_eq!(3., RR.abs(-3.));
+    assert_eq!(1024., RR.pow(2., 10));
+    // exponents that are not exactly representable as `f64` are not rounded
+    assert_eq!(-1., RR.pow_gen(-1., &((1 << 53) + 1), StaticRing::<i64>::RING));
+}
+
+#[test]
+fn test_canonical_homs() {
+    let RR = Real64::RING;
+    let CC = Complex64::RING;
+    assert_eq!(-7., RR.coerce(&StaticRing::<i64>::RING, -7));
+    let x = CC.coerce(&RR, 2.5);
+    assert!(CC.is_approx_eq(CC.from_f64(2.5), x, 1));
+    let hom = CC.can_hom(&RR).unwrap();
+    assert!(CC.is_approx_eq(CC.from_f64(-1.5), hom.map(-1.5), 1));
+}
+
+#[test]
+fn test_polynomial_root_finding() {
+    let RR = Real64::RING;
+    let poly_ring = DensePolyRing::new(RR, "X");
+    // (X^2 - 2)(X - 3)
+    let f = poly_ring.mul(
+        poly_ring.from_terms([(-2., 0), (1., 2)].into_iter()),
+        poly_ring.from_terms([(-3., 0), (1., 1)].into_iter())
+    );
+    assert_eq!(Some(3), poly_ring.degree(&f));
+    let df = poly_ring.from_terms(poly_ring.terms(&f).filter(|(_, i)| *i > 0).map(|(c, i)| (c * i as f64, i - 1)));
+
+    let mut x = 1.;
+    for _ in 0..20 {
+        x = x - poly_ring.evaluate(&f, &x, &RR.identity()) / poly_ring.evaluate(&df, &x, &RR.identity());
+    }
+    assert!(RR.is_approx_eq(2f64.sqrt(), x, 2));
+
+    let (q, r) = poly_ring.div_rem_monic(poly_ring.clone_el(&f), &poly_ring.from_terms([(-3., 0), (1., 1)].into_iter()));
+    assert!(poly_ring.is_zero(&r));
+    assert_eq!(-2., *poly_ring.coefficient_at(&q, 0));
+}
+
+#[test]
+fn test_least_squares() {
+    let RR = Real64::RING;
+    // fit `y = a + b x` to the points (0, 1), (1, 3), (2, 5.5)
+    let points = [(0., 1.), (1., 3.), (2., 5.5)];
+    let mut A = DenseMatrix::zero(3, 2, RR);
+    let mut At = DenseMatrix::zero(2, 3, RR);
+    let mut y = DenseMatrix::zero(3, 1, RR);
+    for (i, (x_i, y_i)) in points.iter().enumerate() {
+        *A.at_mut(i, 0) = 1.;
+        *A.at_mut(i, 1) = *x_i;
+        *At.at_mut(0, i) = 1.;
+        *At.at_mut(1, i) = *x_i;
+        *y.at_mut(i, 0) = *y_i;
+    }
+    // solve the normal equations `A^T A (a, b) = A^T y`
+    let mut AtA = At.mul(&A, RR);
+    let Aty = At.mul(&y, RR);
+    let solution = solve_right(&mut AtA, Aty, RR).unwrap();
+    assert!(RR.is_approx_eq(11. / 12., *solution.at(0, 0), 8));
+    assert!(RR.is_approx_eq(2.25, *solution.at(1, 0), 8));
+}
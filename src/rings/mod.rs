@@ -19,6 +19,12 @@ pub mod zn;
 /// 
 pub mod poly;
 
+///
+/// This module contains [`power_series::PowerSeriesRing`], an implementation of the ring of
+/// power series `R[[X]]` over any base ring, truncated at a fixed precision.
+/// 
+pub mod power_series;
+
 ///
 /// This module contains the wrapper [`field::AsField`] that can be used to create a ring
 /// implementing [`crate::field::Field`] from rings that are fields, but do not implement
@@ -26,12 +32,43 @@ pub mod poly;
 /// 
 pub mod field;
 
+///
+/// This module contains [`rational::RationalField`], an implementation of the field of
+/// rational numbers `Q` as fractions over an integer ring.
+/// 
+pub mod rational;
+
 ///
 /// An implementation of the field of complex numbers `C`, using 64-bit floating point
 /// numbers.
 /// 
 pub mod float_complex;
 
+///
+/// An implementation of the field of real numbers `R`, using 64-bit floating point
+/// numbers.
+/// 
+pub mod float_real;
+
+///
+/// This module contains [`float_mp::RealMP`] and [`float_mp::ComplexMP`], implementations of
+/// the fields of real resp. complex numbers using binary floating point numbers of arbitrary 
+/// precision.
+/// 
+pub mod float_mp;
+
+///
+/// This module contains [`ball::RealBall`] and [`ball::ComplexBall`], which represent real resp.
+/// complex numbers by balls around a floating point midpoint, and provide certified error bounds.
+/// 
+pub mod ball;
+
+///
+/// This module contains [`quadratic::QuadraticIntegerRing`], an implementation of the Euclidean
+/// rings of integers of imaginary quadratic number fields, like the Gaussian integers `Z[i]`.
+/// 
+pub mod quadratic;
+
 ///
 /// This module contains the trait [`extension::FreeAlgebra`] for rings that are free modules of finite
 /// rank over a base ring. It also provides one implementation in [`extension::extension_impl`] based
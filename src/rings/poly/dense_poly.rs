@@ -6,6 +6,8 @@ use crate::mempool::{DefaultMemoryProvider, GrowableMemoryProvider};
 use crate::vector::VectorViewMut;
 use crate::ring::*;
 use crate::algorithms;
+use crate::algorithms::conv_mul::FFTConvolutionHint;
+use crate::algorithms::eea::EEAHint;
 use crate::rings::poly::*;
 
 use std::cmp::min;
@@ -105,6 +107,18 @@ impl<R: RingStore, M: GrowableMemoryProvider<El<R>>> DensePolyRingBase<R, M> {
         }
     }
 
+    ///
+    /// Whether division with remainder should be performed using [`algorithms::poly_div::fast_poly_div_rem()`],
+    /// which is asymptotically faster, but has a larger overhead than the standard algorithm. This only
+    /// pays off if the base ring supports FFT-based multiplication (see [`FFTConvolutionHint`]), and the
+    /// inputs are somewhat larger than the length from which on the FFT is used.
+    /// 
+    fn use_fast_poly_div(&self, lhs: &M::Object, rhs: &M::Object) -> bool {
+        let lhs_deg = self.degree(lhs).unwrap_or(0);
+        let rhs_deg = self.degree(rhs).unwrap();
+        lhs_deg >= rhs_deg && min(lhs_deg - rhs_deg + 1, rhs_deg) >= self.base_ring().get_ring().fft_convolution_threshold().saturating_mul(4)
+    }
+
     fn poly_div<F>(&self, lhs: &mut M::Object, rhs: &M::Object, mut left_div_lc: F) -> Option<M::Object>
         where F: FnMut(El<R>) -> Option<El<R>>
     {
@@ -393,6 +407,9 @@ impl<R, M: GrowableMemoryProvider<El<R>>> PolyRing for DensePolyRingBase<R, M>
 
     fn div_rem_monic(&self, mut lhs: Self::Element, rhs: &Self::Element) -> (Self::Element, Self::Element) {
         assert!(self.base_ring().is_one(self.coefficient_at(rhs, self.degree(rhs).unwrap())));
+        if self.use_fast_poly_div(&lhs, rhs) {
+            return algorithms::poly_div::fast_poly_div_rem(RingRef::new(self), lhs, rhs, &self.base_ring().one());
+        }
         let quo = self.poly_div(&mut lhs, rhs, |x| Some(x)).unwrap();
         return (quo, lhs);
     }
@@ -448,6 +465,9 @@ impl<R, M: GrowableMemoryProvider<El<R>>> EuclideanRing for DensePolyRingBase<R,
 {
     fn euclidean_div_rem(&self, mut lhs: Self::Element, rhs: &Self::Element) -> (Self::Element, Self::Element) {
         let lc_inv = self.base_ring.invert(&rhs[self.degree(rhs).unwrap()]).unwrap();
+        if self.use_fast_poly_div(&lhs, rhs) {
+            return algorithms::poly_div::fast_poly_div_rem(RingRef::new(self), lhs, rhs, &lc_inv);
+        }
         let quo = self.poly_div(&mut lhs, rhs, |x| Some(self.base_ring().mul_ref_snd(x, &lc_inv))).unwrap();
         return (quo, lhs);
     }
@@ -457,6 +477,22 @@ impl<R, M: GrowableMemoryProvider<El<R>>> EuclideanRing for DensePolyRingBase<R,
     }
 }
 
+impl<R, M: GrowableMemoryProvider<El<R>>> EEAHint for DensePolyRingBase<R, M> 
+    where R: RingStore
+{
+    fn fast_eea(&self, lhs: &Self::Element, rhs: &Self::Element) -> Option<(Self::Element, Self::Element, Self::Element)>
+        where Self: EuclideanRing
+    {
+        // as for division, the half-gcd algorithm only pays off if the base ring supports FFT-based multiplication
+        let min_deg = min(self.degree(lhs).unwrap_or(0), self.degree(rhs).unwrap_or(0));
+        if min_deg >= self.base_ring().get_ring().fft_convolution_threshold().saturating_mul(4) {
+            Some(algorithms::half_gcd::half_gcd_eea(RingRef::new(self), self.clone_el(lhs), self.clone_el(rhs)))
+        } else {
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 use crate::rings::zn::*;
 #[cfg(test)]
@@ -509,6 +545,20 @@ fn test_euclidean_ring_axioms() {
     crate::pid::generic_tests::test_euclidean_ring_axioms(&poly_ring, edge_case_elements(&poly_ring));
 }
 
+#[test]
+fn test_derivative_content_primitive_part() {
+    let ZZ = StaticRing::<i64>::RING;
+    let poly_ring = DensePolyRing::new(ZZ, "X");
+    let f = poly_ring.from_terms([(6, 0), (-4, 1), (10, 3)].into_iter());
+    assert_el_eq!(&poly_ring, &poly_ring.from_terms([(-4, 0), (30, 2)].into_iter()), &poly_ring.derivative(&f));
+    assert_eq!(2, poly_ring.content(&f).abs());
+    let primitive = poly_ring.primitive_part(poly_ring.clone_el(&f));
+    assert_eq!(1, poly_ring.content(&primitive).abs());
+    assert_el_eq!(&poly_ring, &f, &poly_ring.inclusion().mul_map(primitive, poly_ring.content(&f)));
+    assert!(poly_ring.is_zero(&poly_ring.derivative(&poly_ring.one())));
+    assert_eq!(0, poly_ring.content(&poly_ring.zero()));
+}
+
 #[test]
 fn test_canonical_iso_axioms_different_base_ring() {
     let poly_ring1 = DensePolyRing::new(zn_barett::Zn::new(StaticRing::<i128>::RING, 7), "X");
@@ -523,4 +573,58 @@ fn test_canonical_iso_sparse_poly_ring() {
     let poly_ring2 = DensePolyRing::new(zn_42::Zn::new(7), "X");
     crate::ring::generic_tests::test_hom_axioms(&poly_ring1, &poly_ring2, edge_case_elements(&poly_ring1));
     crate::ring::generic_tests::test_iso_axioms(&poly_ring1, &poly_ring2, edge_case_elements(&poly_ring1));
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_mul_fft() {
+    // degrees are large enough that `zn_64::Zn` uses FFT-based multiplication, but `zn_barett::Zn<StaticRing<i128>>` does not
+    for modulus in [65537, 1000003] {
+        let fft_ring = DensePolyRing::new(zn_64::Zn::new(modulus as u64), "X");
+        let ref_ring = DensePolyRing::new(zn_barett::Zn::new(StaticRing::<i128>::RING, modulus as i128), "X");
+        let f_coeffs = (0..300).map(|i| (i * i + 7) % modulus).collect::<Vec<_>>();
+        let g_coeffs = (0..400).map(|i| (3 * i + 1) % modulus).collect::<Vec<_>>();
+        let fft_product = fft_ring.mul(
+            fft_ring.from_terms(f_coeffs.iter().enumerate().map(|(i, c)| (fft_ring.base_ring().int_hom().map(*c), i))),
+            fft_ring.from_terms(g_coeffs.iter().enumerate().map(|(i, c)| (fft_ring.base_ring().int_hom().map(*c), i)))
+        );
+        let ref_product = ref_ring.mul(
+            ref_ring.from_terms(f_coeffs.iter().enumerate().map(|(i, c)| (ref_ring.base_ring().int_hom().map(*c), i))),
+            ref_ring.from_terms(g_coeffs.iter().enumerate().map(|(i, c)| (ref_ring.base_ring().int_hom().map(*c), i)))
+        );
+        assert_eq!(Some(698), fft_ring.degree(&fft_product));
+        for i in 0..=698 {
+            assert_eq!(
+                ref_ring.base_ring().smallest_positive_lift(*ref_ring.coefficient_at(&ref_product, i)) as i64, 
+                fft_ring.base_ring().smallest_positive_lift(*fft_ring.coefficient_at(&fft_product, i))
+            );
+        }
+    }
+}
+
+#[test]
+fn test_div_rem_monic_fast() {
+    // degrees are large enough that `div_rem_monic()` uses `fast_poly_div_rem()`
+    let P = DensePolyRing::new(zn_64::Zn::new(65537), "X");
+    let lhs = P.from_terms((0..2500).map(|i| (P.base_ring().int_hom().map(i * i + 7), i as usize)));
+    let rhs = P.from_terms((0..1100).map(|i| (P.base_ring().int_hom().map(3 * i + 1), i as usize)).chain([(P.base_ring().one(), 1100)].into_iter()));
+    let (quo, rem) = P.div_rem_monic(P.clone_el(&lhs), &rhs);
+    assert!(P.degree(&rem).unwrap() < 1100);
+    assert_el_eq!(&P, &lhs, &P.add(P.mul(quo, rhs), rem));
+}
+
+#[test]
+fn test_eea_half_gcd() {
+    // degrees are large enough that `eea()` uses the half-gcd algorithm
+    let P = DensePolyRing::new(zn_64::Zn::new(65537).as_field().ok().unwrap(), "X");
+    let hom = P.base_ring().int_hom();
+    let mut rng = oorandom::Rand32::new(1);
+    let mut random_poly = |deg: usize| P.from_terms((0..=deg).map(|i| (hom.map((rng.rand_u32() % 65537) as i32), i)));
+    let common_factor = random_poly(50);
+    let f = P.mul_ref(&common_factor, &random_poly(1000));
+    let g = P.mul_ref(&common_factor, &random_poly(1010));
+    let (s, t, d) = algorithms::eea::eea(P.clone_el(&f), P.clone_el(&g), &P);
+    assert_el_eq!(&P, &d, &P.add(P.mul_ref(&s, &f), P.mul_ref(&t, &g)));
+    assert!(P.checked_div(&f, &d).is_some());
+    assert!(P.checked_div(&g, &d).is_some());
+    assert!(P.checked_div(&d, &common_factor).is_some());
+}
@@ -0,0 +1,500 @@
+use crate::algorithms;
+use crate::divisibility::*;
+use crate::pid::*;
+use crate::ring::*;
+use crate::homomorphism::*;
+use crate::integer::*;
+use crate::rings::zn::zn_static;
+use crate::rings::poly::*;
+use crate::rings::poly::dense_poly::DensePolyRingBase;
+use crate::rings::poly::sparse_poly::SparsePolyRingBase;
+use crate::mempool::GrowableMemoryProvider;
+
+static ZERO: u64 = 0;
+static ONE: u64 = 1;
+
+///
+/// The polynomial ring `F2[X]`, where polynomials are stored bit-packed into `u64`s.
+///
+/// Compared to a [`dense_poly::DensePolyRing`] over [`zn_static::Fp<2>`], this uses
+/// 64 times less memory, and addition resp. multiplication work on whole words using
+/// xor resp. carry-less multiplication. Since all elements of `F2` are either zero or one,
+/// the coefficients returned by [`PolyRing::coefficient_at()`] and [`PolyRing::terms()`]
+/// are references to static constants.
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::rings::poly::*;
+/// # use feanor_math::rings::poly::gf2_poly::*;
+/// # use feanor_math::assert_el_eq;
+/// let P = GF2PolyRing::new("X");
+/// let x = P.indeterminate();
+/// let f = P.add(P.pow(P.clone_el(&x), 2), P.one());
+/// // in characteristic 2, we have `(X + 1)^2 = X^2 + 1`
+/// assert_el_eq!(&P, &f, &P.pow(P.add(x, P.one()), 2));
+/// ```
+///
+#[derive(Clone, Copy)]
+pub struct GF2PolyRingBase {
+    base_ring: zn_static::Fp<2>,
+    unknown_name: &'static str
+}
+
+///
+/// The polynomial ring `F2[X]`. For details, see [`GF2PolyRingBase`].
+///
+pub type GF2PolyRing = RingValue<GF2PolyRingBase>;
+
+impl GF2PolyRing {
+
+    pub const fn new(unknown_name: &'static str) -> Self {
+        RingValue::from(GF2PolyRingBase::new(unknown_name))
+    }
+}
+
+impl GF2PolyRingBase {
+
+    pub const fn new(unknown_name: &'static str) -> Self {
+        GF2PolyRingBase {
+            base_ring: zn_static::Fp::<2>::RING,
+            unknown_name: unknown_name
+        }
+    }
+
+    ///
+    /// Returns the polynomial whose coefficients are given by the bits of the given words,
+    /// i.e. the coefficient of `X^i` is the `i % 64`-th bit of `words[i / 64]`.
+    ///
+    pub fn from_words(&self, mut words: Vec<u64>) -> <Self as RingBase>::Element {
+        normalize(&mut words);
+        return words;
+    }
+
+    ///
+    /// Returns the bit-packed coefficients of the given polynomial, as described in
+    /// [`GF2PolyRingBase::from_words()`].
+    ///
+    pub fn words<'a>(&self, el: &'a <Self as RingBase>::Element) -> &'a [u64] {
+        &el[..]
+    }
+}
+
+///
+/// Computes the product of the two polynomials over `F2` whose coefficients are given
+/// by the bits of `lhs` and `rhs`.
+///
+pub fn carryless_mul(lhs: u64, rhs: u64) -> u128 {
+    let mut table = [0u128; 16];
+    for i in 1..16 {
+        table[i] = if i % 2 == 0 { table[i / 2] << 1 } else { table[i - 1] ^ lhs as u128 };
+    }
+    let mut result = 0;
+    for i in (0..16).rev() {
+        result = (result << 4) ^ table[((rhs >> (4 * i)) & 0xF) as usize];
+    }
+    return result;
+}
+
+///
+/// Inserts a zero bit after each bit of the input, which corresponds to squaring the
+/// polynomial over `F2` whose coefficients are given by the bits of `x`.
+///
+fn spread_bits(x: u32) -> u64 {
+    let mut x = x as u64;
+    x = (x | (x << 16)) & 0x0000FFFF0000FFFF;
+    x = (x | (x << 8)) & 0x00FF00FF00FF00FF;
+    x = (x | (x << 4)) & 0x0F0F0F0F0F0F0F0F;
+    x = (x | (x << 2)) & 0x3333333333333333;
+    x = (x | (x << 1)) & 0x5555555555555555;
+    return x;
+}
+
+fn normalize(el: &mut Vec<u64>) {
+    while el.last() == Some(&0) {
+        el.pop();
+    }
+}
+
+fn degree(el: &[u64]) -> Option<usize> {
+    el.last().map(|w| 64 * (el.len() - 1) + 63 - w.leading_zeros() as usize)
+}
+
+fn xor_shifted(lhs: &mut Vec<u64>, rhs: &[u64], shift: usize) {
+    let (words, bits) = (shift / 64, shift % 64);
+    if lhs.len() < rhs.len() + words + 1 {
+        lhs.resize(rhs.len() + words + 1, 0);
+    }
+    for (i, w) in rhs.iter().enumerate() {
+        lhs[i + words] ^= w << bits;
+        if bits != 0 {
+            lhs[i + words + 1] ^= w >> (64 - bits);
+        }
+    }
+    normalize(lhs);
+}
+
+fn div_rem(mut lhs: Vec<u64>, rhs: &[u64]) -> (Vec<u64>, Vec<u64>) {
+    let d = degree(rhs).unwrap();
+    let mut quo = Vec::new();
+    while let Some(e) = degree(&lhs).filter(|e| *e >= d) {
+        if quo.is_empty() {
+            quo.resize((e - d) / 64 + 1, 0);
+        }
+        quo[(e - d) / 64] |= 1 << ((e - d) % 64);
+        xor_shifted(&mut lhs, rhs, e - d);
+    }
+    return (quo, lhs);
+}
+
+impl PartialEq for GF2PolyRingBase {
+
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl RingBase for GF2PolyRingBase {
+
+    type Element = Vec<u64>;
+
+    fn clone_el(&self, val: &Self::Element) -> Self::Element {
+        val.clone()
+    }
+
+    fn add_assign_ref(&self, lhs: &mut Self::Element, rhs: &Self::Element) {
+        xor_shifted(lhs, rhs, 0);
+    }
+
+    fn add_assign(&self, lhs: &mut Self::Element, rhs: Self::Element) {
+        self.add_assign_ref(lhs, &rhs);
+    }
+
+    fn sub_assign_ref(&self, lhs: &mut Self::Element, rhs: &Self::Element) {
+        self.add_assign_ref(lhs, rhs);
+    }
+
+    fn negate_inplace(&self, _: &mut Self::Element) {}
+
+    fn mul_assign(&self, lhs: &mut Self::Element, rhs: Self::Element) {
+        self.mul_assign_ref(lhs, &rhs);
+    }
+
+    fn mul_assign_ref(&self, lhs: &mut Self::Element, rhs: &Self::Element) {
+        *lhs = self.mul_ref(lhs, rhs);
+    }
+
+    fn mul_ref(&self, lhs: &Self::Element, rhs: &Self::Element) -> Self::Element {
+        if lhs.is_empty() || rhs.is_empty() {
+            return Vec::new();
+        }
+        let mut result = vec![0; lhs.len() + rhs.len()];
+        for (i, a) in lhs.iter().enumerate() {
+            for (j, b) in rhs.iter().enumerate() {
+                let prod = carryless_mul(*a, *b);
+                result[i + j] ^= prod as u64;
+                result[i + j + 1] ^= (prod >> 64) as u64;
+            }
+        }
+        normalize(&mut result);
+        return result;
+    }
+
+    fn square(&self, value: &mut Self::Element) {
+        *value = value.iter().flat_map(|w| [spread_bits(*w as u32), spread_bits((*w >> 32) as u32)]).collect();
+        normalize(value);
+    }
+
+    fn zero(&self) -> Self::Element {
+        Vec::new()
+    }
+
+    fn from_int(&self, value: i32) -> Self::Element {
+        if value % 2 == 0 { Vec::new() } else { vec![1] }
+    }
+
+    fn mul_assign_int(&self, lhs: &mut Self::Element, rhs: i32) {
+        if rhs % 2 == 0 {
+            lhs.clear();
+        }
+    }
+
+    fn eq_el(&self, lhs: &Self::Element, rhs: &Self::Element) -> bool {
+        lhs == rhs
+    }
+
+    fn is_zero(&self, value: &Self::Element) -> bool {
+        value.is_empty()
+    }
+
+    fn is_commutative(&self) -> bool { true }
+
+    fn is_noetherian(&self) -> bool { true }
+
+    fn dbg<'a>(&self, value: &Self::Element, out: &mut std::fmt::Formatter<'a>) -> std::fmt::Result {
+        super::generic_impls::dbg_poly(self, value, out, self.unknown_name)
+    }
+}
+
+impl HashableElRing for GF2PolyRingBase {
+
+    fn hash<H: std::hash::Hasher>(&self, el: &Self::Element, h: &mut H) {
+        std::hash::Hash::hash(el, h)
+    }
+}
+
+impl_eq_based_self_iso!{ GF2PolyRingBase }
+
+impl<R, M> CanHomFrom<DensePolyRingBase<R, M>> for GF2PolyRingBase
+    where R: RingStore, zn_static::ZnBase<2, true>: CanHomFrom<R::Type>, M: GrowableMemoryProvider<El<R>>
+{
+    type Homomorphism = super::generic_impls::GenericCanHomFrom<DensePolyRingBase<R, M>, Self>;
+
+    fn has_canonical_hom(&self, from: &DensePolyRingBase<R, M>) -> Option<Self::Homomorphism> {
+        super::generic_impls::generic_has_canonical_hom(from, self)
+    }
+
+    fn map_in(&self, from: &DensePolyRingBase<R, M>, el: <DensePolyRingBase<R, M> as RingBase>::Element, hom: &Self::Homomorphism) -> Self::Element {
+        super::generic_impls::generic_map_in(from, self, el, hom)
+    }
+}
+
+impl<R, M> CanonicalIso<DensePolyRingBase<R, M>> for GF2PolyRingBase
+    where R: RingStore, zn_static::ZnBase<2, true>: CanonicalIso<R::Type>, M: GrowableMemoryProvider<El<R>>
+{
+    type Isomorphism = super::generic_impls::GenericCanonicalIso<DensePolyRingBase<R, M>, Self>;
+
+    fn has_canonical_iso(&self, from: &DensePolyRingBase<R, M>) -> Option<Self::Isomorphism> {
+        self.base_ring().get_ring().has_canonical_iso(from.base_ring().get_ring())
+    }
+
+    fn map_out(&self, from: &DensePolyRingBase<R, M>, el: Self::Element, iso: &Self::Isomorphism) -> <DensePolyRingBase<R, M> as RingBase>::Element {
+        super::generic_impls::generic_map_out(from, self, el, iso)
+    }
+}
+
+impl<R> CanHomFrom<SparsePolyRingBase<R>> for GF2PolyRingBase
+    where R: RingStore, zn_static::ZnBase<2, true>: CanHomFrom<R::Type>
+{
+    type Homomorphism = super::generic_impls::GenericCanHomFrom<SparsePolyRingBase<R>, Self>;
+
+    fn has_canonical_hom(&self, from: &SparsePolyRingBase<R>) -> Option<Self::Homomorphism> {
+        super::generic_impls::generic_has_canonical_hom(from, self)
+    }
+
+    fn map_in(&self, from: &SparsePolyRingBase<R>, el: <SparsePolyRingBase<R> as RingBase>::Element, hom: &Self::Homomorphism) -> Self::Element {
+        super::generic_impls::generic_map_in(from, self, el, hom)
+    }
+}
+
+impl<R> CanonicalIso<SparsePolyRingBase<R>> for GF2PolyRingBase
+    where R: RingStore, zn_static::ZnBase<2, true>: CanonicalIso<R::Type>
+{
+    type Isomorphism = super::generic_impls::GenericCanonicalIso<SparsePolyRingBase<R>, Self>;
+
+    fn has_canonical_iso(&self, from: &SparsePolyRingBase<R>) -> Option<Self::Isomorphism> {
+        self.base_ring().get_ring().has_canonical_iso(from.base_ring().get_ring())
+    }
+
+    fn map_out(&self, from: &SparsePolyRingBase<R>, el: Self::Element, iso: &Self::Isomorphism) -> <SparsePolyRingBase<R> as RingBase>::Element {
+        super::generic_impls::generic_map_out(from, self, el, iso)
+    }
+}
+
+impl<I: ?Sized + IntegerRing> CanHomFrom<I> for GF2PolyRingBase {
+
+    type Homomorphism = ();
+
+    fn has_canonical_hom(&self, _: &I) -> Option<()> {
+        Some(())
+    }
+
+    fn map_in(&self, from: &I, el: I::Element, _: &()) -> Self::Element {
+        if from.abs_is_bit_set(&el, 0) { vec![1] } else { Vec::new() }
+    }
+}
+
+impl RingExtension for GF2PolyRingBase {
+
+    type BaseRing = zn_static::Fp<2>;
+
+    fn base_ring<'a>(&'a self) -> &'a Self::BaseRing {
+        &self.base_ring
+    }
+
+    fn from(&self, x: El<Self::BaseRing>) -> Self::Element {
+        self.from_int(x as i32)
+    }
+}
+
+pub struct GF2PolyTermsIter<'a> {
+    words: &'a [u64],
+    index: usize,
+    current: u64
+}
+
+impl<'a> Iterator for GF2PolyTermsIter<'a> {
+
+    type Item = (&'a u64, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current == 0 {
+            self.index += 1;
+            self.current = *self.words.get(self.index)?;
+        }
+        let bit = self.current.trailing_zeros() as usize;
+        self.current &= self.current - 1;
+        return Some((&ONE, 64 * self.index + bit));
+    }
+}
+
+impl PolyRing for GF2PolyRingBase {
+
+    type TermsIterator<'a> = GF2PolyTermsIter<'a>;
+
+    fn indeterminate(&self) -> Self::Element {
+        vec![2]
+    }
+
+    fn terms<'a>(&'a self, f: &'a Self::Element) -> GF2PolyTermsIter<'a> {
+        GF2PolyTermsIter {
+            words: &f[..],
+            index: 0,
+            current: f.first().copied().unwrap_or(0)
+        }
+    }
+
+    fn add_assign_from_terms<I>(&self, lhs: &mut Self::Element, rhs: I)
+        where I: Iterator<Item = (El<Self::BaseRing>, usize)>
+    {
+        for (c, i) in rhs {
+            if c != 0 {
+                if lhs.len() <= i / 64 {
+                    lhs.resize(i / 64 + 1, 0);
+                }
+                lhs[i / 64] ^= 1 << (i % 64);
+            }
+        }
+        normalize(lhs);
+    }
+
+    fn coefficient_at<'a>(&'a self, f: &'a Self::Element, i: usize) -> &'a El<Self::BaseRing> {
+        if f.get(i / 64).map(|w| (w >> (i % 64)) & 1 == 1).unwrap_or(false) {
+            &ONE
+        } else {
+            &ZERO
+        }
+    }
+
+    fn degree(&self, f: &Self::Element) -> Option<usize> {
+        degree(f)
+    }
+
+    fn div_rem_monic(&self, lhs: Self::Element, rhs: &Self::Element) -> (Self::Element, Self::Element) {
+        div_rem(lhs, rhs)
+    }
+}
+
+impl DivisibilityRing for GF2PolyRingBase {
+
+    fn checked_left_div(&self, lhs: &Self::Element, rhs: &Self::Element) -> Option<Self::Element> {
+        if self.is_zero(rhs) {
+            if self.is_zero(lhs) {
+                return Some(self.zero());
+            } else {
+                return None;
+            }
+        }
+        let (quo, rem) = div_rem(lhs.clone(), rhs);
+        if rem.is_empty() {
+            Some(quo)
+        } else {
+            None
+        }
+    }
+}
+
+impl PrincipalIdealRing for GF2PolyRingBase {
+
+    fn ideal_gen(&self, lhs: &Self::Element, rhs: &Self::Element) -> (Self::Element, Self::Element, Self::Element) {
+        algorithms::eea::eea(self.clone_el(lhs), self.clone_el(rhs), RingRef::new(self))
+    }
+}
+
+impl EuclideanRing for GF2PolyRingBase {
+
+    fn euclidean_div_rem(&self, lhs: Self::Element, rhs: &Self::Element) -> (Self::Element, Self::Element) {
+        assert!(!self.is_zero(rhs));
+        div_rem(lhs, rhs)
+    }
+
+    fn euclidean_deg(&self, val: &Self::Element) -> Option<usize> {
+        return Some(self.degree(val).map(|x| x + 1).unwrap_or(0));
+    }
+}
+
+impl super::dense_poly::ImplGenericCanonicalIsoMarker for GF2PolyRingBase {}
+
+impl super::sparse_poly::ImplGenericCanonicalIsoMarker for GF2PolyRingBase {}
+
+#[cfg(test)]
+use crate::rings::poly::dense_poly::DensePolyRing;
+#[cfg(test)]
+use crate::rings::finite::FiniteRingStore;
+
+#[cfg(test)]
+fn edge_case_elements(poly_ring: &GF2PolyRing) -> impl Iterator<Item = Vec<u64>> {
+    vec![
+        poly_ring.zero(),
+        poly_ring.one(),
+        poly_ring.indeterminate(),
+        poly_ring.get_ring().from_words(vec![0b1011]),
+        poly_ring.get_ring().from_words(vec![1 << 63]),
+        poly_ring.get_ring().from_words(vec![u64::MAX, 1]),
+        poly_ring.get_ring().from_words(vec![0x123456789ABCDEF, 0, 0xFEDCBA987654321])
+    ].into_iter()
+}
+
+#[test]
+fn test_ring_axioms() {
+    let poly_ring = GF2PolyRing::new("X");
+    crate::ring::generic_tests::test_ring_axioms(&poly_ring, edge_case_elements(&poly_ring));
+    crate::divisibility::generic_tests::test_divisibility_axioms(&poly_ring, edge_case_elements(&poly_ring));
+    crate::pid::generic_tests::test_euclidean_ring_axioms(&poly_ring, edge_case_elements(&poly_ring));
+}
+
+#[test]
+fn test_poly_ring_axioms() {
+    let poly_ring = GF2PolyRing::new("X");
+    super::generic_tests::test_poly_ring_axioms(poly_ring, zn_static::Fp::<2>::RING.elements());
+}
+
+#[test]
+fn test_carryless_mul() {
+    assert_eq!(0b1111, carryless_mul(0b11, 0b101));
+    assert_eq!(0b101, carryless_mul(0b11, 0b11));
+    assert_eq!(1 << 126, carryless_mul(1 << 63, 1 << 63));
+    assert_eq!(u64::MAX as u128, carryless_mul(u64::MAX, 1));
+}
+
+#[test]
+fn test_mul_and_square_against_dense() {
+    let poly_ring = GF2PolyRing::new("X");
+    let dense_ring = DensePolyRing::new(zn_static::Fp::<2>::RING, "X");
+    let hom = dense_ring.can_hom(&poly_ring).unwrap();
+    for a in edge_case_elements(&poly_ring) {
+        for b in edge_case_elements(&poly_ring) {
+            assert_el_eq!(&dense_ring, &dense_ring.mul(hom.map_ref(&a), hom.map_ref(&b)), &hom.map(poly_ring.mul_ref(&a, &b)));
+        }
+        assert_el_eq!(&dense_ring, &dense_ring.pow(hom.map_ref(&a), 2), &hom.map(poly_ring.pow(a, 2)));
+    }
+}
+
+#[test]
+fn test_canonical_iso_dense_poly_ring() {
+    let poly_ring = GF2PolyRing::new("X");
+    let dense_ring = DensePolyRing::new(zn_static::Fp::<2>::RING, "X");
+    crate::ring::generic_tests::test_hom_axioms(&poly_ring, &dense_ring, edge_case_elements(&poly_ring));
+    crate::ring::generic_tests::test_iso_axioms(&poly_ring, &dense_ring, edge_case_elements(&poly_ring));
+    crate::ring::generic_tests::test_hom_axioms(&dense_ring, &poly_ring, edge_case_elements(&poly_ring).map(|f| dense_ring.coerce(&poly_ring, f)));
+}
@@ -1,8 +1,12 @@
+use crate::algorithms;
+use crate::divisibility::{DivisibilityRing, DivisibilityRingStore};
+use crate::pid::{PrincipalIdealRing, PrincipalIdealRingStore};
 use crate::ring::*;
 use crate::homomorphism::*;
 
 pub mod dense_poly;
 pub mod sparse_poly;
+pub mod gf2_poly;
 
 ///
 /// Trait for all rings that represent the polynomial ring `R[X]` with
@@ -88,6 +92,67 @@ pub trait PolyRingStore: RingStore
     {
         self.get_ring().evaluate(f, value, hom)
     }
+
+    ///
+    /// Computes the formal derivative of `f`.
+    ///
+    fn derivative(&self, f: &El<Self>) -> El<Self> {
+        self.from_terms(self.terms(f)
+            .filter(|(_, i)| *i > 0)
+            .map(|(c, i)| (self.base_ring().int_hom().mul_ref_fst_map(c, i as i32), i - 1))
+        )
+    }
+
+    ///
+    /// Computes the content of `f`, i.e. a greatest common divisor of all its coefficients. As usual,
+    /// this is only unique up to multiplication by units. The content of `0` is `0`.
+    ///
+    fn content(&self, f: &El<Self>) -> El<<Self::Type as RingExtension>::BaseRing>
+        where <<Self::Type as RingExtension>::BaseRing as RingStore>::Type: PrincipalIdealRing
+    {
+        let base_ring = self.base_ring();
+        self.terms(f).fold(base_ring.zero(), |current, (c, _)| base_ring.ideal_gen(&current, c).2)
+    }
+
+    ///
+    /// Returns `f` divided by its content, as given by [`PolyRingStore::content()`].
+    ///
+    fn primitive_part(&self, f: El<Self>) -> El<Self>
+        where <<Self::Type as RingExtension>::BaseRing as RingStore>::Type: PrincipalIdealRing
+    {
+        if self.is_zero(&f) {
+            return f;
+        }
+        let base_ring = self.base_ring();
+        let content = self.content(&f);
+        if base_ring.is_one(&content) {
+            return f;
+        }
+        self.from_terms(self.terms(&f).map(|(c, i)| (base_ring.checked_div(c, &content).unwrap(), i)))
+    }
+
+    ///
+    /// Computes the square-free decomposition of `f`, i.e. pairwise coprime square-free polynomials
+    /// with distinct multiplicities, whose product is `f` up to a constant. For details, see
+    /// [`algorithms::poly_squarefree::squarefree_decomposition()`].
+    ///
+    fn squarefree_decomposition(&self, f: &El<Self>) -> Vec<(El<Self>, usize)>
+        where Self::Type: DivisibilityRing,
+            <<Self::Type as RingExtension>::BaseRing as RingStore>::Type: PrincipalIdealRing
+    {
+        algorithms::poly_squarefree::squarefree_decomposition(RingRef::new(self.get_ring()), f)
+    }
+
+    ///
+    /// Computes the square-free part of `f`, i.e. the product of all its irreducible factors, each
+    /// with multiplicity one. For details, see [`algorithms::poly_squarefree::squarefree_part()`].
+    ///
+    fn squarefree_part(&self, f: &El<Self>) -> El<Self>
+        where Self::Type: DivisibilityRing,
+            <<Self::Type as RingExtension>::BaseRing as RingStore>::Type: PrincipalIdealRing
+    {
+        algorithms::poly_squarefree::squarefree_part(RingRef::new(self.get_ring()), f)
+    }
 }
 
 impl<R: RingStore> PolyRingStore for R
@@ -0,0 +1,585 @@
+use crate::algorithms;
+use crate::divisibility::{DivisibilityRing, DivisibilityRingStore};
+use crate::ring::*;
+use crate::homomorphism::*;
+use crate::rings::poly::PolyRingStore;
+use crate::rings::poly::dense_poly::DensePolyRing;
+
+///
+/// The ring of power series `R[[X]]` over a base ring `R`, truncated at a fixed precision `N`.
+/// In other words, this is the ring `R[X]/(X^N)`, and all operations are exact modulo `X^N`.
+///
+/// Elements are stored as polynomials of degree `< N`, using [`DensePolyRing`], and products are
+/// computed by multiplying the polynomials and truncating. Hence, multiplication profits from the
+/// fast multiplication algorithms of [`DensePolyRing`]. The more complicated operations, like
+/// [`PowerSeriesRingBase::sqrt()`], [`PowerSeriesRingBase::log()`], [`PowerSeriesRingBase::exp()`]
+/// or [`PowerSeriesRingBase::reversion()`] use Newton iteration. Note that the coefficients of degree `>= N`
+/// of products are computed before being discarded, so base rings with fixed-size integers might overflow
+/// even if the result does not.
+///
+/// # Example
+///
+/// Computing the number of binary trees with `n` nodes. Their generating function `C` satisfies
+/// `C = 1 + X C^2`, thus `XC` is the compositional inverse of `X - X^2`.
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::primitive_int::*;
+/// # use feanor_math::rings::power_series::*;
+/// let ZZ = StaticRing::<i64>::RING;
+/// let P = PowerSeriesRing::new(ZZ, "X", 11);
+/// let x = P.indeterminate();
+/// let catalan = P.reversion(&P.sub(P.clone_el(&x), P.pow(x, 2)));
+/// assert_eq!(4862, *P.coefficient_at(&catalan, 10));
+/// ```
+///
+pub struct PowerSeriesRingBase<R: RingStore> {
+    poly_ring: DensePolyRing<R>,
+    unknown_name: &'static str,
+    precision: usize
+}
+
+///
+/// The ring of power series `R[[X]]`, truncated at a fixed precision.
+/// For details, see [`PowerSeriesRingBase`].
+///
+pub type PowerSeriesRing<R> = RingValue<PowerSeriesRingBase<R>>;
+
+impl<R: RingStore> PowerSeriesRing<R> {
+
+    pub fn new(base_ring: R, unknown_name: &'static str, precision: usize) -> Self {
+        RingValue::from(PowerSeriesRingBase::new(base_ring, unknown_name, precision))
+    }
+
+    pub fn precision(&self) -> usize { self.get_ring().precision() }
+
+    pub fn poly_ring(&self) -> &DensePolyRing<R> { self.get_ring().poly_ring() }
+
+    pub fn indeterminate(&self) -> El<Self> { self.get_ring().indeterminate() }
+
+    pub fn from_poly(&self, f: El<DensePolyRing<R>>) -> El<Self> { self.get_ring().from_poly(f) }
+
+    pub fn as_poly<'a>(&self, f: &'a El<Self>) -> &'a El<DensePolyRing<R>> { self.get_ring().as_poly(f) }
+
+    pub fn coefficient_at<'a>(&'a self, f: &'a El<Self>, i: usize) -> &'a El<R> { self.get_ring().coefficient_at(f, i) }
+
+    pub fn valuation(&self, f: &El<Self>) -> Option<usize> { self.get_ring().valuation(f) }
+
+    pub fn derivative(&self, f: &El<Self>) -> El<Self> { self.get_ring().derivative(f) }
+
+    pub fn compose(&self, f: &El<Self>, g: &El<Self>) -> El<Self> { self.get_ring().compose(f, g) }
+}
+
+impl<R: RingStore> PowerSeriesRing<R>
+    where R::Type: DivisibilityRing
+{
+    pub fn integral(&self, f: &El<Self>) -> El<Self> { self.get_ring().integral(f) }
+
+    pub fn sqrt(&self, f: &El<Self>) -> El<Self> { self.get_ring().sqrt(f) }
+
+    pub fn log(&self, f: &El<Self>) -> El<Self> { self.get_ring().log(f) }
+
+    pub fn exp(&self, f: &El<Self>) -> El<Self> { self.get_ring().exp(f) }
+
+    pub fn reversion(&self, f: &El<Self>) -> El<Self> { self.get_ring().reversion(f) }
+}
+
+impl<R: RingStore> PowerSeriesRingBase<R> {
+
+    pub fn new(base_ring: R, unknown_name: &'static str, precision: usize) -> Self {
+        assert!(precision > 0);
+        PowerSeriesRingBase {
+            poly_ring: DensePolyRing::new(base_ring, unknown_name),
+            unknown_name: unknown_name,
+            precision: precision
+        }
+    }
+
+    ///
+    /// Returns the precision `N` of this ring, i.e. the exponent such that this ring is `R[X]/(X^N)`.
+    ///
+    pub fn precision(&self) -> usize {
+        self.precision
+    }
+
+    ///
+    /// Returns the polynomial ring that is used to store the elements of this ring.
+    ///
+    pub fn poly_ring(&self) -> &DensePolyRing<R> {
+        &self.poly_ring
+    }
+
+    pub fn indeterminate(&self) -> El<DensePolyRing<R>> {
+        self.from_poly(self.poly_ring.indeterminate())
+    }
+
+    ///
+    /// Returns the power series given by the polynomial `f`, i.e. truncates `f` to precision `N`.
+    ///
+    pub fn from_poly(&self, f: El<DensePolyRing<R>>) -> El<DensePolyRing<R>> {
+        if self.poly_ring.degree(&f).map(|d| d < self.precision).unwrap_or(true) {
+            f
+        } else {
+            self.truncate(&f, self.precision)
+        }
+    }
+
+    ///
+    /// Returns the polynomial of degree `< N` that represents the given power series.
+    ///
+    pub fn as_poly<'a>(&self, f: &'a El<DensePolyRing<R>>) -> &'a El<DensePolyRing<R>> {
+        f
+    }
+
+    ///
+    /// Returns the coefficient of `X^i` in `f`. For `i >= N`, this is always zero.
+    ///
+    pub fn coefficient_at<'a>(&'a self, f: &'a El<DensePolyRing<R>>, i: usize) -> &'a El<R> {
+        self.poly_ring.coefficient_at(f, i)
+    }
+
+    ///
+    /// Returns the smallest `i` such that the coefficient of `X^i` in `f` is nonzero, or `None`
+    /// if `f` is zero.
+    ///
+    pub fn valuation(&self, f: &El<DensePolyRing<R>>) -> Option<usize> {
+        self.poly_ring.terms(f).map(|(_, i)| i).min()
+    }
+
+    ///
+    /// Computes the formal derivative of `f`. Note that the result is only correct up to precision
+    /// `N - 1`, since the coefficient of `X^N` in `f` is not known.
+    ///
+    pub fn derivative(&self, f: &El<DensePolyRing<R>>) -> El<DensePolyRing<R>> {
+        self.poly_ring.derivative(f)
+    }
+
+    ///
+    /// Computes the composition `f(g)`. This requires `g` to have zero constant coefficient.
+    /// 
+    /// This uses the baby-step giant-step method of Brent and Kung, see [`algorithms::poly_compose::truncated_compose()`],
+    /// thus requires about `2 sqrt(N)` multiplications and `O(N^2)` additional operations in the base ring.
+    ///
+    pub fn compose(&self, f: &El<DensePolyRing<R>>, g: &El<DensePolyRing<R>>) -> El<DensePolyRing<R>> {
+        self.compose_prec(f, g, self.precision)
+    }
+
+    fn truncate(&self, f: &El<DensePolyRing<R>>, precision: usize) -> El<DensePolyRing<R>> {
+        let base_ring = self.poly_ring.base_ring();
+        self.poly_ring.from_terms(self.poly_ring.terms(f).filter(|(_, i)| *i < precision).map(|(c, i)| (base_ring.clone_el(c), i)))
+    }
+
+    fn mul_prec(&self, lhs: &El<DensePolyRing<R>>, rhs: &El<DensePolyRing<R>>, precision: usize) -> El<DensePolyRing<R>> {
+        self.truncate(&self.poly_ring.mul(self.truncate(lhs, precision), self.truncate(rhs, precision)), precision)
+    }
+
+    fn compose_prec(&self, f: &El<DensePolyRing<R>>, g: &El<DensePolyRing<R>>, precision: usize) -> El<DensePolyRing<R>> {
+        assert!(self.poly_ring.base_ring().is_zero(self.poly_ring.coefficient_at(g, 0)));
+        algorithms::poly_compose::truncated_compose(&self.poly_ring, &self.truncate(f, precision), g, precision)
+    }
+}
+
+impl<R: RingStore> PowerSeriesRingBase<R>
+    where R::Type: DivisibilityRing
+{
+    fn invert_prec(&self, f: &El<DensePolyRing<R>>, precision: usize) -> Option<El<DensePolyRing<R>>> {
+        let constant_coeff_inv = self.poly_ring.base_ring().invert(self.poly_ring.coefficient_at(f, 0))?;
+        Some(algorithms::poly_div::invert_power_series(&self.poly_ring, &self.truncate(f, precision), &constant_coeff_inv, precision))
+    }
+
+    fn integral_prec(&self, f: &El<DensePolyRing<R>>, precision: usize) -> El<DensePolyRing<R>> {
+        let base_ring = self.poly_ring.base_ring();
+        self.poly_ring.from_terms(self.poly_ring.terms(f)
+            .filter(|(_, i)| *i + 1 < precision)
+            .map(|(c, i)| (base_ring.checked_div(c, &base_ring.int_hom().map(i as i32 + 1)).expect("integration requires the integers 1, ..., N - 1 to be invertible"), i + 1))
+        )
+    }
+
+    fn log_prec(&self, f: &El<DensePolyRing<R>>, precision: usize) -> El<DensePolyRing<R>> {
+        assert!(self.poly_ring.base_ring().is_one(self.poly_ring.coefficient_at(f, 0)));
+        let f = self.truncate(f, precision);
+        let quotient = self.mul_prec(&self.derivative(&f), &self.invert_prec(&f, precision).unwrap(), precision);
+        return self.integral_prec(&quotient, precision);
+    }
+
+    ///
+    /// Computes the formal integral of `f` with zero constant coefficient. This requires the integers
+    /// `1, ..., N - 1` to be invertible in the base ring. The coefficient of `X^(N - 1)` in `f` is
+    /// ignored, since the corresponding term of the integral has degree `N`.
+    ///
+    pub fn integral(&self, f: &El<DensePolyRing<R>>) -> El<DensePolyRing<R>> {
+        self.integral_prec(f, self.precision)
+    }
+
+    ///
+    /// Computes the unique square root of `f` with constant coefficient `1`. This requires `f` to have
+    /// constant coefficient `1`, and `2` to be invertible in the base ring.
+    ///
+    pub fn sqrt(&self, f: &El<DensePolyRing<R>>) -> El<DensePolyRing<R>> {
+        let base_ring = self.poly_ring.base_ring();
+        assert!(base_ring.is_one(self.poly_ring.coefficient_at(f, 0)));
+        let two_inv = base_ring.invert(&base_ring.int_hom().map(2)).expect("the square root requires 2 to be invertible");
+        // we compute the inverse square root `h = f^(-1/2)` via the Newton step `h -> h + h(1 - fh^2)/2`
+        let mut inv_sqrt = self.poly_ring.one();
+        let mut current_precision = 1;
+        while current_precision < self.precision {
+            current_precision = std::cmp::min(2 * current_precision, self.precision);
+            let error = self.poly_ring.sub(self.poly_ring.one(), self.mul_prec(f, &self.mul_prec(&inv_sqrt, &inv_sqrt, current_precision), current_precision));
+            let correction = self.mul_prec(&inv_sqrt, &error, current_precision);
+            self.poly_ring.add_assign(&mut inv_sqrt, self.poly_ring.inclusion().mul_ref_map(&correction, &two_inv));
+        }
+        return self.mul_prec(f, &inv_sqrt, self.precision);
+    }
+
+    ///
+    /// Computes the logarithm `log(f) = integral(f'/f)` of `f`. This requires `f` to have constant
+    /// coefficient `1`, and the integers `1, ..., N - 1` to be invertible in the base ring.
+    ///
+    pub fn log(&self, f: &El<DensePolyRing<R>>) -> El<DensePolyRing<R>> {
+        self.log_prec(f, self.precision)
+    }
+
+    ///
+    /// Computes the exponential `exp(f) = sum_i f^i/i!` of `f`. This requires `f` to have constant
+    /// coefficient `0`, and the integers `1, ..., N - 1` to be invertible in the base ring.
+    ///
+    pub fn exp(&self, f: &El<DensePolyRing<R>>) -> El<DensePolyRing<R>> {
+        assert!(self.poly_ring.base_ring().is_zero(self.poly_ring.coefficient_at(f, 0)));
+        // Newton step `g -> g(1 + f - log(g))`
+        let mut result = self.poly_ring.one();
+        let mut current_precision = 1;
+        while current_precision < self.precision {
+            current_precision = std::cmp::min(2 * current_precision, self.precision);
+            let error = self.poly_ring.sub(self.truncate(f, current_precision), self.log_prec(&result, current_precision));
+            result = self.mul_prec(&result, &self.poly_ring.add(self.poly_ring.one(), error), current_precision);
+        }
+        return result;
+    }
+
+    ///
+    /// Computes the compositional inverse of `f`, i.e. the unique power series `g` with zero constant
+    /// coefficient such that `f(g) = g(f) = X`. This requires `f` to have zero constant coefficient,
+    /// and the coefficient of `X` in `f` must be a unit.
+    ///
+    pub fn reversion(&self, f: &El<DensePolyRing<R>>) -> El<DensePolyRing<R>> {
+        let base_ring = self.poly_ring.base_ring();
+        assert!(base_ring.is_zero(self.poly_ring.coefficient_at(f, 0)));
+        let linear_coeff_inv = base_ring.invert(self.poly_ring.coefficient_at(f, 1)).expect("the coefficient of X must be a unit");
+        if self.precision <= 2 {
+            return self.truncate(&self.poly_ring.inclusion().mul_map(self.poly_ring.indeterminate(), linear_coeff_inv), self.precision);
+        }
+        let f_derivative = self.derivative(f);
+        // Newton step `g -> g - (f(g) - X) / f'(g)`
+        let mut result = self.poly_ring.inclusion().mul_map(self.poly_ring.indeterminate(), linear_coeff_inv);
+        let mut current_precision = 2;
+        while current_precision < self.precision {
+            current_precision = std::cmp::min(2 * current_precision, self.precision);
+            let error = self.poly_ring.sub(self.compose_prec(f, &result, current_precision), self.truncate(&self.poly_ring.indeterminate(), current_precision));
+            let derivative_inv = self.invert_prec(&self.compose_prec(&f_derivative, &result, current_precision), current_precision).unwrap();
+            self.poly_ring.sub_assign(&mut result, self.mul_prec(&error, &derivative_inv, current_precision));
+        }
+        return result;
+    }
+}
+
+impl<R: RingStore> PartialEq for PowerSeriesRingBase<R> {
+
+    fn eq(&self, other: &Self) -> bool {
+        self.poly_ring.get_ring() == other.poly_ring.get_ring() && self.precision == other.precision
+    }
+}
+
+impl<R: RingStore + Clone> Clone for PowerSeriesRingBase<R> {
+
+    fn clone(&self) -> Self {
+        PowerSeriesRingBase {
+            poly_ring: DensePolyRing::new(self.poly_ring.base_ring().clone(), self.unknown_name),
+            unknown_name: self.unknown_name,
+            precision: self.precision
+        }
+    }
+}
+
+impl<R: RingStore> RingBase for PowerSeriesRingBase<R> {
+
+    type Element = El<DensePolyRing<R>>;
+
+    fn clone_el(&self, val: &Self::Element) -> Self::Element {
+        self.poly_ring.clone_el(val)
+    }
+
+    fn add_assign_ref(&self, lhs: &mut Self::Element, rhs: &Self::Element) {
+        self.poly_ring.add_assign_ref(lhs, rhs)
+    }
+
+    fn add_assign(&self, lhs: &mut Self::Element, rhs: Self::Element) {
+        self.poly_ring.add_assign(lhs, rhs)
+    }
+
+    fn sub_assign_ref(&self, lhs: &mut Self::Element, rhs: &Self::Element) {
+        self.poly_ring.sub_assign_ref(lhs, rhs)
+    }
+
+    fn negate_inplace(&self, lhs: &mut Self::Element) {
+        self.poly_ring.negate_inplace(lhs)
+    }
+
+    fn mul_assign(&self, lhs: &mut Self::Element, rhs: Self::Element) {
+        self.mul_assign_ref(lhs, &rhs)
+    }
+
+    fn mul_assign_ref(&self, lhs: &mut Self::Element, rhs: &Self::Element) {
+        *lhs = self.mul_ref(lhs, rhs);
+    }
+
+    fn mul_ref(&self, lhs: &Self::Element, rhs: &Self::Element) -> Self::Element {
+        self.from_poly(self.poly_ring.mul_ref(lhs, rhs))
+    }
+
+    fn from_int(&self, value: i32) -> Self::Element {
+        self.poly_ring.int_hom().map(value)
+    }
+
+    fn eq_el(&self, lhs: &Self::Element, rhs: &Self::Element) -> bool {
+        self.poly_ring.eq_el(lhs, rhs)
+    }
+
+    fn is_zero(&self, value: &Self::Element) -> bool {
+        self.poly_ring.is_zero(value)
+    }
+
+    fn is_commutative(&self) -> bool {
+        self.poly_ring.base_ring().is_commutative()
+    }
+
+    fn is_noetherian(&self) -> bool {
+        self.poly_ring.base_ring().is_noetherian()
+    }
+
+    fn dbg<'a>(&self, value: &Self::Element, out: &mut std::fmt::Formatter<'a>) -> std::fmt::Result {
+        self.poly_ring.get_ring().dbg(value, out)?;
+        write!(out, " + O({}^{})", self.unknown_name, self.precision)
+    }
+}
+
+impl<R: RingStore> RingExtension for PowerSeriesRingBase<R> {
+
+    type BaseRing = R;
+
+    fn base_ring<'a>(&'a self) -> &'a Self::BaseRing {
+        self.poly_ring.base_ring()
+    }
+
+    fn from(&self, x: El<Self::BaseRing>) -> Self::Element {
+        self.poly_ring.inclusion().map(x)
+    }
+
+    fn mul_assign_base(&self, lhs: &mut Self::Element, rhs: &El<Self::BaseRing>) {
+        self.poly_ring.inclusion().mul_assign_map_ref(lhs, rhs)
+    }
+}
+
+///
+/// The canonical homomorphism `S[[X]]/(X^M) -> R[[X]]/(X^N)` exists if there is a canonical
+/// homomorphism `S -> R` and `M >= N`.
+///
+impl<R1: RingStore, R2: RingStore> CanHomFrom<PowerSeriesRingBase<R1>> for PowerSeriesRingBase<R2>
+    where R2::Type: CanHomFrom<R1::Type>
+{
+    type Homomorphism = <R2::Type as CanHomFrom<R1::Type>>::Homomorphism;
+
+    fn has_canonical_hom(&self, from: &PowerSeriesRingBase<R1>) -> Option<Self::Homomorphism> {
+        if from.precision >= self.precision {
+            self.base_ring().get_ring().has_canonical_hom(from.base_ring().get_ring())
+        } else {
+            None
+        }
+    }
+
+    fn map_in(&self, from: &PowerSeriesRingBase<R1>, el: <PowerSeriesRingBase<R1> as RingBase>::Element, hom: &Self::Homomorphism) -> Self::Element {
+        self.map_in_ref(from, &el, hom)
+    }
+
+    fn map_in_ref(&self, from: &PowerSeriesRingBase<R1>, el: &<PowerSeriesRingBase<R1> as RingBase>::Element, hom: &Self::Homomorphism) -> Self::Element {
+        self.poly_ring.from_terms(from.poly_ring.terms(el)
+            .filter(|(_, i)| *i < self.precision)
+            .map(|(c, i)| (self.base_ring().get_ring().map_in_ref(from.base_ring().get_ring(), c, hom), i))
+        )
+    }
+}
+
+impl<R1: RingStore, R2: RingStore> CanonicalIso<PowerSeriesRingBase<R1>> for PowerSeriesRingBase<R2>
+    where R2::Type: CanonicalIso<R1::Type>
+{
+    type Isomorphism = <R2::Type as CanonicalIso<R1::Type>>::Isomorphism;
+
+    fn has_canonical_iso(&self, from: &PowerSeriesRingBase<R1>) -> Option<Self::Isomorphism> {
+        if from.precision == self.precision {
+            self.base_ring().get_ring().has_canonical_iso(from.base_ring().get_ring())
+        } else {
+            None
+        }
+    }
+
+    fn map_out(&self, from: &PowerSeriesRingBase<R1>, el: Self::Element, iso: &Self::Isomorphism) -> <PowerSeriesRingBase<R1> as RingBase>::Element {
+        from.poly_ring.from_terms(self.poly_ring.terms(&el)
+            .map(|(c, i)| (self.base_ring().get_ring().map_out(from.base_ring().get_ring(), self.base_ring().clone_el(c), iso), i))
+        )
+    }
+}
+
+///
+/// Division of `lhs` by `rhs` is done by writing `rhs = X^v u` where `u` has nonzero constant coefficient.
+/// If this coefficient is a unit, `u` is inverted using Newton iteration. Otherwise, the quotient is found
+/// by solving the triangular system for its coefficients, which always finds a quotient if the base ring is
+/// an integral domain.
+///
+impl<R: RingStore> DivisibilityRing for PowerSeriesRingBase<R>
+    where R::Type: DivisibilityRing
+{
+    fn checked_left_div(&self, lhs: &Self::Element, rhs: &Self::Element) -> Option<Self::Element> {
+        let base_ring = self.poly_ring.base_ring();
+        let v = if let Some(v) = self.valuation(rhs) {
+            v
+        } else if self.is_zero(lhs) {
+            return Some(self.zero());
+        } else {
+            return None;
+        };
+        if self.valuation(lhs).map(|w| w < v).unwrap_or(false) {
+            return None;
+        }
+        // the quotient is only determined modulo `X^(N - v)`
+        let precision = self.precision - v;
+        let shift = |f: &Self::Element| self.poly_ring.from_terms(self.poly_ring.terms(f).map(|(c, i)| (base_ring.clone_el(c), i - v)));
+        let lhs = shift(lhs);
+        let rhs = shift(rhs);
+        if let Some(rhs_inv) = self.invert_prec(&rhs, precision) {
+            return Some(self.mul_prec(&lhs, &rhs_inv, precision));
+        }
+        let mut result = Vec::with_capacity(precision);
+        for i in 0..precision {
+            let mut current = base_ring.clone_el(self.poly_ring.coefficient_at(&lhs, i));
+            for j in 0..i {
+                base_ring.sub_assign(&mut current, base_ring.mul_ref(&result[j], self.poly_ring.coefficient_at(&rhs, i - j)));
+            }
+            result.push(base_ring.checked_left_div(&current, self.poly_ring.coefficient_at(&rhs, 0))?);
+        }
+        return Some(self.poly_ring.from_terms(result.into_iter().enumerate().map(|(i, c)| (c, i))));
+    }
+}
+
+#[cfg(test)]
+use crate::primitive_int::StaticRing;
+#[cfg(test)]
+use crate::integer::BigIntRing;
+#[cfg(test)]
+use crate::field::FieldStore;
+#[cfg(test)]
+use crate::rings::rational::RationalField;
+#[cfg(test)]
+use crate::rings::zn::zn_static::Fp;
+
+#[cfg(test)]
+fn edge_case_elements<R: RingStore>(ring: &PowerSeriesRing<R>) -> impl Iterator<Item = El<PowerSeriesRing<R>>> + '_ {
+    let base_ring = ring.base_ring();
+    [vec![], vec![1], vec![-1], vec![0, 1], vec![2, 0, 1], vec![1, 1, 1, 1, 1], vec![0, 0, -3, 1, 0, 0, 5]].into_iter()
+        .map(move |coeffs| ring.from_poly(ring.poly_ring().from_terms(coeffs.into_iter().enumerate().map(|(i, c)| (base_ring.int_hom().map(c), i)))))
+}
+
+#[test]
+fn test_ring_axioms() {
+    let ring = PowerSeriesRing::new(StaticRing::<i64>::RING, "X", 5);
+    crate::ring::generic_tests::test_ring_axioms(&ring, edge_case_elements(&ring));
+    crate::divisibility::generic_tests::test_divisibility_axioms(&ring, edge_case_elements(&ring));
+
+    let ring = PowerSeriesRing::new(Fp::<7>::RING, "X", 3);
+    crate::ring::generic_tests::test_ring_axioms(&ring, edge_case_elements(&ring));
+    crate::divisibility::generic_tests::test_divisibility_axioms(&ring, edge_case_elements(&ring));
+}
+
+#[test]
+fn test_canonical_hom() {
+    let from = PowerSeriesRing::new(StaticRing::<i64>::RING, "X", 8);
+    let to = PowerSeriesRing::new(StaticRing::<i64>::RING, "X", 5);
+    crate::ring::generic_tests::test_hom_axioms(&from, &to, edge_case_elements(&from));
+    crate::ring::generic_tests::test_iso_axioms(&to, &to, edge_case_elements(&to));
+    assert!(from.can_hom(&to).is_none());
+}
+
+#[test]
+fn test_div() {
+    let ZZ = StaticRing::<i64>::RING;
+    let ring = PowerSeriesRing::new(ZZ, "X", 10);
+    let x = ring.indeterminate();
+    // the generating function of the Fibonacci numbers is `X / (1 - X - X^2)`
+    let fibonacci = ring.checked_div(&x, &ring.sub(ring.sub(ring.one(), ring.clone_el(&x)), ring.pow(ring.clone_el(&x), 2))).unwrap();
+    assert_eq!(vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34], (0..10).map(|i| *ring.coefficient_at(&fibonacci, i)).collect::<Vec<_>>());
+
+    // the lowest coefficient of the divisor is not a unit
+    let a = ring.add(ring.int_hom().map(2), ring.clone_el(&x));
+    let b = ring.sub(ring.int_hom().map(3), ring.pow(ring.clone_el(&x), 4));
+    assert_el_eq!(&ring, &b, &ring.checked_div(&ring.mul_ref(&a, &b), &a).unwrap());
+    assert!(ring.checked_div(&ring.one(), &a).is_none());
+    assert!(ring.checked_div(&x, &ring.pow(ring.clone_el(&x), 2)).is_none());
+}
+
+#[test]
+fn test_sqrt() {
+    let QQ = RationalField::new(BigIntRing::RING);
+    let ring = PowerSeriesRing::new(&QQ, "X", 12);
+    let f = ring.add(ring.one(), ring.from_poly(ring.poly_ring().from_terms([(QQ.int_hom().map(3), 1), (QQ.int_hom().map(-7), 5)].into_iter())));
+    let root = ring.sqrt(&f);
+    assert!(QQ.is_one(ring.coefficient_at(&root, 0)));
+    assert_el_eq!(&ring, &f, &ring.pow(root, 2));
+
+    let ring = PowerSeriesRing::new(Fp::<17>::RING, "X", 20);
+    let f = ring.pow(ring.add(ring.one(), ring.int_hom().mul_map(ring.indeterminate(), 5)), 2);
+    assert_el_eq!(&ring, &ring.add(ring.one(), ring.int_hom().mul_map(ring.indeterminate(), 5)), &ring.sqrt(&f));
+}
+
+#[test]
+fn test_log_exp() {
+    let QQ = RationalField::new(BigIntRing::RING);
+    let ring = PowerSeriesRing::new(&QQ, "X", 10);
+    let x = ring.indeterminate();
+    let exp_x = ring.exp(&x);
+    for i in 0..10 {
+        let factorial = (1..=(i as i32)).product::<i32>();
+        assert_el_eq!(&QQ, &QQ.div(&QQ.int_hom().map(1), &QQ.int_hom().map(factorial)), ring.coefficient_at(&exp_x, i));
+    }
+    assert_el_eq!(&ring, &x, &ring.log(&exp_x));
+
+    // `log(1 + X) = X - X^2/2 + X^3/3 - ...`
+    let log = ring.log(&ring.add(ring.one(), ring.clone_el(&x)));
+    for i in 1..10 {
+        assert_el_eq!(&QQ, &QQ.div(&QQ.int_hom().map(if i % 2 == 0 { -1 } else { 1 }), &QQ.int_hom().map(i as i32)), ring.coefficient_at(&log, i));
+    }
+
+    let f = ring.from_poly(ring.poly_ring().from_terms([(QQ.int_hom().map(2), 1), (QQ.div(&QQ.int_hom().map(-1), &QQ.int_hom().map(3)), 2), (QQ.int_hom().map(5), 7)].into_iter()));
+    let g = ring.from_poly(ring.poly_ring().from_terms([(QQ.div(&QQ.int_hom().map(1), &QQ.int_hom().map(2)), 3), (QQ.int_hom().map(1), 4)].into_iter()));
+    assert_el_eq!(&ring, &ring.mul(ring.exp(&f), ring.exp(&g)), &ring.exp(&ring.add_ref(&f, &g)));
+    assert_el_eq!(&ring, &f, &ring.log(&ring.exp(&f)));
+}
+
+#[test]
+fn test_compose_reversion() {
+    let QQ = RationalField::new(BigIntRing::RING);
+    let ring = PowerSeriesRing::new(&QQ, "X", 9);
+    let x = ring.indeterminate();
+    // `exp(X) - 1` and `log(1 + X)` are compositional inverses
+    let f = ring.sub(ring.exp(&x), ring.one());
+    let g = ring.log(&ring.add(ring.one(), ring.clone_el(&x)));
+    assert_el_eq!(&ring, &x, &ring.compose(&f, &g));
+    assert_el_eq!(&ring, &g, &ring.reversion(&f));
+
+    let ZZ = StaticRing::<i64>::RING;
+    let ring = PowerSeriesRing::new(ZZ, "X", 12);
+    let x = ring.indeterminate();
+    let f = ring.from_poly(ring.poly_ring().from_terms([(-1, 1), (1, 2), (1, 5), (-2, 11)].into_iter()));
+    let g = ring.reversion(&f);
+    assert_el_eq!(&ring, &x, &ring.compose(&f, &g));
+    assert_el_eq!(&ring, &x, &ring.compose(&g, &f));
+    // the reversion of `X - X^2` is the generating function `X C(X)` of the Catalan numbers
+    let catalan = ring.reversion(&ring.sub(ring.clone_el(&x), ring.pow(ring.clone_el(&x), 2)));
+    assert_eq!(vec![0, 1, 1, 2, 5, 14, 42, 132, 429, 1430, 4862, 16796], (0..12).map(|i| *ring.coefficient_at(&catalan, i)).collect::<Vec<_>>());
+}
@@ -0,0 +1,593 @@
+use crate::algorithms;
+use crate::divisibility::{DivisibilityRing, DivisibilityRingStore};
+use crate::field::FieldStore;
+use crate::integer::*;
+use crate::pid::*;
+use crate::primitive_int::StaticRing;
+use crate::ring::*;
+use crate::homomorphism::*;
+use crate::rings::poly::PolyRingStore;
+use crate::rings::poly::dense_poly::DensePolyRing;
+use crate::rings::zn::{ZnRingStore, zn_barett};
+
+///
+/// The values `d` for which the ring of integers of `Q(sqrt(d))` is imaginary quadratic and
+/// norm-Euclidean.
+///
+const EUCLIDEAN_IMAGINARY_DISCRIMINANTS: [i64; 5] = [-1, -2, -3, -7, -11];
+
+///
+/// The ring of integers `O` of an imaginary quadratic number field `Q(sqrt(d))` that is
+/// Euclidean w.r.t. the norm, i.e. `d` is one of `-1, -2, -3, -7, -11`. The most important
+/// cases are the Gaussian integers `Z[i]` (`d = -1`) and the Eisenstein integers `Z[ω]` (`d = -3`).
+///
+/// Elements are stored as `a + bω` with integers `a, b`, where `ω = sqrt(d)` if `d = 2, 3 mod 4`
+/// and `ω = (1 + sqrt(d))/2` if `d = 1 mod 4`. In both cases, `ω^2 = tω + c` for integers `t, c`.
+///
+/// Division with remainder is done by rounding the exact quotient in `Q(sqrt(d))` to the closest
+/// element of `O`, similar to [`crate::rings::float_complex::Complex64::closest_gaussian_int()`],
+/// but without any rounding errors.
+///
+/// # Example
+///
+/// Writing a prime `p = 1 mod 4` as sum of two squares.
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::homomorphism::*;
+/// # use feanor_math::primitive_int::*;
+/// # use feanor_math::rings::quadratic::*;
+/// let ZZi = QuadraticIntegerRing::new_gaussian(StaticRing::<i64>::RING);
+/// let p = 10009;
+/// let factorization = ZZi.factor(&ZZi.int_hom().map(p));
+/// assert_eq!(2, factorization.len());
+/// let (a, b) = ZZi.coefficients(&factorization[0].0);
+/// assert_eq!(p as i64, a * a + b * b);
+/// ```
+///
+pub struct QuadraticIntegerRingBase<I: IntegerRingStore>
+    where I::Type: IntegerRing
+{
+    integer_ring: I,
+    d: i64,
+    t: i32,
+    c: i32
+}
+
+///
+/// The ring of integers of a norm-Euclidean imaginary quadratic number field.
+/// For details, see [`QuadraticIntegerRingBase`].
+///
+pub type QuadraticIntegerRing<I> = RingValue<QuadraticIntegerRingBase<I>>;
+
+pub struct QuadraticIntegerEl<I: IntegerRingStore>(El<I>, El<I>)
+    where I::Type: IntegerRing;
+
+impl<I: IntegerRingStore> Clone for QuadraticIntegerEl<I>
+    where El<I>: Clone,
+        I::Type: IntegerRing
+{
+    fn clone(&self) -> Self {
+        QuadraticIntegerEl(self.0.clone(), self.1.clone())
+    }
+}
+
+impl<I: IntegerRingStore> Copy for QuadraticIntegerEl<I>
+    where El<I>: Copy,
+        I::Type: IntegerRing
+{}
+
+impl<I: IntegerRingStore> QuadraticIntegerRing<I>
+    where I::Type: IntegerRing
+{
+    pub fn new(integer_ring: I, d: i64) -> Self {
+        RingValue::from(QuadraticIntegerRingBase::new(integer_ring, d))
+    }
+
+    ///
+    /// Creates the ring of Gaussian integers `Z[i]`.
+    ///
+    pub fn new_gaussian(integer_ring: I) -> Self {
+        Self::new(integer_ring, -1)
+    }
+
+    ///
+    /// Creates the ring of Eisenstein integers `Z[ω]` with `ω = (1 + sqrt(-3))/2`.
+    ///
+    pub fn new_eisenstein(integer_ring: I) -> Self {
+        Self::new(integer_ring, -3)
+    }
+
+    pub fn d(&self) -> i64 { self.get_ring().d() }
+
+    pub fn integer_ring(&self) -> &I { self.get_ring().integer_ring() }
+
+    pub fn generator(&self) -> El<Self> { self.get_ring().generator() }
+
+    pub fn from_coefficients(&self, a: El<I>, b: El<I>) -> El<Self> { self.get_ring().from_coefficients(a, b) }
+
+    pub fn coefficients<'a>(&self, x: &'a El<Self>) -> (&'a El<I>, &'a El<I>) { self.get_ring().coefficients(x) }
+
+    pub fn conjugate(&self, x: &El<Self>) -> El<Self> { self.get_ring().conjugate(x) }
+
+    pub fn norm(&self, x: &El<Self>) -> El<I> { self.get_ring().norm(x) }
+
+    pub fn factor(&self, x: &El<Self>) -> Vec<(El<Self>, usize)> { self.get_ring().factor(x) }
+}
+
+impl<I: IntegerRingStore> QuadraticIntegerRingBase<I>
+    where I::Type: IntegerRing
+{
+    pub fn new(integer_ring: I, d: i64) -> Self {
+        assert!(EUCLIDEAN_IMAGINARY_DISCRIMINANTS.contains(&d), "The ring of integers of Q(sqrt({})) is not an imaginary quadratic norm-Euclidean ring", d);
+        let (t, c) = if d.rem_euclid(4) == 1 {
+            (1, ((d - 1) / 4) as i32)
+        } else {
+            (0, d as i32)
+        };
+        QuadraticIntegerRingBase { integer_ring, d, t, c }
+    }
+
+    ///
+    /// Returns the square-free integer `d` such that this ring is the ring of integers
+    /// of `Q(sqrt(d))`.
+    ///
+    pub fn d(&self) -> i64 {
+        self.d
+    }
+
+    pub fn integer_ring(&self) -> &I {
+        &self.integer_ring
+    }
+
+    ///
+    /// Returns the element `ω` that generates this ring as `Z`-module together with `1`.
+    /// This is `sqrt(d)` if `d = 2, 3 mod 4` and `(1 + sqrt(d))/2` otherwise.
+    ///
+    pub fn generator(&self) -> QuadraticIntegerEl<I> {
+        QuadraticIntegerEl(self.integer_ring.zero(), self.integer_ring.one())
+    }
+
+    ///
+    /// Returns the element `a + bω`, where `ω` is [`QuadraticIntegerRingBase::generator()`].
+    ///
+    pub fn from_coefficients(&self, a: El<I>, b: El<I>) -> QuadraticIntegerEl<I> {
+        QuadraticIntegerEl(a, b)
+    }
+
+    ///
+    /// Returns `(a, b)` such that `x = a + bω`, where `ω` is [`QuadraticIntegerRingBase::generator()`].
+    ///
+    pub fn coefficients<'a>(&self, x: &'a QuadraticIntegerEl<I>) -> (&'a El<I>, &'a El<I>) {
+        (&x.0, &x.1)
+    }
+
+    ///
+    /// Returns the image of `x` under the nontrivial automorphism `sqrt(d) -> -sqrt(d)`,
+    /// which is the restriction of complex conjugation.
+    ///
+    pub fn conjugate(&self, x: &QuadraticIntegerEl<I>) -> QuadraticIntegerEl<I> {
+        let ZZ = &self.integer_ring;
+        QuadraticIntegerEl(
+            ZZ.add_ref_fst(&x.0, ZZ.int_hom().mul_ref_map(&x.1, &self.t)),
+            ZZ.negate(ZZ.clone_el(&x.1))
+        )
+    }
+
+    ///
+    /// Returns the norm `x * conj(x)` of `x`, which is always a nonnegative integer.
+    ///
+    pub fn norm(&self, x: &QuadraticIntegerEl<I>) -> El<I> {
+        let ZZ = &self.integer_ring;
+        let mut result = ZZ.pow(ZZ.clone_el(&x.0), 2);
+        ZZ.add_assign(&mut result, ZZ.int_hom().mul_map(ZZ.mul_ref(&x.0, &x.1), self.t));
+        ZZ.sub_assign(&mut result, ZZ.int_hom().mul_map(ZZ.pow(ZZ.clone_el(&x.1), 2), self.c));
+        return result;
+    }
+
+    ///
+    /// Factors `x` into prime elements. As for [`crate::algorithms::int_factor::factor()`], a nontrivial
+    /// unit factor is returned as additional factor with multiplicity `1`, and the factorization of `0`
+    /// is `[(0, 1)]`.
+    ///
+    /// The prime factors are only unique up to multiplication by units.
+    ///
+    pub fn factor(&self, x: &QuadraticIntegerEl<I>) -> Vec<(QuadraticIntegerEl<I>, usize)> {
+        if self.is_zero(x) {
+            return vec![(self.zero(), 1)];
+        }
+        let ZZbig = BigIntRing::RING;
+        let ZZ = &self.integer_ring;
+        let norm = int_cast(self.norm(x), &ZZbig, ZZ);
+        let mut current = self.clone_el(x);
+        let mut result = Vec::new();
+        for (p, e) in algorithms::int_factor::factor(&ZZbig, norm) {
+            let primes = self.primes_above(&p);
+            if primes.is_empty() {
+                // p is inert, so it is itself prime in this ring
+                debug_assert!(e % 2 == 0);
+                let p = self.from_coefficients(int_cast(p, ZZ, &ZZbig), ZZ.zero());
+                for _ in 0..(e / 2) {
+                    current = self.checked_left_div(&current, &p).unwrap();
+                }
+                result.push((p, e / 2));
+            } else {
+                for prime in primes {
+                    let mut multiplicity = 0;
+                    while let Some(quo) = self.checked_left_div(&current, &prime) {
+                        current = quo;
+                        multiplicity += 1;
+                    }
+                    if multiplicity > 0 {
+                        result.push((prime, multiplicity));
+                    }
+                }
+            }
+        }
+        debug_assert!(self.is_unit(&current));
+        if !self.is_one(&current) {
+            result.push((current, 1));
+        }
+        return result;
+    }
+
+    ///
+    /// Returns the pairwise non-associated prime elements `π` with `π | p`, if `p` is not
+    /// prime in this ring. If `p` remains prime, returns the empty vector.
+    ///
+    fn primes_above(&self, p: &El<BigIntRing>) -> Vec<QuadraticIntegerEl<I>> {
+        let ZZbig = BigIntRing::RING;
+        let ZZ = &self.integer_ring;
+
+        // find a root `r` of the minimal polynomial `X^2 - tX - c` of `ω` modulo `p`
+        let root = if ZZbig.eq_el(p, &ZZbig.int_hom().map(2)) {
+            (0..2).filter(|r| (r * r - self.t * r - self.c) % 2 == 0).next().map(|r| ZZbig.int_hom().map(r))
+        } else {
+            let Fp = zn_barett::Zn::new(ZZbig, ZZbig.clone_el(p)).as_field().ok().unwrap();
+            let discriminant = Fp.int_hom().map(self.t * self.t + 4 * self.c);
+            let legendre_exp = ZZbig.half_exact(ZZbig.sub_ref_fst(p, ZZbig.one()));
+            let legendre_symbol = Fp.pow_gen(Fp.clone_el(&discriminant), &legendre_exp, &ZZbig);
+            if Fp.is_zero(&discriminant) {
+                // p is ramified, so the minimal polynomial has the double root `t/2`
+                Some(Fp.smallest_positive_lift(Fp.div(&Fp.int_hom().map(self.t), &Fp.int_hom().map(2))))
+            } else if Fp.is_one(&legendre_symbol) {
+                let poly_ring = DensePolyRing::new(&Fp, "X");
+                let minpoly = poly_ring.from_terms([(Fp.int_hom().map(-self.c), 0), (Fp.int_hom().map(-self.t), 1), (Fp.one(), 2)].into_iter());
+                let factor = algorithms::cantor_zassenhaus::cantor_zassenhaus(&poly_ring, minpoly, 1);
+                Some(Fp.smallest_positive_lift(Fp.negate(Fp.div(poly_ring.coefficient_at(&factor, 0), poly_ring.coefficient_at(&factor, 1)))))
+            } else {
+                None
+            }
+        };
+
+        if let Some(root) = root {
+            let p = self.from_coefficients(int_cast(ZZbig.clone_el(p), ZZ, &ZZbig), ZZ.zero());
+            let omega_minus_root = self.from_coefficients(ZZ.negate(int_cast(root, ZZ, &ZZbig)), ZZ.one());
+            let prime = algorithms::eea::gcd(p, omega_minus_root, RingRef::new(self));
+            let conj_prime = self.conjugate(&prime);
+            if self.checked_left_div(&conj_prime, &prime).is_some() {
+                return vec![prime];
+            } else {
+                return vec![prime, conj_prime];
+            }
+        } else {
+            return Vec::new();
+        }
+    }
+}
+
+impl<I: IntegerRingStore> PartialEq for QuadraticIntegerRingBase<I>
+    where I::Type: IntegerRing
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.integer_ring.get_ring() == other.integer_ring.get_ring() && self.d == other.d
+    }
+}
+
+impl<I: IntegerRingStore> Clone for QuadraticIntegerRingBase<I>
+    where I: Clone,
+        I::Type: IntegerRing
+{
+    fn clone(&self) -> Self {
+        QuadraticIntegerRingBase {
+            integer_ring: self.integer_ring.clone(),
+            d: self.d,
+            t: self.t,
+            c: self.c
+        }
+    }
+}
+
+impl<I: IntegerRingStore> Copy for QuadraticIntegerRingBase<I>
+    where I: Copy,
+        I::Type: IntegerRing
+{}
+
+impl<I: IntegerRingStore> RingBase for QuadraticIntegerRingBase<I>
+    where I::Type: IntegerRing
+{
+    type Element = QuadraticIntegerEl<I>;
+
+    fn clone_el(&self, val: &Self::Element) -> Self::Element {
+        QuadraticIntegerEl(self.integer_ring.clone_el(&val.0), self.integer_ring.clone_el(&val.1))
+    }
+
+    fn add_assign_ref(&self, lhs: &mut Self::Element, rhs: &Self::Element) {
+        self.integer_ring.add_assign_ref(&mut lhs.0, &rhs.0);
+        self.integer_ring.add_assign_ref(&mut lhs.1, &rhs.1);
+    }
+
+    fn add_assign(&self, lhs: &mut Self::Element, rhs: Self::Element) {
+        self.integer_ring.add_assign(&mut lhs.0, rhs.0);
+        self.integer_ring.add_assign(&mut lhs.1, rhs.1);
+    }
+
+    fn sub_assign_ref(&self, lhs: &mut Self::Element, rhs: &Self::Element) {
+        self.integer_ring.sub_assign_ref(&mut lhs.0, &rhs.0);
+        self.integer_ring.sub_assign_ref(&mut lhs.1, &rhs.1);
+    }
+
+    fn negate_inplace(&self, lhs: &mut Self::Element) {
+        self.integer_ring.negate_inplace(&mut lhs.0);
+        self.integer_ring.negate_inplace(&mut lhs.1);
+    }
+
+    fn mul_assign(&self, lhs: &mut Self::Element, rhs: Self::Element) {
+        self.mul_assign_ref(lhs, &rhs)
+    }
+
+    fn mul_assign_ref(&self, lhs: &mut Self::Element, rhs: &Self::Element) {
+        // (a + bω)(e + fω) = (ae + bfc) + (af + be + bft)ω
+        let ZZ = &self.integer_ring;
+        let bf = ZZ.mul_ref(&lhs.1, &rhs.1);
+        let new_fst = ZZ.add(ZZ.mul_ref(&lhs.0, &rhs.0), ZZ.int_hom().mul_ref_map(&bf, &self.c));
+        let new_snd = ZZ.add(
+            ZZ.add(ZZ.mul_ref(&lhs.0, &rhs.1), ZZ.mul_ref(&lhs.1, &rhs.0)),
+            ZZ.int_hom().mul_map(bf, self.t)
+        );
+        *lhs = QuadraticIntegerEl(new_fst, new_snd);
+    }
+
+    fn from_int(&self, value: i32) -> Self::Element {
+        QuadraticIntegerEl(self.integer_ring.int_hom().map(value), self.integer_ring.zero())
+    }
+
+    fn eq_el(&self, lhs: &Self::Element, rhs: &Self::Element) -> bool {
+        self.integer_ring.eq_el(&lhs.0, &rhs.0) && self.integer_ring.eq_el(&lhs.1, &rhs.1)
+    }
+
+    fn is_zero(&self, value: &Self::Element) -> bool {
+        self.integer_ring.is_zero(&value.0) && self.integer_ring.is_zero(&value.1)
+    }
+
+    fn is_one(&self, value: &Self::Element) -> bool {
+        self.integer_ring.is_one(&value.0) && self.integer_ring.is_zero(&value.1)
+    }
+
+    fn is_commutative(&self) -> bool { true }
+    fn is_noetherian(&self) -> bool { true }
+
+    fn dbg<'a>(&self, value: &Self::Element, out: &mut std::fmt::Formatter<'a>) -> std::fmt::Result {
+        self.integer_ring.get_ring().dbg(&value.0, out)?;
+        write!(out, " + ")?;
+        self.integer_ring.get_ring().dbg(&value.1, out)?;
+        if self.t == 0 {
+            write!(out, " * sqrt({})", self.d)
+        } else {
+            write!(out, " * (1 + sqrt({}))/2", self.d)
+        }
+    }
+}
+
+impl<I: IntegerRingStore> CanHomFrom<Self> for QuadraticIntegerRingBase<I>
+    where I::Type: IntegerRing
+{
+    type Homomorphism = ();
+
+    fn has_canonical_hom(&self, from: &Self) -> Option<()> {
+        if self == from {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn map_in(&self, _from: &Self, el: Self::Element, _: &()) -> Self::Element {
+        el
+    }
+}
+
+impl<I: IntegerRingStore> CanonicalIso<Self> for QuadraticIntegerRingBase<I>
+    where I::Type: IntegerRing
+{
+    type Isomorphism = ();
+
+    fn has_canonical_iso(&self, from: &Self) -> Option<()> {
+        if self == from {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn map_out(&self, _from: &Self, el: Self::Element, _: &()) -> Self::Element {
+        el
+    }
+}
+
+impl<I: IntegerRingStore, J: ?Sized + IntegerRing> CanHomFrom<J> for QuadraticIntegerRingBase<I>
+    where I::Type: IntegerRing
+{
+    type Homomorphism = ();
+
+    fn has_canonical_hom(&self, _: &J) -> Option<()> {
+        Some(())
+    }
+
+    fn map_in(&self, from: &J, el: J::Element, _: &()) -> Self::Element {
+        QuadraticIntegerEl(int_cast(el, &self.integer_ring, RingRef::new(from)), self.integer_ring.zero())
+    }
+}
+
+impl<I: IntegerRingStore> DivisibilityRing for QuadraticIntegerRingBase<I>
+    where I::Type: IntegerRing
+{
+    fn checked_left_div(&self, lhs: &Self::Element, rhs: &Self::Element) -> Option<Self::Element> {
+        if self.is_zero(rhs) {
+            if self.is_zero(lhs) {
+                return Some(self.zero());
+            } else {
+                return None;
+            }
+        }
+        let ZZ = &self.integer_ring;
+        let norm = self.norm(rhs);
+        let QuadraticIntegerEl(a, b) = self.mul_ref_snd(self.conjugate(rhs), lhs);
+        return Some(QuadraticIntegerEl(ZZ.checked_div(&a, &norm)?, ZZ.checked_div(&b, &norm)?));
+    }
+}
+
+impl<I: IntegerRingStore> PrincipalIdealRing for QuadraticIntegerRingBase<I>
+    where I::Type: IntegerRing
+{
+    fn ideal_gen(&self, lhs: &Self::Element, rhs: &Self::Element) -> (Self::Element, Self::Element, Self::Element) {
+        algorithms::eea::eea(self.clone_el(lhs), self.clone_el(rhs), RingRef::new(self))
+    }
+}
+
+impl<I: IntegerRingStore> EuclideanRing for QuadraticIntegerRingBase<I>
+    where I::Type: IntegerRing
+{
+    fn euclidean_div_rem(&self, lhs: Self::Element, rhs: &Self::Element) -> (Self::Element, Self::Element) {
+        assert!(!self.is_zero(rhs));
+        let ZZ = &self.integer_ring;
+        let norm = self.norm(rhs);
+        // the exact quotient is `(p + qω)/norm`, which we round to the closest element of the ring
+        let QuadraticIntegerEl(p, q) = self.mul_ref_snd(self.conjugate(rhs), &lhs);
+        let b = ZZ.rounded_div(ZZ.clone_el(&q), &norm);
+        let a = if self.t == 0 {
+            ZZ.rounded_div(p, &norm)
+        } else {
+            // `a + bω = (a + b/2) + b/2 sqrt(d)`, so round `p/norm + q/(2 norm) - b/2`
+            let numerator = ZZ.sub(ZZ.add(ZZ.int_hom().mul_map(p, 2), q), ZZ.mul_ref(&b, &norm));
+            ZZ.rounded_div(numerator, &ZZ.int_hom().mul_map(norm, 2))
+        };
+        let quo = QuadraticIntegerEl(a, b);
+        let rem = self.sub(lhs, self.mul_ref(&quo, rhs));
+        return (quo, rem);
+    }
+
+    fn euclidean_deg(&self, val: &Self::Element) -> Option<usize> {
+        let ZZ = &self.integer_ring;
+        let norm = self.norm(val);
+        if ZZ.abs_highest_set_bit(&norm).unwrap_or(0) < usize::BITS as usize - 1 {
+            Some(int_cast(norm, &StaticRing::<i128>::RING, ZZ) as usize)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+use crate::rings::rust_bigint::RustBigintRing;
+
+#[cfg(test)]
+fn edge_case_elements<I: IntegerRingStore>(ring: &QuadraticIntegerRing<I>) -> impl Iterator<Item = QuadraticIntegerEl<I>> + '_
+    where I::Type: IntegerRing
+{
+    let ZZ = ring.integer_ring();
+    [(0, 0), (1, 0), (0, 1), (-1, 2), (3, -5), (7, 11), (-13, -4), (100, 1)].into_iter()
+        .map(move |(a, b)| ring.from_coefficients(ZZ.int_hom().map(a), ZZ.int_hom().map(b)))
+}
+
+#[test]
+fn test_ring_axioms() {
+    for d in EUCLIDEAN_IMAGINARY_DISCRIMINANTS {
+        let ring = QuadraticIntegerRing::new(StaticRing::<i64>::RING, d);
+        crate::ring::generic_tests::test_ring_axioms(&ring, edge_case_elements(&ring));
+    }
+}
+
+#[test]
+fn test_euclidean_axioms() {
+    for d in EUCLIDEAN_IMAGINARY_DISCRIMINANTS {
+        let ring = QuadraticIntegerRing::new(StaticRing::<i64>::RING, d);
+        crate::pid::generic_tests::test_euclidean_ring_axioms(&ring, edge_case_elements(&ring));
+    }
+}
+
+#[test]
+fn test_norm_and_generator() {
+    let ZZi = QuadraticIntegerRing::new_gaussian(StaticRing::<i64>::RING);
+    let i = ZZi.generator();
+    assert_el_eq!(&ZZi, &ZZi.neg_one(), &ZZi.pow(ZZi.clone_el(&i), 2));
+    assert_eq!(25, ZZi.norm(&ZZi.from_coefficients(3, 4)));
+
+    let ZZw = QuadraticIntegerRing::new_eisenstein(StaticRing::<i64>::RING);
+    let w = ZZw.generator();
+    // ω is a primitive 6-th root of unity
+    assert_el_eq!(&ZZw, &ZZw.one(), &ZZw.pow(ZZw.clone_el(&w), 6));
+    assert!(!ZZw.is_one(&ZZw.pow(ZZw.clone_el(&w), 3)));
+    assert_eq!(1, ZZw.norm(&w));
+    assert_eq!(7, ZZw.norm(&ZZw.from_coefficients(3, -1)));
+    assert!(ZZw.is_unit(&w));
+}
+
+#[test]
+fn test_gcd() {
+    let ZZi = QuadraticIntegerRing::new_gaussian(StaticRing::<i64>::RING);
+    // 5 = (2 + i)(2 - i) and 13 = (3 + 2i)(3 - 2i)
+    let a = ZZi.mul(ZZi.from_coefficients(2, 1), ZZi.from_coefficients(3, 2));
+    let b = ZZi.mul(ZZi.from_coefficients(2, 1), ZZi.from_coefficients(3, -2));
+    let gcd = ZZi.ideal_gen(&a, &b).2;
+    assert_eq!(5, ZZi.norm(&gcd));
+    assert!(ZZi.checked_div(&ZZi.from_coefficients(2, 1), &gcd).map(|u| ZZi.is_unit(&u)).unwrap_or(false));
+}
+
+#[test]
+fn test_factor() {
+    for d in EUCLIDEAN_IMAGINARY_DISCRIMINANTS {
+        let ring = QuadraticIntegerRing::new(StaticRing::<i64>::RING, d);
+        for (a, b) in [(2, 0), (3, 0), (5, 0), (60, 0), (7, 3), (-12, 9), (1, 1), (0, 1)] {
+            let x = ring.from_coefficients(a, b);
+            let factorization = ring.factor(&x);
+            let product = ring.prod(factorization.iter().map(|(f, e)| ring.pow(ring.clone_el(f), *e)));
+            assert_el_eq!(&ring, &x, &product);
+            for (f, _) in &factorization {
+                if !ring.is_unit(f) {
+                    let norm = ring.norm(f);
+                    // the norm of a prime is either a prime `p` or `p^2` for an inert prime `p`
+                    let norm_factorization = algorithms::int_factor::factor(&StaticRing::<i64>::RING, norm);
+                    assert_eq!(1, norm_factorization.len());
+                    assert!(norm_factorization[0].1 == 1 || (norm_factorization[0].1 == 2 && ring.eq_el(f, &ring.int_hom().map(norm_factorization[0].0 as i32))));
+                }
+            }
+        }
+    }
+    let ZZi = QuadraticIntegerRing::new_gaussian(StaticRing::<i64>::RING);
+    assert_eq!(vec![(0, 1)], ZZi.factor(&ZZi.zero()).into_iter().map(|(f, e)| (ZZi.norm(&f), e)).collect::<Vec<_>>());
+    assert!(ZZi.factor(&ZZi.generator()).iter().all(|(f, _)| ZZi.is_unit(f)));
+}
+
+#[test]
+fn test_sum_of_squares_and_cornacchia() {
+    let ZZ = RustBigintRing::RING;
+    // a prime `p = 1 mod 4` of 80 bits
+    let p = ZZ.add(ZZ.power_of_two(80), ZZ.int_hom().map(13));
+    assert!(algorithms::miller_rabin::is_prime(&ZZ, &p, 10));
+    let ZZi = QuadraticIntegerRing::new_gaussian(ZZ);
+    let factorization = ZZi.factor(&ZZi.from_coefficients(ZZ.clone_el(&p), ZZ.zero()));
+    assert_eq!(2, factorization.len());
+    let (a, b) = ZZi.coefficients(&factorization[0].0);
+    assert_el_eq!(&ZZ, &p, &ZZ.add(ZZ.pow(ZZ.clone_el(a), 2), ZZ.pow(ZZ.clone_el(b), 2)));
+
+    // solve `x^2 + 2y^2 = 1019`
+    let ZZ2 = QuadraticIntegerRing::new(StaticRing::<i64>::RING, -2);
+    let factorization = ZZ2.factor(&ZZ2.int_hom().map(1019));
+    assert_eq!(2, factorization.len());
+    let (x, y) = ZZ2.coefficients(&factorization[0].0);
+    assert_eq!(1019, x * x + 2 * y * y);
+
+    // 1019 = 2 mod 3 is inert in the Eisenstein integers
+    let ZZw = QuadraticIntegerRing::new_eisenstein(StaticRing::<i64>::RING);
+    assert_eq!(1, ZZw.factor(&ZZw.int_hom().map(1019)).len());
+}
@@ -0,0 +1,394 @@
+use std::cmp::Ordering;
+
+use crate::algorithms;
+use crate::algorithms::resultant::{ResultantHint, resultant_over_field};
+use crate::rings::poly::{PolyRing, PolyRingStore};
+use crate::divisibility::{DivisibilityRing, DivisibilityRingStore};
+use crate::field::Field;
+use crate::integer::*;
+use crate::ordered::{OrderedRing, OrderedRingStore};
+use crate::pid::*;
+use crate::ring::*;
+use crate::homomorphism::*;
+
+///
+/// An implementation of the field of rational numbers `Q`, as fractions
+/// of elements of a given integer ring.
+///
+/// Elements are always stored in reduced form, i.e. as `a/b` with `b > 0`
+/// and `gcd(a, b) = 1`. Note that computations with rational numbers are
+/// prone to coefficient growth, so it is usually best to use an arbitrary-precision
+/// integer ring like [`BigIntRing`].
+///
+/// # Example
+/// ```
+/// # use feanor_math::ring::*;
+/// # use feanor_math::homomorphism::*;
+/// # use feanor_math::field::*;
+/// # use feanor_math::primitive_int::*;
+/// # use feanor_math::rings::rational::*;
+/// let QQ = RationalField::new(StaticRing::<i64>::RING);
+/// let a = QQ.div(&QQ.int_hom().map(1), &QQ.int_hom().map(6));
+/// let b = QQ.from_fraction(1, 3);
+/// let sum = QQ.add(a, b);
+/// assert_eq!((&1, &2), (QQ.num(&sum), QQ.den(&sum)));
+/// ```
+///
+pub struct RationalFieldBase<I: IntegerRingStore>
+    where I::Type: IntegerRing
+{
+    integer_ring: I
+}
+
+///
+/// An implementation of the field of rational numbers `Q`. For details, see
+/// [`RationalFieldBase`].
+///
+pub type RationalField<I> = RingValue<RationalFieldBase<I>>;
+
+pub struct RationalFieldEl<I: IntegerRingStore>(El<I>, El<I>)
+    where I::Type: IntegerRing;
+
+impl<I: IntegerRingStore> Clone for RationalFieldEl<I>
+    where El<I>: Clone,
+        I::Type: IntegerRing
+{
+    fn clone(&self) -> Self {
+        RationalFieldEl(self.0.clone(), self.1.clone())
+    }
+}
+
+impl<I: IntegerRingStore> Copy for RationalFieldEl<I>
+    where El<I>: Copy,
+        I::Type: IntegerRing
+{}
+
+impl<I: IntegerRingStore> RationalField<I>
+    where I::Type: IntegerRing
+{
+    pub fn new(integer_ring: I) -> Self {
+        RingValue::from(RationalFieldBase::new(integer_ring))
+    }
+
+    pub fn from_fraction(&self, num: El<I>, den: El<I>) -> El<Self> { self.get_ring().from_fraction(num, den) }
+
+    pub fn num<'a>(&self, x: &'a El<Self>) -> &'a El<I> { self.get_ring().num(x) }
+
+    pub fn den<'a>(&self, x: &'a El<Self>) -> &'a El<I> { self.get_ring().den(x) }
+}
+
+impl<I: IntegerRingStore> RationalFieldBase<I>
+    where I::Type: IntegerRing
+{
+    pub fn new(integer_ring: I) -> Self {
+        RationalFieldBase { integer_ring }
+    }
+
+    ///
+    /// Returns the fraction `num/den`. Panics if `den` is zero.
+    ///
+    pub fn from_fraction(&self, num: El<I>, den: El<I>) -> RationalFieldEl<I> {
+        assert!(!self.integer_ring.is_zero(&den));
+        let mut result = RationalFieldEl(num, den);
+        self.reduce(&mut result);
+        return result;
+    }
+
+    ///
+    /// Returns the numerator of the reduced representation of `x`.
+    ///
+    pub fn num<'a>(&self, x: &'a RationalFieldEl<I>) -> &'a El<I> {
+        &x.0
+    }
+
+    ///
+    /// Returns the (positive) denominator of the reduced representation of `x`.
+    ///
+    pub fn den<'a>(&self, x: &'a RationalFieldEl<I>) -> &'a El<I> {
+        &x.1
+    }
+
+    fn reduce(&self, value: &mut RationalFieldEl<I>) {
+        let ZZ = &self.integer_ring;
+        if ZZ.is_neg(&value.1) {
+            ZZ.negate_inplace(&mut value.0);
+            ZZ.negate_inplace(&mut value.1);
+        }
+        let gcd = ZZ.abs(algorithms::eea::signed_gcd(ZZ.clone_el(&value.0), ZZ.clone_el(&value.1), ZZ));
+        if !ZZ.is_one(&gcd) {
+            value.0 = ZZ.checked_div(&value.0, &gcd).unwrap();
+            value.1 = ZZ.checked_div(&value.1, &gcd).unwrap();
+        }
+    }
+}
+
+impl<I: IntegerRingStore> PartialEq for RationalFieldBase<I>
+    where I::Type: IntegerRing
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.integer_ring.get_ring() == other.integer_ring.get_ring()
+    }
+}
+
+impl<I: IntegerRingStore> Clone for RationalFieldBase<I>
+    where I: Clone,
+        I::Type: IntegerRing
+{
+    fn clone(&self) -> Self {
+        RationalFieldBase { integer_ring: self.integer_ring.clone() }
+    }
+}
+
+impl<I: IntegerRingStore> Copy for RationalFieldBase<I>
+    where I: Copy,
+        I::Type: IntegerRing
+{}
+
+impl<I: IntegerRingStore> RingBase for RationalFieldBase<I>
+    where I::Type: IntegerRing
+{
+    type Element = RationalFieldEl<I>;
+
+    fn clone_el(&self, val: &Self::Element) -> Self::Element {
+        RationalFieldEl(self.integer_ring.clone_el(&val.0), self.integer_ring.clone_el(&val.1))
+    }
+
+    fn add_assign_ref(&self, lhs: &mut Self::Element, rhs: &Self::Element) {
+        let ZZ = &self.integer_ring;
+        if ZZ.eq_el(&lhs.1, &rhs.1) {
+            ZZ.add_assign_ref(&mut lhs.0, &rhs.0);
+        } else {
+            ZZ.mul_assign_ref(&mut lhs.0, &rhs.1);
+            ZZ.add_assign(&mut lhs.0, ZZ.mul_ref(&lhs.1, &rhs.0));
+            ZZ.mul_assign_ref(&mut lhs.1, &rhs.1);
+        }
+        self.reduce(lhs);
+    }
+
+    fn add_assign(&self, lhs: &mut Self::Element, rhs: Self::Element) {
+        self.add_assign_ref(lhs, &rhs)
+    }
+
+    fn negate_inplace(&self, lhs: &mut Self::Element) {
+        self.integer_ring.negate_inplace(&mut lhs.0);
+    }
+
+    fn mul_assign_ref(&self, lhs: &mut Self::Element, rhs: &Self::Element) {
+        self.integer_ring.mul_assign_ref(&mut lhs.0, &rhs.0);
+        self.integer_ring.mul_assign_ref(&mut lhs.1, &rhs.1);
+        self.reduce(lhs);
+    }
+
+    fn mul_assign(&self, lhs: &mut Self::Element, rhs: Self::Element) {
+        self.mul_assign_ref(lhs, &rhs)
+    }
+
+    fn from_int(&self, value: i32) -> Self::Element {
+        RationalFieldEl(self.integer_ring.int_hom().map(value), self.integer_ring.one())
+    }
+
+    fn eq_el(&self, lhs: &Self::Element, rhs: &Self::Element) -> bool {
+        self.integer_ring.eq_el(&lhs.0, &rhs.0) && self.integer_ring.eq_el(&lhs.1, &rhs.1)
+    }
+
+    fn is_zero(&self, value: &Self::Element) -> bool {
+        self.integer_ring.is_zero(&value.0)
+    }
+
+    fn is_one(&self, value: &Self::Element) -> bool {
+        self.integer_ring.is_one(&value.0) && self.integer_ring.is_one(&value.1)
+    }
+
+    fn is_commutative(&self) -> bool { true }
+    fn is_noetherian(&self) -> bool { true }
+
+    fn dbg<'a>(&self, value: &Self::Element, out: &mut std::fmt::Formatter<'a>) -> std::fmt::Result {
+        self.integer_ring.get_ring().dbg(&value.0, out)?;
+        if !self.integer_ring.is_one(&value.1) {
+            write!(out, "/")?;
+            self.integer_ring.get_ring().dbg(&value.1, out)?;
+        }
+        return Ok(());
+    }
+}
+
+impl<I: IntegerRingStore> RingExtension for RationalFieldBase<I>
+    where I::Type: IntegerRing
+{
+    type BaseRing = I;
+
+    fn base_ring<'a>(&'a self) -> &'a Self::BaseRing {
+        &self.integer_ring
+    }
+
+    fn from(&self, x: El<Self::BaseRing>) -> Self::Element {
+        RationalFieldEl(x, self.integer_ring.one())
+    }
+}
+
+impl<I: IntegerRingStore> HashableElRing for RationalFieldBase<I>
+    where I::Type: IntegerRing + HashableElRing
+{
+    fn hash<H: std::hash::Hasher>(&self, el: &Self::Element, h: &mut H) {
+        self.integer_ring.get_ring().hash(&el.0, h);
+        self.integer_ring.get_ring().hash(&el.1, h);
+    }
+}
+
+impl<I: IntegerRingStore, J: IntegerRingStore> CanHomFrom<RationalFieldBase<J>> for RationalFieldBase<I>
+    where I::Type: IntegerRing,
+        J::Type: IntegerRing
+{
+    type Homomorphism = ();
+
+    fn has_canonical_hom(&self, _: &RationalFieldBase<J>) -> Option<()> {
+        Some(())
+    }
+
+    fn map_in(&self, from: &RationalFieldBase<J>, el: RationalFieldEl<J>, _: &()) -> Self::Element {
+        RationalFieldEl(int_cast(el.0, &self.integer_ring, &from.integer_ring), int_cast(el.1, &self.integer_ring, &from.integer_ring))
+    }
+}
+
+impl<I: IntegerRingStore, J: IntegerRingStore> CanonicalIso<RationalFieldBase<J>> for RationalFieldBase<I>
+    where I::Type: IntegerRing,
+        J::Type: IntegerRing
+{
+    type Isomorphism = ();
+
+    fn has_canonical_iso(&self, _: &RationalFieldBase<J>) -> Option<()> {
+        Some(())
+    }
+
+    fn map_out(&self, from: &RationalFieldBase<J>, el: Self::Element, _: &()) -> RationalFieldEl<J> {
+        RationalFieldEl(int_cast(el.0, &from.integer_ring, &self.integer_ring), int_cast(el.1, &from.integer_ring, &self.integer_ring))
+    }
+}
+
+impl<I: IntegerRingStore, J: ?Sized + IntegerRing> CanHomFrom<J> for RationalFieldBase<I>
+    where I::Type: IntegerRing
+{
+    type Homomorphism = ();
+
+    fn has_canonical_hom(&self, _: &J) -> Option<()> {
+        Some(())
+    }
+
+    fn map_in(&self, from: &J, el: J::Element, _: &()) -> Self::Element {
+        RationalFieldEl(int_cast(el, &self.integer_ring, RingRef::new(from)), self.integer_ring.one())
+    }
+}
+
+impl<I: IntegerRingStore> OrderedRing for RationalFieldBase<I>
+    where I::Type: IntegerRing
+{
+    fn cmp(&self, lhs: &Self::Element, rhs: &Self::Element) -> Ordering {
+        // denominators are positive
+        self.integer_ring.cmp(&self.integer_ring.mul_ref(&lhs.0, &rhs.1), &self.integer_ring.mul_ref(&rhs.0, &lhs.1))
+    }
+}
+
+impl<I: IntegerRingStore> DivisibilityRing for RationalFieldBase<I>
+    where I::Type: IntegerRing
+{
+    fn checked_left_div(&self, lhs: &Self::Element, rhs: &Self::Element) -> Option<Self::Element> {
+        if self.is_zero(rhs) {
+            if self.is_zero(lhs) {
+                return Some(self.zero());
+            } else {
+                return None;
+            }
+        }
+        let ZZ = &self.integer_ring;
+        let mut result = RationalFieldEl(ZZ.mul_ref(&lhs.0, &rhs.1), ZZ.mul_ref(&lhs.1, &rhs.0));
+        self.reduce(&mut result);
+        return Some(result);
+    }
+}
+
+impl<I: IntegerRingStore> PrincipalIdealRing for RationalFieldBase<I>
+    where I::Type: IntegerRing
+{
+    fn ideal_gen(&self, lhs: &Self::Element, rhs: &Self::Element) -> (Self::Element, Self::Element, Self::Element) {
+        if !self.is_zero(lhs) {
+            (self.checked_left_div(&self.one(), lhs).unwrap(), self.zero(), self.one())
+        } else if !self.is_zero(rhs) {
+            (self.zero(), self.checked_left_div(&self.one(), rhs).unwrap(), self.one())
+        } else {
+            (self.zero(), self.zero(), self.zero())
+        }
+    }
+}
+
+impl<I: IntegerRingStore> EuclideanRing for RationalFieldBase<I>
+    where I::Type: IntegerRing
+{
+    fn euclidean_div_rem(&self, lhs: Self::Element, rhs: &Self::Element) -> (Self::Element, Self::Element) {
+        assert!(!self.is_zero(rhs));
+        (self.checked_left_div(&lhs, rhs).unwrap(), self.zero())
+    }
+
+    fn euclidean_deg(&self, val: &Self::Element) -> Option<usize> {
+        if self.is_zero(val) {
+            Some(0)
+        } else {
+            Some(1)
+        }
+    }
+}
+
+impl<I: IntegerRingStore> Field for RationalFieldBase<I>
+    where I::Type: IntegerRing
+{}
+
+impl<I: IntegerRingStore> ResultantHint for RationalFieldBase<I>
+    where I::Type: IntegerRing
+{
+    fn compute_resultant<P>(&self, poly_ring: P, f: &El<P>, g: &El<P>) -> Self::Element
+        where P: PolyRingStore,
+            P::Type: PolyRing,
+            <P::Type as RingExtension>::BaseRing: RingStore<Type = Self>
+    {
+        resultant_over_field(poly_ring, f, g)
+    }
+}
+
+#[cfg(test)]
+use crate::primitive_int::StaticRing;
+#[cfg(test)]
+use crate::field::FieldStore;
+
+#[cfg(test)]
+fn edge_case_elements<I: IntegerRingStore>(ring: &RationalField<I>) -> impl Iterator<Item = RationalFieldEl<I>> + '_
+    where I::Type: IntegerRing
+{
+    let ZZ = ring.base_ring();
+    [(0, 1), (1, 1), (-1, 1), (1, 2), (-3, 4), (7, 3), (100, 9), (-5, 12)].into_iter()
+        .map(move |(a, b)| ring.from_fraction(ZZ.int_hom().map(a), ZZ.int_hom().map(b)))
+}
+
+#[test]
+fn test_ring_axioms() {
+    let QQ = RationalField::new(StaticRing::<i64>::RING);
+    crate::ring::generic_tests::test_ring_axioms(&QQ, edge_case_elements(&QQ));
+    crate::divisibility::generic_tests::test_divisibility_axioms(&QQ, edge_case_elements(&QQ));
+    crate::pid::generic_tests::test_euclidean_ring_axioms(&QQ, edge_case_elements(&QQ));
+}
+
+#[test]
+fn test_reduced_form() {
+    let QQ = RationalField::new(StaticRing::<i64>::RING);
+    let x = QQ.from_fraction(6, -4);
+    assert_eq!((&-3, &2), (QQ.num(&x), QQ.den(&x)));
+    assert_el_eq!(&QQ, &QQ.one(), &QQ.mul(QQ.from_fraction(2, 3), QQ.from_fraction(3, 2)));
+    assert_el_eq!(&QQ, &QQ.from_fraction(1, 12), &QQ.sub(QQ.from_fraction(1, 4), QQ.from_fraction(1, 6)));
+    assert_el_eq!(&QQ, &QQ.from_fraction(-9, 4), &QQ.div(&QQ.from_fraction(3, 2), &QQ.from_fraction(-2, 3)));
+}
+
+#[test]
+fn test_ordering() {
+    let QQ = RationalField::new(StaticRing::<i64>::RING);
+    assert!(QQ.is_lt(&QQ.from_fraction(1, 3), &QQ.from_fraction(1, 2)));
+    assert!(QQ.is_lt(&QQ.from_fraction(-1, 2), &QQ.from_fraction(-1, 3)));
+    assert!(QQ.is_neg(&QQ.from_fraction(1, -7)));
+}
@@ -5,6 +5,10 @@ use crate::ordered::*;
 use crate::primitive_int::StaticRingBase;
 use crate::ring::*;
 use crate::algorithms;
+use crate::algorithms::conv_mul::FFTConvolutionHint;
+use crate::algorithms::fft_mul::add_assign_integer_fft_convoluted_mul;
+use crate::algorithms::resultant::{ResultantHint, resultant_over_integers};
+use crate::rings::poly::{PolyRing, PolyRingStore};
 use std::cmp::Ordering::*;
 
 #[derive(Clone, Debug)]
@@ -289,6 +293,26 @@ impl EuclideanRing for RustBigintRingBase {
     }
 }
 
+impl FFTConvolutionHint for RustBigintRingBase {
+
+    fn fft_convolution_threshold(&self) -> usize { 32 }
+
+    fn add_assign_fft_convoluted_mul(&self, dst: &mut [Self::Element], lhs: &[Self::Element], rhs: &[Self::Element]) -> bool {
+        add_assign_integer_fft_convoluted_mul(RingRef::new(self), dst, lhs, rhs)
+    }
+}
+
+impl ResultantHint for RustBigintRingBase {
+
+    fn compute_resultant<P>(&self, poly_ring: P, f: &El<P>, g: &El<P>) -> Self::Element
+        where P: PolyRingStore,
+            P::Type: PolyRing,
+            <P::Type as RingExtension>::BaseRing: RingStore<Type = Self>
+    {
+        resultant_over_integers(poly_ring, f, g)
+    }
+}
+
 impl HashableElRing for RustBigintRingBase {
 
     fn hash<H: std::hash::Hasher>(&self, el: &Self::Element, h: &mut H) {
@@ -1,4 +1,6 @@
 use crate::algorithms::fft::cooley_tuckey::*;
+use crate::algorithms::conv_mul::FFTConvolutionHint;
+use crate::algorithms::fft_mul::add_assign_zn_fft_convoluted_mul;
 use crate::delegate::DelegateRing;
 use crate::integer::IntegerRingStore;
 use crate::pid::PrincipalIdealRingStore;
@@ -451,6 +453,15 @@ impl ZnRing for ZnBase {
     }
 }
 
+impl FFTConvolutionHint for ZnBase {
+
+    fn fft_convolution_threshold(&self) -> usize { 256 }
+
+    fn add_assign_fft_convoluted_mul(&self, dst: &mut [Self::Element], lhs: &[Self::Element], rhs: &[Self::Element]) -> bool {
+        add_assign_zn_fft_convoluted_mul(RingRef::new(self), dst, lhs, rhs)
+    }
+}
+
 impl HashableElRing for ZnBase {
 
     fn hash<H: std::hash::Hasher>(&self, el: &Self::Element, h: &mut H) {
@@ -1,6 +1,8 @@
 use std::marker::PhantomData;
 
 use crate::algorithms::fft::cooley_tuckey::CooleyTuckeyButterfly;
+use crate::algorithms::conv_mul::FFTConvolutionHint;
+use crate::algorithms::fft_mul::add_assign_zn_fft_convoluted_mul;
 use crate::delegate::DelegateRing;
 use crate::ordered::OrderedRingStore;
 use crate::primitive_int::*;
@@ -12,6 +14,7 @@ use crate::rings::rust_bigint::*;
 
 use super::*;
 use super::zn_barett;
+use crate::rings::field::AsFieldBase;
 
 fn high(x: u128) -> u64 {
     (x >> 64) as u64
@@ -459,6 +462,33 @@ impl ZnRing for ZnBase {
     }
 }
 
+impl FFTConvolutionHint for ZnBase {
+
+    fn fft_convolution_threshold(&self) -> usize { 256 }
+
+    fn add_assign_fft_convoluted_mul(&self, dst: &mut [Self::Element], lhs: &[Self::Element], rhs: &[Self::Element]) -> bool {
+        add_assign_zn_fft_convoluted_mul(RingRef::new(self), dst, lhs, rhs)
+    }
+}
+
+impl FFTConvolutionHint for AsFieldBase<Zn> {
+
+    fn fft_convolution_threshold(&self) -> usize { 256 }
+
+    fn add_assign_fft_convoluted_mul(&self, dst: &mut [Self::Element], lhs: &[Self::Element], rhs: &[Self::Element]) -> bool {
+        let lhs = lhs.iter().map(|x| *self.delegate_ref(x)).collect::<Vec<_>>();
+        let rhs = rhs.iter().map(|x| *self.delegate_ref(x)).collect::<Vec<_>>();
+        let mut result = dst.iter().map(|x| *self.delegate_ref(x)).collect::<Vec<_>>();
+        if !add_assign_zn_fft_convoluted_mul(self.base_ring(), &mut result[..], &lhs[..], &rhs[..]) {
+            return false;
+        }
+        for (d, x) in dst.iter_mut().zip(result.into_iter()) {
+            *d = self.rev_delegate(x);
+        }
+        return true;
+    }
+}
+
 impl HashableElRing for ZnBase {
 
     fn hash<H: std::hash::Hasher>(&self, el: &Self::Element, h: &mut H) {
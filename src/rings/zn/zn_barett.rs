@@ -8,6 +8,9 @@ use crate::homomorphism::*;
 use crate::rings::field::AssumeFieldDivision;
 use crate::rings::zn::*;
 use crate::algorithms;
+use crate::algorithms::conv_mul::FFTConvolutionHint;
+use crate::algorithms::fft_mul::add_assign_zn_fft_convoluted_mul;
+use crate::integer::BigIntRing;
 
 ///
 /// Ring representing `Z/nZ`, computing the modular reductions
@@ -422,6 +425,15 @@ impl<I: IntegerRingStore> ZnRing for ZnBase<I>
     }
 }
 
+impl FFTConvolutionHint for ZnBase<BigIntRing> {
+
+    fn fft_convolution_threshold(&self) -> usize { 32 }
+
+    fn add_assign_fft_convoluted_mul(&self, dst: &mut [Self::Element], lhs: &[Self::Element], rhs: &[Self::Element]) -> bool {
+        add_assign_zn_fft_convoluted_mul(RingRef::new(self), dst, lhs, rhs)
+    }
+}
+
 impl<R: ZnRingStore<Type = ZnBase<I>>, I: IntegerRingStore> CanHomFrom<ZnBase<I>> for AsFieldBase<R>
     where I::Type: IntegerRing
 {
@@ -480,8 +492,6 @@ impl<R: ZnRingStore<Type = ZnBase<I>>, I: IntegerRingStore> CanonicalIso<AsField
 
 #[cfg(test)]
 use crate::rings::finite::FiniteRingStore;
-#[cfg(test)]
-use crate::integer::BigIntRing;
 
 #[test]
 fn test_mul() {
@@ -1,4 +1,8 @@
 use crate::algorithms::eea::*;
+use crate::algorithms::conv_mul::FFTConvolutionHint;
+use crate::algorithms::fft_mul::add_assign_zn_fft_convoluted_mul;
+use crate::algorithms::resultant::{ResultantHint, resultant_over_field};
+use crate::rings::poly::{PolyRing, PolyRingStore};
 use crate::pid::{EuclideanRing, PrincipalIdealRing, PrincipalIdealRingStore};
 use crate::field::Field;
 use crate::divisibility::*;
@@ -230,6 +234,25 @@ impl<const N: u64, const IS_FIELD: bool> ZnRing for ZnBase<N, IS_FIELD> {
 
 impl<const N: u64> Field for ZnBase<N, true> {}
 
+impl<const N: u64> ResultantHint for ZnBase<N, true> {
+    fn compute_resultant<P>(&self, poly_ring: P, f: &El<P>, g: &El<P>) -> Self::Element
+        where P: PolyRingStore,
+            P::Type: PolyRing,
+            <P::Type as RingExtension>::BaseRing: RingStore<Type = Self>
+    {
+        resultant_over_field(poly_ring, f, g)
+    }
+}
+
+impl<const N: u64, const IS_FIELD: bool> FFTConvolutionHint for ZnBase<N, IS_FIELD> {
+
+    fn fft_convolution_threshold(&self) -> usize { 256 }
+
+    fn add_assign_fft_convoluted_mul(&self, dst: &mut [Self::Element], lhs: &[Self::Element], rhs: &[Self::Element]) -> bool {
+        add_assign_zn_fft_convoluted_mul(RingRef::new(self), dst, lhs, rhs)
+    }
+}
+
 impl<const N: u64, const IS_FIELD: bool> RingValue<ZnBase<N, IS_FIELD>> {
     pub const RING: Self = Self::from(ZnBase::new());
 }